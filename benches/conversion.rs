@@ -0,0 +1,52 @@
+// Conversion throughput, to compare builds with and without the
+// static-tables feature, and the ASCII fast path with the general one:
+//
+//     cargo bench --bench conversion
+//     cargo bench --bench conversion --features static-tables
+use std::{hint::black_box, time::Instant};
+
+use styledtextlib::{convert_ascii_str, Converter, LetterStyle, StyledLetter};
+
+const ROUNDS: usize = 2000;
+
+// run `convert` over `text` and print the time per char
+fn measure<T>(label: &str, text: &str, mut convert: impl FnMut(&str) -> T) {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(convert(black_box(text)));
+    }
+    let elapsed = start.elapsed();
+    let chars = text.chars().count() * ROUNDS;
+    println!(
+        "{}: {:.1} ns/char",
+        label,
+        elapsed.as_nanos() as f64 / chars as f64
+    );
+}
+
+fn main() {
+    let text = "The quick brown fox jumps over the lazy dog 0123456789 αβγ ΔΣΩ ".repeat(16);
+    // no digits, which script and fraktur don't have: convert_ascii_str
+    // would stop at the first one
+    let ascii = "The quick brown fox jumps over the lazy dog ".repeat(16);
+    for (letter_type, letter_style) in [
+        (StyledLetter::Serif, LetterStyle::Bold),
+        (StyledLetter::Script, LetterStyle::Normal),
+        (StyledLetter::Fraktur, LetterStyle::Normal),
+        (StyledLetter::DoubleStruck, LetterStyle::Bold),
+    ] {
+        let converter = Converter::new(letter_type, letter_style);
+        let label = format!("{} {}", letter_type, letter_style);
+        measure(&label, &text, |text| converter.convert_str_lossy(text));
+        measure(
+            &format!("{}, ASCII, convert_str_lossy", label),
+            &ascii,
+            |text| converter.convert_str_lossy(text),
+        );
+        measure(
+            &format!("{}, ASCII, convert_ascii_str", label),
+            &ascii,
+            |text| convert_ascii_str(text, &letter_type, &letter_style),
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use crate::{letter::value_name, CharacterInfo, LetterStyle, StyledLetter};
+
+// How much of a text screen readers will struggle with. Styled letters are
+// math alphanumeric symbols: they are spelled out one by one by their
+// Unicode name ("mathematical bold capital h") or skipped entirely.
+#[derive(Debug, Clone, PartialEq)]
+pub struct A11yReport {
+    // characters in the text
+    pub total: usize,
+    // styled letters and digits, i.e. everything that is not serif normal
+    pub affected: usize,
+    // (type, style, count) of the styles used, in order of first appearance
+    pub styles: Vec<(StyledLetter, LetterStyle, usize)>,
+}
+
+impl A11yReport {
+    pub fn is_readable(&self) -> bool {
+        self.affected == 0
+    }
+}
+
+impl Display for A11yReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} characters are styled and may be unreadable by screen readers",
+            self.affected, self.total
+        )?;
+        if self.styles.is_empty() {
+            return Ok(());
+        }
+        let styles: Vec<String> = self
+            .styles
+            .iter()
+            .map(|(letter_type, letter_style, count)| {
+                format!(
+                    "{} {}: {}",
+                    value_name(letter_type),
+                    value_name(letter_style),
+                    count
+                )
+            })
+            .collect();
+        write!(f, " ({})", styles.join(", "))
+    }
+}
+
+pub fn a11y_report(text: &str) -> A11yReport {
+    let mut report = A11yReport {
+        total: 0,
+        affected: 0,
+        styles: Vec::new(),
+    };
+    for ch in text.chars() {
+        report.total += 1;
+        let info = match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => info,
+            CharacterInfo::Other(_) => continue,
+        };
+        // plain ASCII and plain Greek
+        if let (StyledLetter::Serif, LetterStyle::Normal) = (&info.letter_type, &info.letter_style)
+        {
+            continue;
+        }
+        report.affected += 1;
+        let used = report
+            .styles
+            .iter_mut()
+            .find(|(letter_type, letter_style, _)| {
+                *letter_type == info.letter_type && *letter_style == info.letter_style
+            });
+        match used {
+            Some((_, _, count)) => *count += 1,
+            None => report.styles.push((info.letter_type, info.letter_style, 1)),
+        }
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        total = report.total,
+        affected = report.affected,
+        "detected styled characters"
+    );
+    report
+}
+
+#[cfg(test)]
+mod test_a11y {
+    use super::*;
+
+    #[test]
+    pub fn test_a11y_report() {
+        let report = a11y_report("𝐇𝐢 there, 𝓕𝓻𝓮𝓭 𝟏");
+        assert_eq!(16, report.total);
+        assert_eq!(7, report.affected);
+        assert_eq!(
+            vec![
+                (StyledLetter::Serif, LetterStyle::Bold, 3),
+                (StyledLetter::Script, LetterStyle::Bold, 4),
+            ],
+            report.styles
+        );
+        assert_eq!(
+            "7 of 16 characters are styled and may be unreadable by screen readers \
+             (serif bold: 3, script bold: 4)",
+            report.to_string()
+        );
+        assert!(a11y_report("plain αβγ 123").is_readable());
+        assert!(a11y_report("").is_readable());
+    }
+}
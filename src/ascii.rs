@@ -0,0 +1,64 @@
+use crate::{CharacterType, LetterInfo, LetterStyle, LetterTypeError, StyledLetter};
+
+// `convert` for text the caller has already checked to be ASCII, e.g. with
+// `str::is_ascii`: letters and digits are converted straight from their
+// offsets, without classifying each character. Non-ASCII input is a bug;
+// debug builds panic on it, release builds treat its bytes as other
+// characters and may return mangled text.
+pub fn convert_ascii_str(
+    text: &str,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> Result<String, LetterTypeError> {
+    debug_assert!(text.is_ascii(), "convert_ascii_str needs ASCII input");
+    let info = |offset: u8, uppercase: bool, character_type: CharacterType| {
+        LetterInfo::new(
+            offset as usize,
+            uppercase,
+            StyledLetter::Serif,
+            LetterStyle::Normal,
+            character_type,
+        )
+    };
+    let mut res = String::with_capacity(text.len() * 4);
+    for byte in text.bytes() {
+        let letter = match byte {
+            b'A'..=b'Z' => info(byte - b'A', true, CharacterType::Letter),
+            b'a'..=b'z' => info(byte - b'a', false, CharacterType::Letter),
+            b'0'..=b'9' => info(byte - b'0', false, CharacterType::Digit),
+            _ => {
+                res.push(byte as char);
+                continue;
+            }
+        };
+        res.push(letter.convert(letter_type, letter_style)?);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test_ascii {
+    use super::*;
+    use crate::convert_pair;
+
+    #[test]
+    pub fn test_convert_ascii_str() {
+        let text = "The quick brown fox, 0123456789!";
+        for letter_type in StyledLetter::ALL {
+            for letter_style in LetterStyle::ALL {
+                let expected: Result<String, _> = text
+                    .chars()
+                    .map(|ch| convert_pair(ch, letter_type, letter_style))
+                    .collect();
+                assert_eq!(
+                    expected.ok(),
+                    convert_ascii_str(text, letter_type, letter_style).ok()
+                );
+            }
+        }
+        assert_eq!(
+            "ℎ𝑒𝑙𝑙𝑜 ~",
+            convert_ascii_str("hello ~", &StyledLetter::Serif, &LetterStyle::Italic).unwrap()
+        );
+    }
+}
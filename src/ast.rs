@@ -0,0 +1,257 @@
+use std::ops::Range;
+
+use crate::{
+    destyle, grapheme::is_variation_selector, sanitize::is_combining, CharacterInfo, Decoration,
+    LetterStyle, StyledLetter,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AstNode {
+    // letters and digits of one font and style, with the decorations every
+    // one of them carries
+    Run {
+        range: Range<usize>,
+        letter_type: StyledLetter,
+        letter_style: LetterStyle,
+        decorations: Vec<Decoration>,
+        // the text de-styled, without the decoration marks
+        plain: String,
+    },
+    // everything that has no style: spaces, punctuation, symbols, emoji
+    PassThrough {
+        range: Range<usize>,
+        decorations: Vec<Decoration>,
+        text: String,
+    },
+    // a decoration mark with no character before it to decorate
+    Decoration {
+        range: Range<usize>,
+        decoration: Decoration,
+    },
+}
+
+impl AstNode {
+    // byte range in the parsed string
+    pub fn range(&self) -> &Range<usize> {
+        match self {
+            AstNode::Run { range, .. } => range,
+            AstNode::PassThrough { range, .. } => range,
+            AstNode::Decoration { range, .. } => range,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StyledAst {
+    pub nodes: Vec<AstNode>,
+}
+
+// A base character with the combining marks that follow it.
+struct Cluster {
+    range: Range<usize>,
+    base: Option<char>,
+    decorations: Vec<Decoration>,
+    // the base character and its non-decoration marks (accents)
+    text: String,
+}
+
+fn clusters(text: &str) -> Vec<Cluster> {
+    let mut clusters: Vec<Cluster> = Vec::new();
+    for (offset, ch) in text.char_indices() {
+        let end = offset + ch.len_utf8();
+        if is_variation_selector(ch) {
+            if let Some(cluster) = clusters.last_mut().filter(|cluster| cluster.base.is_some()) {
+                cluster.range.end = end;
+                cluster.text.push(ch);
+                continue;
+            }
+        }
+        if is_combining(ch) {
+            if let Some(cluster) = clusters.last_mut().filter(|cluster| cluster.base.is_some()) {
+                cluster.range.end = end;
+                match Decoration::from_mark(ch) {
+                    Some(decoration) if !cluster.decorations.contains(&decoration) => {
+                        cluster.decorations.push(decoration)
+                    }
+                    Some(_) => {}
+                    None => cluster.text.push(ch),
+                }
+                continue;
+            }
+        }
+        let base = if is_combining(ch) { None } else { Some(ch) };
+        clusters.push(Cluster {
+            range: offset..end,
+            base,
+            decorations: Vec::new(),
+            text: ch.to_string(),
+        });
+    }
+    clusters
+}
+
+// Parse (styled) text into runs of one font and style, unstyled spans and
+// decorations, each with its byte range in `text`.
+pub fn parse(text: &str) -> StyledAst {
+    let mut nodes: Vec<AstNode> = Vec::new();
+    for cluster in clusters(text) {
+        let base = match cluster.base {
+            Some(base) => base,
+            None => {
+                match Decoration::from_mark(cluster.text.chars().next().unwrap_or_default()) {
+                    Some(decoration) => nodes.push(AstNode::Decoration {
+                        range: cluster.range,
+                        decoration,
+                    }),
+                    None => nodes.push(AstNode::PassThrough {
+                        range: cluster.range,
+                        decorations: Vec::new(),
+                        text: cluster.text,
+                    }),
+                }
+                continue;
+            }
+        };
+        let node = match CharacterInfo::get_letter_info(base) {
+            CharacterInfo::Letter(info) => {
+                // a variation selector means nothing after a letter
+                let mut plain: String = cluster
+                    .text
+                    .chars()
+                    .filter(|ch| !is_variation_selector(*ch))
+                    .collect();
+                plain.replace_range(..base.len_utf8(), &destyle(base).to_string());
+                AstNode::Run {
+                    range: cluster.range,
+                    letter_type: info.letter_type,
+                    letter_style: info.letter_style,
+                    decorations: cluster.decorations,
+                    plain,
+                }
+            }
+            CharacterInfo::Other(_) => AstNode::PassThrough {
+                range: cluster.range,
+                decorations: cluster.decorations,
+                text: cluster.text,
+            },
+        };
+        // extend the previous node when font, style and decorations match
+        match (nodes.last_mut(), node) {
+            (
+                Some(AstNode::Run {
+                    range,
+                    letter_type,
+                    letter_style,
+                    decorations,
+                    plain,
+                }),
+                AstNode::Run {
+                    range: next_range,
+                    letter_type: next_type,
+                    letter_style: next_style,
+                    decorations: next_decorations,
+                    plain: next_plain,
+                },
+            ) if *letter_type == next_type
+                && *letter_style == next_style
+                && *decorations == next_decorations =>
+            {
+                range.end = next_range.end;
+                plain.push_str(&next_plain);
+            }
+            (
+                Some(AstNode::PassThrough {
+                    range,
+                    decorations,
+                    text,
+                }),
+                AstNode::PassThrough {
+                    range: next_range,
+                    decorations: next_decorations,
+                    text: next_text,
+                },
+            ) if *decorations == next_decorations => {
+                range.end = next_range.end;
+                text.push_str(&next_text);
+            }
+            (_, node) => nodes.push(node),
+        }
+    }
+    StyledAst { nodes }
+}
+
+#[cfg(test)]
+mod test_ast {
+    use super::*;
+
+    #[test]
+    pub fn test_parse() {
+        let text = "𝐇𝐢, 𝓯o\u{336}o\u{336}!";
+        let ast = parse(text);
+        assert_eq!(
+            vec![
+                AstNode::Run {
+                    range: 0..8,
+                    letter_type: StyledLetter::Serif,
+                    letter_style: LetterStyle::Bold,
+                    decorations: vec![],
+                    plain: "Hi".to_string(),
+                },
+                AstNode::PassThrough {
+                    range: 8..10,
+                    decorations: vec![],
+                    text: ", ".to_string(),
+                },
+                AstNode::Run {
+                    range: 10..14,
+                    letter_type: StyledLetter::Script,
+                    letter_style: LetterStyle::Bold,
+                    decorations: vec![],
+                    plain: "f".to_string(),
+                },
+                AstNode::Run {
+                    range: 14..20,
+                    letter_type: StyledLetter::Serif,
+                    letter_style: LetterStyle::Normal,
+                    decorations: vec![Decoration::Strikethrough],
+                    plain: "oo".to_string(),
+                },
+                AstNode::PassThrough {
+                    range: 20..21,
+                    decorations: vec![],
+                    text: "!".to_string(),
+                },
+            ],
+            ast.nodes
+        );
+        assert_eq!("o\u{336}o\u{336}", &text[ast.nodes[3].range().clone()]);
+    }
+
+    #[test]
+    pub fn test_parse_marks() {
+        // accents stay with their letter, a leading decoration mark has no
+        // letter to attach to
+        let ast = parse("\u{332}e\u{301}");
+        assert_eq!(
+            vec![
+                AstNode::Decoration {
+                    range: 0..2,
+                    decoration: Decoration::Underline,
+                },
+                AstNode::Run {
+                    range: 2..5,
+                    letter_type: StyledLetter::Serif,
+                    letter_style: LetterStyle::Normal,
+                    decorations: vec![],
+                    plain: "e\u{301}".to_string(),
+                },
+            ],
+            ast.nodes
+        );
+        assert!(parse("").nodes.is_empty());
+        // selectors don't break up a run
+        let ast = parse("𝐀\u{FE0F}𝐛");
+        assert_eq!(1, ast.nodes.len());
+        assert!(matches!(&ast.nodes[0], AstNode::Run { plain, .. } if plain == "Ab"));
+    }
+}
@@ -1,75 +1,2043 @@
-use clap::Parser;
-use styledtextlib::{convert, LetterStyle, StyledLetter};
+use std::{
+    ffi::OsStr,
+    io::{self, IsTerminal, Read, Write},
+    marker::PhantomData,
+    ops::Range,
+    path::{Path, PathBuf},
+    process::{ExitCode, Stdio},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+    time::Duration,
+};
+
+use clap::{
+    builder::{PossibleValue, TypedValueParser},
+    error::ErrorKind,
+    CommandFactory, Parser, Subcommand, ValueEnum,
+};
+#[cfg(feature = "i18n")]
+use clap::{Arg, ArgAction, FromArgMatches};
+#[cfg(feature = "random")]
+use rand_core::RngCore;
+use regex::Regex;
+#[cfg(feature = "fun")]
+use styledtextlib::Transform;
+use styledtextlib::{
+    a11y_report, add_spacing, capabilities, compat_report, contains_styled, coverage_report,
+    detect_spoof, diff, diff_lines, display_width, doctor, downgrade, enumerate_lines, export,
+    filter, find_invisible, format_output, format_table, from_braille, osc52, remove_spacing,
+    restore_final_sigma, reverse, samples, sanitize, sentence_case_pieces, set_presentation,
+    split_entities, split_escapes, split_markdown, split_numbers, split_protected, strip_invisible,
+    substitute, to_braille, to_digit_style, to_plain, to_plain_aggressive, truncate,
+    unescape_unicode, CharacterType, Classification, CompatProfile, Converter, Diagnostic,
+    DiffStyle, DigitStyle, DoctorCheck, EntitySegment, EscapeFlavor, EscapeSegment, ExportFormat,
+    FilterMode, GreekVariantChoice, GreekVariantPolicy, Lang, LetterStyle, LetterTypeError, Limit,
+    ListMarker, MarkdownSegment, NumberOptions, NumberSegment, OutputEncoding, OutputFormat,
+    Presentation, ProtectedSegment, SampleFormat, Spacing, StyleSpec, StyledDocument, StyledLetter,
+    TextCounts, PANGRAM,
+};
+#[cfg(feature = "random")]
+use styledtextlib::{convert_random, random_choices};
+#[cfg(feature = "normalization")]
+use styledtextlib::{decompose_greek_for, normalize, strip_diacritics, GreekMarks, Normalization};
+#[cfg(feature = "inspect")]
+use styledtextlib::{inspect, INSPECT_HEADERS};
+#[cfg(feature = "terminal")]
+use styledtextlib::{Run, TerminalPreview};
+
+// Parses through the library's `FromStr`, whose errors name the closest
+// valid values, while --help still lists the possible values.
+#[derive(Clone)]
+struct SuggestingParser<T>(PhantomData<T>);
+
+impl<T> SuggestingParser<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> TypedValueParser for SuggestingParser<T>
+where
+    T: ValueEnum + FromStr<Err = String> + Clone + Send + Sync + 'static,
+{
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        _cmd: &clap::Command,
+        _arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<T, clap::Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8))?;
+        value
+            .parse()
+            .map_err(|e| clap::Error::raw(ErrorKind::InvalidValue, format!("{}\n", e)))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            T::value_variants()
+                .iter()
+                .filter_map(|value| value.to_possible_value()),
+        ))
+    }
+}
 
 #[derive(Parser)]
-#[command(about, version, long_about = None)]
+#[command(
+    about,
+    version,
+    long_about = None,
+    after_help = "Exit status: 0 when everything was converted, 1 when some characters could not be converted (they are dropped, and numbers stay plain with --numbers), 2 when the input could not be read or the output written, 3 when --check found changes",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct AppArgs {
-    text: String,
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    /// turn ASCII letters into styled letters
+    /// text to convert; without it the text is read from --input, else from stdin when it is not
+    /// a terminal. --stdin and --paste force stdin or the clipboard, and more than one source is
+    /// an error
+    #[arg(group = "source")]
+    text: Option<String>,
+
+    /// read the text to convert from a file
+    #[arg(short, long, value_name = "FILE", group = "source")]
+    input: Option<PathBuf>,
+
+    /// read the text to convert from stdin, even when it is a terminal
+    #[arg(long, group = "source")]
+    stdin: bool,
+
+    /// read the text to convert from the clipboard
+    #[arg(long, group = "source")]
+    paste: bool,
+
+    /// print the supported fonts, styles and combinations as JSON and exit
+    #[arg(long, exclusive = true)]
+    capabilities: bool,
+
+    /// letter type and style in one value, e.g. serif.bold or script
+    #[arg(
+        conflicts_with_all = ["ascii", "letter_type", "letter_style", "style_specs"]
+    )]
+    spec: Option<StyleSpec>,
+
+    /// letter type and style in one value, e.g. serif-bold-italic; repeat to convert with several
+    #[arg(
+        long = "as",
+        visible_alias = "to",
+        value_name = "SPEC",
+        conflicts_with_all = ["ascii", "letter_type", "letter_style"]
+    )]
+    style_specs: Vec<StyleSpec>,
+
+    /// print the conversions of all --as specs in an aligned table
+    #[arg(long, conflicts_with = "ascii")]
+    table: bool,
+
+    /// don't print the output, report what would change; exits with 3 if anything would
+    #[arg(long, conflicts_with = "table")]
+    check: bool,
+
+    /// print the input next to the output (side) or the changed words inline (words) instead of the output
     #[arg(
         value_enum,
         long,
+        value_name = "STYLE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "side",
+        conflicts_with_all = ["check", "table", "format"]
+    )]
+    diff: Option<DiffStyle>,
+
+    /// copy the output to the clipboard through the terminal with an OSC 52 escape sequence instead of printing it
+    #[arg(long, conflicts_with_all = ["check", "table"])]
+    copy_osc52: bool,
+
+    /// turn ASCII letters into styled letters
+    #[arg(
+        value_parser = SuggestingParser::<StyledLetter>::new(),
+        short = 't',
+        long,
+        visible_alias = "font",
         conflicts_with = "ascii",
-        requires = "text",
         default_value = "monospace"
     )]
     letter_type: Option<StyledLetter>,
 
     #[arg(
-        value_enum,
+        value_parser = SuggestingParser::<LetterStyle>::new(),
+        short = 's',
         long,
+        visible_alias = "style",
         conflicts_with = "ascii",
-        requires = "text",
         default_value = "normal"
     )]
     letter_style: Option<LetterStyle>,
 
-    /// convert with randomly types and styles
-    #[arg(long, conflicts_with = "ascii", conflicts_with = "letter_type")]
+    /// convert every character with a random letter type and style
+    #[cfg(feature = "random")]
+    #[arg(
+        long,
+        conflicts_with_all = ["ascii", "letter_type", "letter_style", "spec", "style_specs"]
+    )]
     random: bool,
 
-    /// convert text randomly within given types
+    /// letter types --random never picks
+    #[cfg(feature = "random")]
     #[arg(
+        value_parser = SuggestingParser::<StyledLetter>::new(),
         long,
-        conflicts_with = "ascii",
-        conflicts_with = "letter_type",
+        value_delimiter = ',',
         requires = "random"
     )]
     exclude_types: Option<Vec<StyledLetter>>,
 
-    /// convert text randomly within given styles
+    /// letter styles --random never picks
+    #[cfg(feature = "random")]
     #[arg(
+        value_parser = SuggestingParser::<LetterStyle>::new(),
         long,
-        conflicts_with = "ascii",
-        conflicts_with = "letter_type",
+        value_delimiter = ',',
         requires = "random"
     )]
     exclude_styles: Option<Vec<LetterStyle>>,
 
+    /// seed for --random, to get the same output every time
+    #[cfg(feature = "random")]
+    #[arg(long, requires = "random")]
+    seed: Option<u64>,
+
+    /// insert a separator between converted characters: none, thin, space or a single character
+    #[arg(long)]
+    spacing: Option<Spacing>,
+
+    /// rewrite the text before converting, e.g. leet for leetspeak (a -> 4, e -> 3, ...)
+    #[cfg(feature = "fun")]
+    #[arg(value_enum, long, conflicts_with = "ascii")]
+    transform: Option<Transform>,
+
+    /// lowercase the text and capitalize the start of each sentence before converting
+    #[arg(long, conflicts_with = "ascii")]
+    sentence_case: bool,
+
+    /// normalize the input before classifying its characters
+    #[cfg(feature = "normalization")]
+    #[arg(value_enum, long)]
+    normalize: Option<Normalization>,
+
+    /// strip accents and other diacritics (é -> e) before converting; lossy
+    #[cfg(feature = "normalization")]
+    #[arg(long, conflicts_with = "ascii")]
+    strip_diacritics: bool,
+
+    /// keep or strip the accents of accented Greek letters when converting them
+    #[cfg(feature = "normalization")]
+    #[arg(value_enum, long, conflicts_with = "ascii", default_value = "keep")]
+    greek_marks: GreekMarks,
+
+    /// emit the letter or the symbol form of ε/ϵ, θ/ϑ, κ/ϰ, π/ϖ, ρ/ϱ, φ/ϕ
+    #[arg(value_enum, long, conflicts_with = "ascii", default_value = "preserve")]
+    greek_variants: GreekVariantChoice,
+
+    /// styles to try, in order, for digits that have no glyphs in the requested style
+    #[arg(
+        value_parser = SuggestingParser::<LetterStyle>::new(),
+        long,
+        conflicts_with = "ascii",
+        value_delimiter = ','
+    )]
+    digit_fallback: Option<Vec<LetterStyle>>,
+
+    /// specs to try, in order, for characters the requested font and style have no glyph for; without any, the same font upright, normal and bold, then serif
+    #[arg(
+        long,
+        value_name = "SPEC",
+        conflicts_with = "ascii",
+        num_args = 0..=1,
+        require_equals = true,
+        value_delimiter = ','
+    )]
+    fallback_chain: Option<Vec<StyleSpec>>,
+
+    /// write digits and numeric expressions (+ - = ( ) n) as superscripts or subscripts, or digits as seven-segment digits
+    #[arg(value_enum, long, conflicts_with = "ascii")]
+    digit_style: Option<DigitStyle>,
+
+    /// only convert characters that are already styled, leaving plain text alone, e.g. --only-styled --to sans-bold
+    #[arg(long, conflicts_with_all = ["ascii", "numbers"])]
+    only_styled: bool,
+
+    /// convert whole numbers as units; a number whose digits can't be styled stays plain
+    #[arg(long, conflicts_with = "ascii")]
+    numbers: bool,
+
+    /// with --numbers, write a leading hyphen-minus as U+2212 MINUS SIGN
+    #[arg(long, requires = "numbers")]
+    unicode_minus: bool,
+
+    /// with --numbers, write spaces between digit groups as U+2007 FIGURE SPACE
+    #[arg(long, requires = "numbers")]
+    figure_space: bool,
+
+    /// decode \u{1D400}, \uXXXX and \UXXXXXXXX escapes in the input before converting
+    #[arg(long)]
+    unescape: bool,
+
+    /// remove zero-width characters, bidi controls and tag characters from the input
+    #[arg(long)]
+    strip_invisible: bool,
+
+    /// reverse the output by grapheme clusters; matches of --protect keep their reading order
+    #[arg(long)]
+    reverse: bool,
+
+    /// leave URLs, @mentions, #hashtags and :emoji_codes: unconverted
+    #[arg(long, conflicts_with = "reverse")]
+    protect_entities: bool,
+
+    /// leave matches of a regular expression as they are, e.g. ticket IDs or email addresses; repeatable
+    #[arg(long, value_name = "REGEX")]
+    protect: Vec<Regex>,
+
+    /// leave a character after a backslash and spans written as {{raw:...}} unconverted
+    #[arg(long, conflicts_with_all = ["reverse", "markdown"])]
+    escapes: bool,
+
+    /// treat the input as Markdown: code, link destinations and autolinks are left unconverted
+    #[arg(long, conflicts_with_all = ["reverse", "spacing"])]
+    markdown: bool,
+
+    /// with --markdown, style code as monospace instead of leaving it unconverted
+    #[arg(long, requires = "markdown")]
+    style_code: bool,
+
+    /// show keycaps and enclosed characters (decorated with a circle or square) as text or as emoji
+    #[arg(value_enum, long, conflicts_with = "ascii")]
+    presentation: Option<Presentation>,
+
+    /// replace characters outside ascii, wgl4 or bmp by their closest approximation inside it, e.g. 𝒽 by ℎ for bmp
+    #[arg(value_enum, long)]
+    downgrade: Option<CompatProfile>,
+
+    /// number the lines of the output with enclosed numbers: fullstop (⒈), parenthesized (⑴) or circled (①)
+    #[arg(value_enum, long, value_name = "MARKER", conflicts_with_all = ["check", "diff"])]
+    enumerate: Option<ListMarker>,
+
+    /// truncate the output to fit a platform limit: twitter, discord or a number of characters
+    #[arg(long)]
+    limit: Option<Limit>,
+
+    /// with --ascii, write σ at the end of a Greek word as ς
+    #[arg(long, requires = "ascii")]
+    final_sigma: bool,
+
     /// turn styled letters to ASCII letters
     #[arg(long)]
     ascii: bool,
+
+    /// with --ascii, also fold lookalikes such as Cyrillic а, fullwidth and circled letters to ASCII
+    #[arg(long, requires = "ascii", conflicts_with = "final_sigma")]
+    aggressive: bool,
+
+    /// don't print conversion errors, only how many there were; twice for no output at all
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    quiet: u8,
+
+    /// write errors and warnings on stderr as text or as one JSON object per line with a stable code
+    #[arg(value_enum, long, value_name = "FORMAT", default_value = "text")]
+    errors: ErrorFormat,
+
+    /// language of the help and of error messages; defaults to the one of LC_ALL,
+    /// LC_MESSAGES or LANG
+    #[cfg(feature = "i18n")]
+    #[arg(value_enum, long)]
+    lang: Option<Lang>,
+
+    /// write the output as text, as escapes for string literals in source code, with HTML numeric character references or percent-encoded for URLs
+    #[arg(
+        value_enum,
+        long,
+        default_value = "text",
+        conflicts_with_all = ["check", "table"]
+    )]
+    format: OutputFormat,
+
+    /// with --format escapes, the language whose escapes to write: rust (or js), utf16 (or json) or python
+    #[arg(value_enum, long, default_value = "rust")]
+    escape_flavor: EscapeFlavor,
+
+    /// encode the output as utf8, utf8-bom, utf16le or utf16be; UTF-16 starts with a byte order mark
+    #[arg(value_enum, long, value_name = "ENCODING", default_value = "utf8")]
+    output_encoding: OutputEncoding,
+
+    /// report on stderr how much of the output screen readers may fail to read
+    #[arg(long)]
+    warn_a11y: bool,
+
+    /// report on stderr output characters outside the BMP or the WGL4 set of older fonts
+    #[arg(long)]
+    warn_compat: bool,
+
+    /// report on stderr output characters that common platforms' fonts likely can't show;
+    /// experimental, the font coverage data is hand-written and unverified
+    #[arg(long)]
+    warn_coverage: bool,
 }
 
-fn main() {
-    let args = AppArgs::parse();
+#[derive(Subcommand)]
+enum Command {
+    /// strip styling back to plain text that screen readers can read
+    A11y {
+        text: String,
+
+        /// print a report of the styled characters instead of the plain text
+        #[arg(long)]
+        report: bool,
+    },
+    /// report lines containing styled or mixed-script lookalike text; exits with 1 if any are found
+    DetectSpoof {
+        /// files to scan; reads stdin when none are given
+        files: Vec<PathBuf>,
+    },
+    /// report zero-width characters, bidi controls and tag characters; exits with 1 if any are found
+    DetectInvisible {
+        /// files to scan; reads stdin when none are given
+        files: Vec<PathBuf>,
+    },
+    /// print the lines that have styled letters or digits; exits with 1 if no line does
+    Grep {
+        /// files to search; reads stdin when none are given
+        files: Vec<PathBuf>,
+
+        /// print the lines without styled letters or digits instead
+        #[arg(short = 'v', long)]
+        invert: bool,
+
+        /// only count letters and digits in these fonts as styled
+        #[arg(
+            value_parser = SuggestingParser::<StyledLetter>::new(),
+            long,
+            value_delimiter = ','
+        )]
+        font: Vec<StyledLetter>,
+    },
+    /// replace a text everywhere it occurs, styled or not, with a replacement in the same font and style
+    Sub {
+        /// text to replace, matched against the de-styled input
+        pattern: String,
+
+        /// text to replace it with
+        replacement: String,
+
+        /// files to edit; reads stdin when none are given
+        files: Vec<PathBuf>,
+    },
+    /// fold styled letters to plain text and remove decorations, combining mark spam and tag characters
+    Sanitize {
+        /// text to sanitize; reads stdin when not given
+        text: Option<String>,
 
-    let input: String = args.text;
-    let letter_type = args.letter_type.unwrap();
-    let letter_style = args.letter_style.unwrap();
-    if !input.is_empty() {
-        let mut res: String = String::with_capacity(input.len());
-        for ch in input.chars() {
-            match convert(ch, &letter_type, &letter_style) {
-                Ok(char) => {
-                    res.push(char);
+        /// print what was removed on stderr
+        #[arg(long)]
+        report: bool,
+    },
+    /// check the conversion tables and whether this terminal can show styled text; exits with 1 on problems
+    Doctor,
+    /// print the mapping of every character in every font and style
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+    },
+    /// print a pangram, or the given text, in every font and style
+    Samples {
+        /// text to show instead of the pangram
+        text: Option<String>,
+
+        #[arg(long, value_enum, default_value_t = SampleFormat::Text)]
+        format: SampleFormat,
+    },
+    /// convert many files at once, writing the results under an output directory; exits with 1 if
+    /// some characters could not be converted, 2 if a file could not be read or written
+    Convert {
+        /// files to convert, e.g. 'posts/**/*.txt'
+        #[arg(long)]
+        glob: String,
+
+        /// directory to write to; paths below the directory the glob starts from are kept
+        #[arg(
+            long,
+            required_unless_present = "in_place",
+            conflicts_with = "in_place"
+        )]
+        out_dir: Option<PathBuf>,
+
+        /// edit the files in place, keeping the originals with the given suffix, e.g. -i.bak
+        #[arg(
+            short = 'i',
+            long,
+            value_name = "SUFFIX",
+            num_args = 0..=1,
+            default_missing_value = ""
+        )]
+        in_place: Option<String>,
+
+        /// letter type and style, e.g. serif-bold
+        #[arg(long = "as", value_name = "SPEC", default_value = "monospace")]
+        spec: StyleSpec,
+
+        /// turn styled letters to ASCII letters instead
+        #[arg(long, conflicts_with = "spec")]
+        ascii: bool,
+
+        /// number of files to convert in parallel
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+
+        /// encode the files written as utf8, utf8-bom, utf16le or utf16be
+        #[arg(value_enum, long, value_name = "ENCODING", default_value = "utf8")]
+        output_encoding: OutputEncoding,
+    },
+    /// stream stdin to stdout as a git clean or smudge filter
+    #[command(group(clap::ArgGroup::new("direction").required(true).args(["clean", "smudge"])))]
+    Filter {
+        /// de-style the text, for the copy stored in the repository
+        #[arg(long)]
+        clean: bool,
+
+        /// style the text, for the copy in the working tree
+        #[arg(long)]
+        smudge: bool,
+
+        /// letter type and style to smudge with, e.g. serif-bold
+        #[arg(long = "as", value_name = "SPEC", default_value = "monospace")]
+        spec: StyleSpec,
+    },
+    /// count the characters, bytes, UTF-16 code units, grapheme clusters and
+    /// columns of the text before and after converting it
+    Count {
+        /// text to count; reads stdin when not given
+        text: Option<String>,
+
+        /// letter type and style to convert with, e.g. serif-bold
+        #[arg(long = "as", value_name = "SPEC", default_value = "monospace")]
+        spec: StyleSpec,
+
+        /// de-style the text instead of converting it
+        #[arg(long, conflicts_with = "spec")]
+        ascii: bool,
+    },
+    /// watch the clipboard and replace text copied to it with the styled text, until interrupted
+    WatchClipboard {
+        /// letter type and style to convert with, e.g. script-bold
+        #[arg(long = "as", value_name = "SPEC", default_value = "monospace")]
+        spec: StyleSpec,
+
+        /// de-style copied text instead of styling it
+        #[arg(long, conflicts_with = "spec")]
+        ascii: bool,
+
+        /// how often to look at the clipboard, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// write letters and digits as grade 1 Braille patterns
+    Braille {
+        /// text to write; reads stdin when not given
+        text: Option<String>,
+
+        /// read Braille patterns back to text instead
+        #[arg(long)]
+        decode: bool,
+    },
+    /// print the code point, Unicode name, category and block, font, style,
+    /// class and plain form of each character
+    #[cfg(feature = "inspect")]
+    Inspect {
+        /// text to inspect; reads stdin when not given
+        text: Option<String>,
+    },
+    /// print the runs of font, style and decorations in the text as JSON
+    Parse {
+        /// text to parse; reads stdin when not given
+        text: Option<String>,
+    },
+}
+
+// (name, content) of each input, stdin when no files are given
+fn read_inputs(files: &[PathBuf]) -> io::Result<Vec<(String, String)>> {
+    if files.is_empty() {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)?;
+        return Ok(vec![("<stdin>".to_string(), content)]);
+    }
+    files
+        .iter()
+        .map(|file| {
+            let content = std::fs::read_to_string(file)?;
+            Ok((file.display().to_string(), content))
+        })
+        .collect()
+}
+
+fn run_detect_spoof(files: &[PathBuf]) -> ExitCode {
+    let inputs = match read_inputs(files) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            print_error(e);
+            return ExitCode::from(2);
+        }
+    };
+    let mut found = false;
+    for (name, content) in inputs {
+        for finding in detect_spoof(&content) {
+            found = true;
+            println!(
+                "{}:{}:{}: {} '{}', plain form '{}'",
+                name, finding.line, finding.column, finding.kind, finding.text, finding.suggestion
+            );
+        }
+    }
+    if found {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+enum ErrorFormat {
+    Text,
+    Json,
+}
+
+// The language of the messages on stderr, set once the arguments are parsed
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+fn lang() -> Lang {
+    LANG.get().copied().unwrap_or_default()
+}
+
+// "Error: message" on stderr
+fn print_error(message: impl std::fmt::Display) {
+    eprintln!("{}", lang().error_line(&message.to_string()));
+}
+
+// An error or warning on stderr, "Error: message" or, with --errors json,
+// {"level": "error", "code": ..., "message": ...}. Codes are stable, the
+// messages may change.
+fn report(format: ErrorFormat, level: &str, code: &str, message: &str) {
+    match format {
+        ErrorFormat::Text if level == "warning" => eprintln!("{}", lang().warning_line(message)),
+        ErrorFormat::Text => print_error(message),
+        ErrorFormat::Json => eprintln!(
+            "{}",
+            serde_json::json!({"level": level, "code": code, "message": message})
+        ),
+    }
+}
+
+// Conversion errors, shown at the end with the offending characters of the
+// (preprocessed) input underlined. With --quiet they are only counted and
+// summarized (-qq drops the summary too).
+struct Diagnostics {
+    quiet: u8,
+    format: ErrorFormat,
+    errors: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    fn new(quiet: u8, format: ErrorFormat) -> Self {
+        Self {
+            quiet,
+            format,
+            errors: 0,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    // `ch` stands for the characters in `span` that failed to convert
+    fn conversion_error(
+        &mut self,
+        e: LetterTypeError,
+        span: Range<usize>,
+        ch: char,
+        converter: &Converter,
+    ) {
+        self.errors += 1;
+        let diagnostic = Diagnostic::conversion_in(
+            span,
+            ch,
+            &e,
+            converter.letter_type(),
+            converter.letter_style(),
+            &lang(),
+        );
+        // one diagnostic for a run of characters failing the same way
+        match self.diagnostics.last_mut() {
+            Some(last)
+                if last.message == diagnostic.message && last.span.end == diagnostic.span.start =>
+            {
+                last.span.end = diagnostic.span.end
+            }
+            _ => self.diagnostics.push(diagnostic),
+        }
+    }
+
+    fn summarize(&self, source: &str) {
+        if self.format == ErrorFormat::Json {
+            return self.summarize_json();
+        }
+        match self.quiet {
+            0 => {
+                for diagnostic in self.diagnostics.iter() {
+                    eprintln!("{}", diagnostic.render_in(source, &lang()));
                 }
-                Err(e) => {
-                    eprintln!("Error: {} for {} using {:?}-{:?}", e, ch, letter_type, letter_style);
+            }
+            1 if self.errors > 0 => eprintln!("{}", lang().conversion_summary(self.errors)),
+            _ => {}
+        }
+    }
+
+    // one object per diagnostic, or with --quiet one with the count
+    fn summarize_json(&self) {
+        match self.quiet {
+            0 => {
+                for diagnostic in self.diagnostics.iter() {
+                    let mut json = serde_json::json!(diagnostic);
+                    json["level"] = "error".into();
+                    eprintln!("{}", json);
                 }
             }
+            1 if self.errors > 0 => eprintln!(
+                "{}",
+                serde_json::json!({
+                    "level": "error",
+                    "code": "conversion-errors",
+                    "message": format!("{} conversion errors", self.errors),
+                    "count": self.errors,
+                })
+            ),
+            _ => {}
         }
-        println!("{}", res);
     }
 }
+
+// `offset` is the char index of `text` in the whole input
+fn convert_chars(
+    text: &str,
+    offset: usize,
+    converter: &Converter,
+    diagnostics: &mut Diagnostics,
+) -> String {
+    let mut res: String = String::with_capacity(text.len());
+    let mut idx = offset;
+    // the callback never stops the walk
+    let _ = converter.convert_each(text, |_, ch, converted| {
+        match converted {
+            Ok(converted) => res.push(converted),
+            Err(e) => diagnostics.conversion_error(e, idx..idx + 1, ch, converter),
+        }
+        idx += 1;
+        Ok(())
+    });
+    res
+}
+
+fn run_detect_invisible(files: &[PathBuf]) -> ExitCode {
+    let inputs = match read_inputs(files) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            print_error(e);
+            return ExitCode::from(2);
+        }
+    };
+    let mut found = false;
+    for (name, content) in inputs {
+        for run in find_invisible(&content) {
+            found = true;
+            println!(
+                "{}:{}:{}: {} x{}",
+                name, run.line, run.column, run.kind, run.len
+            );
+        }
+    }
+    if found {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_grep(files: &[PathBuf], invert: bool, fonts: &[StyledLetter]) -> ExitCode {
+    let inputs = match read_inputs(files) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            print_error(e);
+            return ExitCode::from(2);
+        }
+    };
+    let mut found = false;
+    for (name, content) in &inputs {
+        for line in content.lines() {
+            if contains_styled(line, fonts) == invert {
+                continue;
+            }
+            found = true;
+            if inputs.len() > 1 {
+                println!("{}:{}", name, line);
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+    if found {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_sub(pattern: &str, replacement: &str, files: &[PathBuf]) -> ExitCode {
+    let inputs = match read_inputs(files) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            print_error(e);
+            return ExitCode::from(2);
+        }
+    };
+    for (_, content) in inputs {
+        print!("{}", substitute(&content, pattern, replacement));
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_sanitize(text: Option<String>, report: bool) -> ExitCode {
+    let (sanitized, sanitize_report) = match text {
+        Some(text) => {
+            let (sanitized, sanitize_report) = sanitize(&text);
+            (sanitized + "\n", sanitize_report)
+        }
+        None => {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                print_error(e);
+                return ExitCode::from(2);
+            }
+            sanitize(&content)
+        }
+    };
+    print!("{}", sanitized);
+    if report {
+        eprintln!("{}", sanitize_report);
+    }
+    ExitCode::SUCCESS
+}
+
+// The locale decides the encoding of terminal output on Unix.
+fn check_locale() -> DoctorCheck {
+    let mut problems = Vec::new();
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter().find_map(|name| {
+        std::env::var(name)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(|value| (name, value))
+    });
+    match locale {
+        Some((name, value)) => {
+            let lower = value.to_lowercase();
+            if !lower.contains("utf-8") && !lower.contains("utf8") {
+                problems.push(format!("{}={} is not a UTF-8 locale", name, value));
+            }
+        }
+        None if cfg!(windows) => {}
+        None => problems.push("none of LC_ALL, LC_CTYPE and LANG is set".to_string()),
+    }
+    DoctorCheck::new("UTF-8 locale", problems)
+}
+
+// The Linux console's fonts have no glyphs outside the BMP.
+fn check_terminal() -> DoctorCheck {
+    let mut problems = Vec::new();
+    if io::stdout().is_terminal() {
+        if let Ok(term @ ("linux" | "dumb")) = std::env::var("TERM").as_deref() {
+            problems.push(format!(
+                "TERM={} can't show mathematical alphanumerics",
+                term
+            ));
+        }
+    }
+    DoctorCheck::new("terminal", problems)
+}
+
+fn run_doctor() -> ExitCode {
+    let mut report = doctor();
+    report.checks.push(check_locale());
+    report.checks.push(check_terminal());
+    println!("{}", report);
+    if report.is_ready() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// The directory a glob starts from: its components before the first one
+// with a wildcard.
+fn glob_base(pattern: &str) -> PathBuf {
+    Path::new(pattern)
+        .components()
+        .take_while(|component| {
+            !component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(['*', '?', '['])
+        })
+        .collect()
+}
+
+// (converted text, characters changed, characters that couldn't be converted)
+fn convert_file_text(text: &str, converter: Option<&Converter>) -> (String, usize, usize) {
+    let converter = match converter {
+        Some(converter) => converter,
+        None => {
+            let res = to_plain(text);
+            let changed = diff_lines(text, &res).changed_chars;
+            return (res, changed, 0);
+        }
+    };
+    let converted = converter.convert_str_lossy(text);
+    let res = converted.output;
+    let errors = converted.failures.len();
+    let changed = diff_lines(text, &res).changed_chars;
+    (res, changed, errors)
+}
+
+// Where converted files go.
+enum Destination<'a> {
+    // below this directory
+    Dir(&'a Path),
+    // over the input file, keeping the original with this suffix unless empty
+    InPlace(&'a str),
+}
+
+// Replace `file` by a temporary file renamed over it, so it is never left
+// half written.
+fn write_atomically(file: &Path, content: &[u8]) -> io::Result<()> {
+    let name = file.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = file.with_file_name(format!(".{}.styledtext.tmp", name));
+    std::fs::write(&tmp, content)?;
+    let result = std::fs::metadata(file)
+        .and_then(|metadata| std::fs::set_permissions(&tmp, metadata.permissions()))
+        .and_then(|_| std::fs::rename(&tmp, file));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+fn convert_file(
+    file: &Path,
+    base: &Path,
+    destination: &Destination,
+    converter: Option<&Converter>,
+    encoding: &OutputEncoding,
+) -> io::Result<(String, usize)> {
+    let (text, input_encoding) = OutputEncoding::decode(&std::fs::read(file)?)?;
+    let (res, changed, errors) = convert_file_text(&text, converter);
+    let content = [encoding.bom(), &encoding.encode(&res)].concat();
+    let out = match *destination {
+        Destination::Dir(out_dir) => {
+            let out = out_dir.join(file.strip_prefix(base).unwrap_or(file));
+            if let Some(parent) = out.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&out, content)?;
+            out
+        }
+        // files whose text and encoding stay the same are left alone
+        Destination::InPlace(_) if changed == 0 && input_encoding == *encoding => {
+            file.to_path_buf()
+        }
+        Destination::InPlace(suffix) => {
+            if !suffix.is_empty() {
+                let mut backup = file.as_os_str().to_os_string();
+                backup.push(suffix);
+                std::fs::copy(file, backup)?;
+            }
+            write_atomically(file, &content)?;
+            file.to_path_buf()
+        }
+    };
+    let stats = format!(
+        "{} -> {}: {} character{} converted, {} error{}",
+        file.display(),
+        out.display(),
+        changed,
+        if changed == 1 { "" } else { "s" },
+        errors,
+        if errors == 1 { "" } else { "s" }
+    );
+    Ok((stats, errors))
+}
+
+fn run_convert(
+    pattern: &str,
+    destination: Destination,
+    spec: &StyleSpec,
+    ascii: bool,
+    jobs: usize,
+    encoding: &OutputEncoding,
+) -> ExitCode {
+    let files: Vec<PathBuf> = match glob::glob(pattern) {
+        Ok(paths) => paths
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect(),
+        Err(e) => {
+            print_error(lang().invalid_glob(pattern, &e.to_string()));
+            return ExitCode::from(2);
+        }
+    };
+    let base = glob_base(pattern);
+    let converter = Converter::new(spec.letter_type, spec.letter_style);
+    let converter = if ascii { None } else { Some(&converter) };
+    let chunk_size = files.len().div_ceil(jobs.max(1)).max(1);
+    let results: Vec<(&PathBuf, io::Result<(String, usize)>)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = files
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let base = &base;
+                let destination = &destination;
+                let handle = scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|file| {
+                            let result = convert_file(file, base, destination, converter, encoding);
+                            (file, result)
+                        })
+                        .collect::<Vec<_>>()
+                });
+                (chunk, handle)
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|(chunk, handle)| {
+                // the files of a worker that panicked may or may not have
+                // been written
+                handle.join().unwrap_or_else(|_| {
+                    chunk
+                        .iter()
+                        .map(|file| (file, Err(io::Error::other(lang().conversion_panicked()))))
+                        .collect()
+                })
+            })
+            .collect()
+    });
+    let mut failed = false;
+    let mut errors = 0;
+    for (file, result) in results {
+        match result {
+            Ok((stats, file_errors)) => {
+                println!("{}", stats);
+                errors += file_errors;
+            }
+            Err(e) => {
+                print_error(format_args!("{}: {}", file.display(), e));
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        ExitCode::from(2)
+    } else if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_filter(smudge: bool, spec: &StyleSpec) -> ExitCode {
+    let mode = if smudge {
+        FilterMode::Smudge(Converter::new(spec.letter_type, spec.letter_style))
+    } else {
+        FilterMode::Clean
+    };
+    match filter(io::stdin().lock(), io::stdout().lock(), &mode) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            print_error(e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run_count(text: Option<String>, spec: &StyleSpec, ascii: bool) -> ExitCode {
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                print_error(e);
+                return ExitCode::from(2);
+            }
+            content.trim_end_matches('\n').to_string()
+        }
+    };
+    let converter = Converter::new(spec.letter_type, spec.letter_style);
+    let (res, _, _) = convert_file_text(&text, (!ascii).then_some(&converter));
+    let rows: Vec<Vec<String>> = [("input", &text), ("output", &res)]
+        .iter()
+        .map(|(name, text)| {
+            let counts = TextCounts::of(text);
+            vec![
+                name.to_string(),
+                counts.chars.to_string(),
+                counts.bytes.to_string(),
+                counts.utf16.to_string(),
+                counts.graphemes.to_string(),
+                counts.width.to_string(),
+            ]
+        })
+        .collect();
+    println!(
+        "{}",
+        format_table(
+            &["", "CHARS", "BYTES", "UTF-16", "GRAPHEMES", "WIDTH"],
+            &rows
+        )
+    );
+    ExitCode::SUCCESS
+}
+
+// The commands that read and write the clipboard: pbpaste and pbcopy on
+// macOS, wl-clipboard on Wayland and xclip on X11.
+type ClipboardCommand = &'static [&'static str];
+
+fn clipboard_commands() -> Option<(ClipboardCommand, ClipboardCommand)> {
+    if cfg!(target_os = "macos") {
+        Some((&["pbpaste"], &["pbcopy"]))
+    } else if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        Some((&["wl-paste", "--no-newline"], &["wl-copy"]))
+    } else if std::env::var_os("DISPLAY").is_some() {
+        Some((
+            &["xclip", "-selection", "clipboard", "-o"],
+            &["xclip", "-selection", "clipboard"],
+        ))
+    } else {
+        None
+    }
+}
+
+fn read_clipboard(command: ClipboardCommand) -> io::Result<String> {
+    let output = std::process::Command::new(command[0])
+        .args(&command[1..])
+        .stderr(Stdio::null())
+        .output()?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn write_clipboard(command: ClipboardCommand, text: &str) -> io::Result<()> {
+    let mut child = std::process::Command::new(command[0])
+        .args(&command[1..])
+        .stdin(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(text.as_bytes())?;
+    child.wait()?;
+    Ok(())
+}
+
+fn run_watch_clipboard(spec: &StyleSpec, ascii: bool, interval: u64) -> ExitCode {
+    let Some((paste, copy)) = clipboard_commands() else {
+        print_error(lang().no_clipboard());
+        return ExitCode::from(2);
+    };
+    let converter = Converter::new(spec.letter_type, spec.letter_style);
+    let converter = (!ascii).then_some(&converter);
+    // what we last put on the clipboard, not to convert it again
+    let mut written = String::new();
+    loop {
+        let text = match read_clipboard(paste) {
+            Ok(text) => text,
+            Err(e) => {
+                print_error(e);
+                return ExitCode::from(2);
+            }
+        };
+        if !text.is_empty() && text != written {
+            let (res, changed, _) = convert_file_text(&text, converter);
+            if changed > 0 {
+                if let Err(e) = write_clipboard(copy, &res) {
+                    print_error(e);
+                    return ExitCode::from(2);
+                }
+            }
+            written = res;
+        }
+        std::thread::sleep(Duration::from_millis(interval));
+    }
+}
+
+fn run_braille(text: Option<String>, decode: bool) -> ExitCode {
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                print_error(e);
+                return ExitCode::from(2);
+            }
+            content.trim_end_matches('\n').to_string()
+        }
+    };
+    if decode {
+        println!("{}", from_braille(&text));
+    } else {
+        println!("{}", to_braille(&text));
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_parse(text: Option<String>) -> ExitCode {
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                print_error(e);
+                return ExitCode::from(2);
+            }
+            content
+        }
+    };
+    match serde_json::to_string_pretty(&StyledDocument::parse(&text)) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            print_error(e);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// the name of a value on the command line, e.g. "sansserif"
+#[cfg(feature = "terminal")]
+fn value_name<T: ValueEnum>(value: &T) -> String {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+// The text sheet is a preview with dimmed labels when printed to a terminal
+fn run_samples(text: &str, format: SampleFormat) -> ExitCode {
+    #[cfg(feature = "terminal")]
+    if format == SampleFormat::Text {
+        let mut preview = TerminalPreview::new(&["FONT", "STYLE", "SAMPLE"]);
+        for letter_type in StyledLetter::ALL {
+            for letter_style in LetterStyle::ALL {
+                if Converter::new(*letter_type, *letter_style)
+                    .convert_char('A')
+                    .is_ok()
+                {
+                    preview.push(
+                        &[&value_name(letter_type), &value_name(letter_style)],
+                        StyledDocument::new(vec![Run::new(text, *letter_type, *letter_style)]),
+                    );
+                }
+            }
+        }
+        return match preview.write_to(&mut anstream::stdout()) {
+            Ok(()) => ExitCode::SUCCESS,
+            // e.g. piped to head
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+            Err(e) => {
+                print_error(e);
+                ExitCode::from(2)
+            }
+        };
+    }
+    print_listing(&samples(text, format))
+}
+
+#[cfg(feature = "inspect")]
+fn run_inspect(text: Option<String>) -> ExitCode {
+    let text = match text {
+        Some(text) => text,
+        None => {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                print_error(e);
+                return ExitCode::from(2);
+            }
+            content.trim_end_matches('\n').to_string()
+        }
+    };
+    let rows: Vec<Vec<String>> = inspect(&text).iter().map(|ch| ch.row()).collect();
+    println!("{}", format_table(&INSPECT_HEADERS, &rows));
+    ExitCode::SUCCESS
+}
+
+// How a part of the input is converted.
+#[derive(Clone, Copy)]
+enum Part {
+    Text,
+    Code,
+    Keep,
+    // an escaped span, with the length of its escape in chars
+    Raw(usize),
+}
+
+fn convert_segment(
+    input: &str,
+    offset: usize,
+    converter: &Converter,
+    args: &AppArgs,
+    diagnostics: &mut Diagnostics,
+) -> String {
+    // transforms keep the length of the text, so offsets stay valid
+    #[cfg(feature = "fun")]
+    let transformed = args.transform.map(|transform| transform.apply(input));
+    #[cfg(feature = "fun")]
+    let input = transformed.as_deref().unwrap_or(input);
+    if args.only_styled {
+        let mut res = String::with_capacity(input.len());
+        for (idx, ch) in input.chars().enumerate() {
+            if Classification::of(ch).is_styled() {
+                let converted =
+                    convert_chars(&ch.to_string(), offset + idx, converter, diagnostics);
+                res.push_str(&converted);
+            } else {
+                res.push(ch);
+            }
+        }
+        return res;
+    }
+    if !args.numbers {
+        return convert_chars(input, offset, converter, diagnostics);
+    }
+    let options = NumberOptions {
+        unicode_minus: args.unicode_minus,
+        figure_space: args.figure_space,
+    };
+    let mut res = String::with_capacity(input.len());
+    let mut offset = offset;
+    for segment in split_numbers(input) {
+        match segment {
+            NumberSegment::Number(number) => {
+                let len = number.chars().count();
+                match converter.convert_number(number, &options) {
+                    Ok(converted) => res.push_str(&converted),
+                    Err(e) => {
+                        diagnostics.conversion_error(e, offset..offset + len, '0', converter);
+                        res.push_str(number);
+                    }
+                }
+                offset += len;
+            }
+            NumberSegment::Text(text) => {
+                res.push_str(&convert_chars(text, offset, converter, diagnostics));
+                offset += text.chars().count();
+            }
+        }
+    }
+    res
+}
+
+fn convert_text(
+    input: &str,
+    converter: &Converter,
+    args: &AppArgs,
+    diagnostics: &mut Diagnostics,
+) -> String {
+    let parts = if args.markdown {
+        split_markdown(input)
+            .into_iter()
+            .map(|segment| match segment {
+                MarkdownSegment::Text(text) => (text, Part::Text),
+                MarkdownSegment::Code(code) => (code, Part::Code),
+                MarkdownSegment::Syntax(syntax) => (syntax, Part::Keep),
+            })
+            .collect()
+    } else if args.escapes {
+        split_escapes(input)
+            .into_iter()
+            .map(|segment| match segment {
+                EscapeSegment::Text(text) => (text, Part::Text),
+                EscapeSegment::Raw { raw, source } => (raw, Part::Raw(source.chars().count())),
+            })
+            .collect()
+    } else {
+        vec![(input, Part::Text)]
+    };
+    let parts: Vec<(&str, Part)> = if args.protect.is_empty() {
+        parts
+    } else {
+        parts
+            .into_iter()
+            .flat_map(|(part, kind)| match kind {
+                Part::Text | Part::Code => split_protected(part, &args.protect)
+                    .into_iter()
+                    .map(|segment| match segment {
+                        ProtectedSegment::Protected(protected) => (protected, Part::Keep),
+                        ProtectedSegment::Text(text) => (text, kind),
+                    })
+                    .collect(),
+                kind => vec![(part, kind)],
+            })
+            .collect()
+    };
+    let code_converter = Converter::new(StyledLetter::MonoSpace, LetterStyle::Normal);
+    // (text, whether it may be changed)
+    let mut pieces: Vec<(String, bool)> = Vec::new();
+    let mut offset = 0;
+    for (part, kind) in parts {
+        match kind {
+            Part::Text if args.protect_entities => {
+                for segment in split_entities(part) {
+                    match segment {
+                        EntitySegment::Entity(_, entity) => {
+                            pieces.push((entity.to_string(), false));
+                            offset += entity.chars().count();
+                        }
+                        EntitySegment::Text(text) => {
+                            let converted =
+                                convert_segment(text, offset, converter, args, diagnostics);
+                            pieces.push((converted, true));
+                            offset += text.chars().count();
+                        }
+                    }
+                }
+                continue;
+            }
+            Part::Text => {
+                let converted = convert_segment(part, offset, converter, args, diagnostics);
+                pieces.push((converted, true));
+            }
+            Part::Code if args.style_code => {
+                let converted = convert_chars(part, offset, &code_converter, diagnostics);
+                pieces.push((converted, false));
+            }
+            Part::Code | Part::Keep => pieces.push((part.to_string(), false)),
+            Part::Raw(source_len) => {
+                pieces.push((part.to_string(), false));
+                offset += source_len;
+                continue;
+            }
+        }
+        offset += part.chars().count();
+    }
+    // --reverse conflicts with everything that splits the input but
+    // --protect, whose matches move as a whole
+    if args.reverse {
+        pieces.reverse();
+        for (piece, changeable) in pieces.iter_mut() {
+            if *changeable {
+                *piece = reverse(piece);
+            }
+        }
+    }
+    match args.spacing.as_ref().and_then(Spacing::separator) {
+        // spacing goes between the pieces too, but never inside an entity
+        Some(separator) => {
+            let pieces: Vec<String> = pieces
+                .into_iter()
+                .map(|(piece, changeable)| {
+                    if changeable {
+                        add_spacing(&piece, &Spacing::Char(separator))
+                    } else {
+                        piece
+                    }
+                })
+                .collect();
+            pieces.join(&separator.to_string())
+        }
+        None => pieces.into_iter().map(|(piece, _)| piece).collect(),
+    }
+}
+
+// Apply --limit and print the requested warnings about the output.
+fn finish_output(mut res: String, args: &AppArgs) -> String {
+    if let Some(ref presentation) = args.presentation {
+        res = set_presentation(&res, presentation);
+    }
+    if let Some(ref profile) = args.downgrade {
+        res = downgrade(&res, profile);
+    }
+    if let Some(ref marker) = args.enumerate {
+        res = enumerate_lines(&res, marker);
+    }
+    if let Some(ref limit) = args.limit {
+        res = truncate(&res, limit);
+    }
+    if args.warn_a11y {
+        let a11y = a11y_report(&res);
+        if !a11y.is_readable() {
+            report(args.errors, "warning", "a11y", &a11y.to_string());
+        }
+    }
+    if args.warn_compat {
+        let compat = compat_report(&res);
+        if !compat.is_compatible() {
+            report(args.errors, "warning", "compat", &compat.to_string());
+        }
+    }
+    if args.warn_coverage {
+        let coverage = coverage_report(&res);
+        for line in coverage.to_string().lines() {
+            report(args.errors, "warning", "coverage", line);
+        }
+    }
+    res
+}
+
+// The converter for a spec with the Greek variant and fallback options.
+fn converter_for(spec: &StyleSpec, args: &AppArgs) -> Converter {
+    let converter = Converter::new(spec.letter_type, spec.letter_style)
+        .with_greek_variants(GreekVariantPolicy::new(args.greek_variants));
+    let converter = match args.digit_fallback {
+        Some(ref digit_fallback) => {
+            converter.with_fallback(CharacterType::Digit, digit_fallback.clone())
+        }
+        None => converter,
+    };
+    match args.fallback_chain {
+        Some(ref chain) if chain.is_empty() => converter.with_default_fallback_chain(),
+        Some(ref chain) => converter.with_fallback_chain(chain.clone()),
+        None => converter,
+    }
+}
+
+// The generator behind --random: SplitMix64, small and good enough to pick
+// styles, and the same output for the same --seed everywhere.
+#[cfg(feature = "random")]
+struct SplitMix64(u64);
+
+#[cfg(feature = "random")]
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+// a different seed on every run when --seed isn't given
+#[cfg(feature = "random")]
+fn time_seed() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+    nanos ^ (u64::from(std::process::id()) << 32)
+}
+
+// The text in pieces, each paired with whether it is outside the matches of
+// --protect.
+fn protected_pieces<'a>(text: &'a str, args: &AppArgs) -> Vec<(&'a str, bool)> {
+    if args.protect.is_empty() {
+        return vec![(text, true)];
+    }
+    split_protected(text, &args.protect)
+        .into_iter()
+        .map(|segment| match segment {
+            ProtectedSegment::Protected(protected) => (protected, false),
+            ProtectedSegment::Text(text) => (text, true),
+        })
+        .collect()
+}
+
+// `f` applied to the parts of `text` outside the matches of --protect
+fn outside_protected<F>(text: &str, args: &AppArgs, mut f: F) -> String
+where
+    F: FnMut(&str) -> String,
+{
+    protected_pieces(text, args)
+        .into_iter()
+        .map(|(piece, change)| if change { f(piece) } else { piece.to_string() })
+        .collect()
+}
+
+// --reverse: the matches of --protect move as a whole and still read as
+// they did, the text around them is reversed
+fn reverse_outside_protected(text: &str, args: &AppArgs) -> String {
+    protected_pieces(text, args)
+        .into_iter()
+        .rev()
+        .map(|(piece, change)| {
+            if change {
+                reverse(piece)
+            } else {
+                piece.to_string()
+            }
+        })
+        .collect()
+}
+
+// Where the text to convert comes from, in order of precedence.
+enum Source<'a> {
+    Text(&'a str),
+    File(&'a Path),
+    Stdin,
+    Clipboard,
+}
+
+impl<'a> Source<'a> {
+    // None when no source is given and stdin is a terminal
+    fn of(args: &'a AppArgs) -> Option<Self> {
+        if let Some(ref text) = args.text {
+            Some(Source::Text(text))
+        } else if let Some(ref file) = args.input {
+            Some(Source::File(file))
+        } else if args.paste {
+            Some(Source::Clipboard)
+        } else if args.stdin || !io::stdin().is_terminal() {
+            Some(Source::Stdin)
+        } else {
+            None
+        }
+    }
+
+    // the text, without the line break that ends files and piped input
+    fn read(&self) -> io::Result<String> {
+        let content = match *self {
+            Source::Text(text) => return Ok(text.to_string()),
+            Source::File(file) => std::fs::read_to_string(file)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", file.display(), e)))?,
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                content
+            }
+            Source::Clipboard => match clipboard_commands() {
+                Some((paste, _)) => read_clipboard(paste)?,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        lang().no_clipboard(),
+                    ))
+                }
+            },
+        };
+        Ok(content.trim_end_matches('\n').to_string())
+    }
+}
+
+// Print a line of output in `encoding`, after the byte order mark if it is
+// the first
+fn print_line(line: &str, encoding: &OutputEncoding) -> io::Result<()> {
+    static STARTED: AtomicBool = AtomicBool::new(false);
+    let mut bytes = Vec::with_capacity(line.len() + 4);
+    if !STARTED.swap(true, Ordering::Relaxed) {
+        bytes.extend_from_slice(encoding.bom());
+    }
+    bytes.extend(encoding.encode(line));
+    bytes.extend(encoding.encode("\n"));
+    io::stdout().lock().write_all(&bytes)
+}
+
+// Print a whole listing or report; piped to e.g. `head -1`, the closed pipe
+// ends it quietly instead of panicking like println! does
+fn print_listing(text: &str) -> ExitCode {
+    match writeln!(io::stdout().lock(), "{}", text) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            print_error(e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+// Print the output, or with --check what it would change in the input
+fn print_output(res: &str, source: &str, args: &AppArgs) -> ExitCode {
+    let res = &format_output(res, &args.format, &args.escape_flavor);
+    if args.copy_osc52 {
+        let tmux = std::env::var_os("TMUX").is_some();
+        print!("{}", osc52(res, tmux));
+        return ExitCode::SUCCESS;
+    }
+    let (output, exit_code) = if let Some(ref style) = args.diff {
+        (diff(source, res, style), ExitCode::SUCCESS)
+    } else if !args.check {
+        (res.to_string(), ExitCode::SUCCESS)
+    } else {
+        let report = diff_lines(source, res);
+        let exit_code = if report.is_unchanged() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::from(3)
+        };
+        (report.to_string(), exit_code)
+    };
+    match print_line(&output, &args.output_encoding) {
+        Ok(()) => exit_code,
+        Err(e) => {
+            report(args.errors, "fatal", "output", &e.to_string());
+            ExitCode::from(2)
+        }
+    }
+}
+
+// The language of the help, from --lang or the locale. The help is built
+// before the arguments are parsed, so --lang is looked for by hand.
+#[cfg(feature = "i18n")]
+fn help_lang() -> Lang {
+    let mut args = std::env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        let value = match arg.to_str() {
+            Some("--") => break,
+            Some("--lang") => args.next(),
+            Some(arg) => match arg.strip_prefix("--lang=") {
+                Some(value) => Some(value.into()),
+                None => continue,
+            },
+            None => continue,
+        };
+        let lang = value
+            .as_deref()
+            .and_then(OsStr::to_str)
+            .and_then(|value| Lang::from_str(value, true).ok());
+        if let Some(lang) = lang {
+            return lang;
+        }
+        break;
+    }
+    Lang::from_env()
+}
+
+// The command line with its help in `lang`; clap's own messages stay English
+#[cfg(feature = "i18n")]
+fn localized_command(lang: &Lang) -> clap::Command {
+    let command = AppArgs::command();
+    let Some(help) = lang.help_text() else {
+        return command;
+    };
+    let ids: Vec<String> = command
+        .get_arguments()
+        .map(|arg| arg.get_id().to_string())
+        .collect();
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+    // clap's --help can't be translated, so it is replaced in every command
+    let help_flag = Arg::new("help")
+        .short('h')
+        .long("help")
+        .action(ArgAction::Help)
+        .help(help.help_flag);
+    let mut command = command
+        .about(help.about)
+        .after_help(help.exit_status)
+        .disable_help_flag(true)
+        .disable_version_flag(true)
+        .arg(help_flag.clone())
+        .arg(
+            Arg::new("version")
+                .short('V')
+                .long("version")
+                .action(ArgAction::Version)
+                .help(help.version_flag),
+        );
+    for id in ids {
+        if let Some(text) = help.arg(&id) {
+            command = command.mut_arg(id, |arg| arg.help(text));
+        }
+    }
+    for name in names {
+        command = command.mut_subcommand(&name, |subcommand| {
+            let ids: Vec<String> = subcommand
+                .get_arguments()
+                .map(|arg| arg.get_id().to_string())
+                .collect();
+            let mut subcommand = subcommand.arg(help_flag.clone());
+            if let Some(text) = help.command(&name) {
+                subcommand = subcommand.about(text);
+            }
+            for id in ids {
+                if let Some(text) = help.command_arg(&name, &id) {
+                    subcommand = subcommand.mut_arg(id, |arg| arg.help(text));
+                }
+            }
+            subcommand
+        });
+    }
+    command
+}
+
+#[cfg(feature = "i18n")]
+fn parse_args() -> AppArgs {
+    let matches = localized_command(&help_lang()).get_matches();
+    AppArgs::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+}
+
+#[cfg(not(feature = "i18n"))]
+fn parse_args() -> AppArgs {
+    AppArgs::parse()
+}
+
+// The language of the messages: with translations, from --lang or the locale
+#[cfg(feature = "i18n")]
+fn message_lang(args: &AppArgs) -> Lang {
+    args.lang.unwrap_or_else(Lang::from_env)
+}
+
+#[cfg(not(feature = "i18n"))]
+fn message_lang(_: &AppArgs) -> Lang {
+    Lang::En
+}
+
+fn main() -> ExitCode {
+    let args = parse_args();
+    LANG.set(message_lang(&args))
+        .expect("the message language is set once");
+
+    match args.command {
+        Some(Command::A11y { text, report }) => {
+            if report {
+                println!("{}", a11y_report(&text));
+            } else {
+                println!("{}", to_plain(&text));
+            }
+            return ExitCode::SUCCESS;
+        }
+        Some(Command::DetectSpoof { files }) => return run_detect_spoof(&files),
+        Some(Command::DetectInvisible { files }) => return run_detect_invisible(&files),
+        Some(Command::Grep {
+            files,
+            invert,
+            font,
+        }) => return run_grep(&files, invert, &font),
+        Some(Command::Sub {
+            pattern,
+            replacement,
+            files,
+        }) => return run_sub(&pattern, &replacement, &files),
+        Some(Command::Sanitize { text, report }) => return run_sanitize(text, report),
+        Some(Command::Count { text, spec, ascii }) => return run_count(text, &spec, ascii),
+        Some(Command::WatchClipboard {
+            spec,
+            ascii,
+            interval,
+        }) => return run_watch_clipboard(&spec, ascii, interval),
+        Some(Command::Braille { text, decode }) => return run_braille(text, decode),
+        #[cfg(feature = "inspect")]
+        Some(Command::Inspect { text }) => return run_inspect(text),
+        Some(Command::Parse { text }) => return run_parse(text),
+        Some(Command::Doctor) => return run_doctor(),
+        Some(Command::Samples { text, format }) => {
+            return run_samples(text.as_deref().unwrap_or(PANGRAM), format);
+        }
+        Some(Command::Convert {
+            glob,
+            out_dir,
+            in_place,
+            spec,
+            ascii,
+            jobs,
+            output_encoding,
+        }) => {
+            let destination = match (&out_dir, &in_place) {
+                (_, Some(suffix)) => Destination::InPlace(suffix),
+                (Some(out_dir), None) => Destination::Dir(out_dir),
+                (None, None) => unreachable!("--out-dir is required without --in-place"),
+            };
+            return run_convert(&glob, destination, &spec, ascii, jobs, &output_encoding);
+        }
+        Some(Command::Filter { smudge, spec, .. }) => return run_filter(smudge, &spec),
+        Some(Command::Export { format }) => {
+            return print_listing(&export(format));
+        }
+        None => {}
+    }
+
+    if args.capabilities {
+        return match serde_json::to_string_pretty(&capabilities()) {
+            Ok(json) => print_listing(&json),
+            Err(e) => {
+                print_error(e);
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    let Some(source) = Source::of(&args) else {
+        AppArgs::command()
+            .error(ErrorKind::MissingRequiredArgument, lang().no_text())
+            .exit();
+    };
+    let source = match source.read() {
+        Ok(text) => text,
+        Err(e) => {
+            report(args.errors, "fatal", "input", &e.to_string());
+            return ExitCode::from(2);
+        }
+    };
+    let input = source.clone();
+    if input.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+
+    let input = if args.unescape {
+        unescape_unicode(&input)
+    } else {
+        input
+    };
+    #[cfg(feature = "normalization")]
+    let input = match args.normalize {
+        Some(ref normalization) => {
+            outside_protected(&input, &args, |text| normalize(text, normalization))
+        }
+        None => input,
+    };
+    let input = if args.strip_invisible {
+        outside_protected(&input, &args, strip_invisible)
+    } else {
+        input
+    };
+
+    if args.ascii {
+        let mut res = outside_protected(&input, &args, |text| {
+            let text = match args.spacing {
+                Some(ref spacing) => remove_spacing(text, spacing),
+                None => text.to_string(),
+            };
+            let res = if args.aggressive {
+                to_plain_aggressive(&text)
+            } else {
+                to_plain(&text)
+            };
+            if args.final_sigma {
+                restore_final_sigma(&res)
+            } else {
+                res
+            }
+        });
+        if args.reverse {
+            res = reverse_outside_protected(&res, &args);
+        }
+        let res = finish_output(res, &args);
+        return print_output(&res, &source, &args);
+    }
+
+    #[cfg(feature = "normalization")]
+    let input = if args.strip_diacritics {
+        outside_protected(&input, &args, strip_diacritics)
+    } else {
+        input
+    };
+    let input = if args.sentence_case {
+        sentence_case_pieces(protected_pieces(&input, &args))
+    } else {
+        input
+    };
+    let input = match args.digit_style {
+        Some(ref digit_style) => {
+            outside_protected(&input, &args, |text| to_digit_style(text, digit_style))
+        }
+        None => input,
+    };
+    #[cfg(feature = "random")]
+    if args.random {
+        let choices: Vec<Converter> = random_choices(
+            args.exclude_types.as_deref().unwrap_or_default(),
+            args.exclude_styles.as_deref().unwrap_or_default(),
+        )
+        .iter()
+        .map(|spec| converter_for(spec, &args))
+        .collect();
+        if choices.is_empty() {
+            AppArgs::command()
+                .error(ErrorKind::ArgumentConflict, lang().nothing_to_pick())
+                .exit();
+        }
+        let mut rng = SplitMix64(args.seed.unwrap_or_else(time_seed));
+        let mut res = outside_protected(&input, &args, |text| {
+            convert_random(text, &choices, &mut rng)
+        });
+        if args.reverse {
+            res = reverse_outside_protected(&res, &args);
+        }
+        if let Some(separator) = args.spacing.as_ref().and_then(Spacing::separator) {
+            res = add_spacing(&res, &Spacing::Char(separator));
+        }
+        let res = finish_output(res, &args);
+        return print_output(&res, &source, &args);
+    }
+    let specs = if !args.style_specs.is_empty() {
+        args.style_specs.clone()
+    } else if let Some(ref spec) = args.spec {
+        vec![spec.clone()]
+    } else {
+        vec![StyleSpec::new(
+            args.letter_type.unwrap(),
+            args.letter_style.unwrap(),
+        )]
+    };
+    let mut diagnostics = Diagnostics::new(args.quiet, args.errors);
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut rows = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let converter = converter_for(&spec, &args);
+        #[cfg(feature = "normalization")]
+        let input = outside_protected(&input, &args, |text| {
+            decompose_greek_for(text, &args.greek_marks, &converter)
+        });
+        let res = finish_output(
+            convert_text(&input, &converter, &args, &mut diagnostics),
+            &args,
+        );
+        if args.table {
+            rows.push(vec![
+                spec.to_string(),
+                res.clone(),
+                display_width(&res).to_string(),
+                res.chars().count().to_string(),
+            ]);
+        } else {
+            let status = print_output(&res, &source, &args);
+            if status != ExitCode::SUCCESS {
+                exit_code = status;
+            }
+        }
+    }
+    if args.table {
+        let table = format_table(&["style", "text", "width", "chars"], &rows);
+        if let Err(e) = print_line(&table, &args.output_encoding) {
+            report(args.errors, "fatal", "output", &e.to_string());
+            return ExitCode::from(2);
+        }
+    }
+    diagnostics.summarize(&input);
+    // the output is partial, unless writing it failed or --check found
+    // changes, which say more
+    if diagnostics.errors > 0 && exit_code == ExitCode::SUCCESS {
+        exit_code = ExitCode::FAILURE;
+    }
+    exit_code
+}
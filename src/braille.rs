@@ -0,0 +1,135 @@
+// Uncontracted (grade 1) English Braille. A cell is U+2800 plus one bit per
+// raised dot, dot 1 being the lowest bit.
+const BLANK: u32 = 0x2800;
+// dot 6
+const CAPITAL_SIGN: char = '\u{2820}';
+// dots 3-4-5-6, digits are written as the letters a-j after it
+const NUMBER_SIGN: char = '\u{283C}';
+// dots 5-6, ends a number when a letter a-j follows it
+const LETTER_SIGN: char = '\u{2830}';
+
+// a-z
+const LETTERS: [u32; 26] = [
+    0x01, 0x03, 0x09, 0x19, 0x11, 0x0B, 0x1B, 0x13, 0x0A, 0x1A, // a-j
+    0x05, 0x07, 0x0D, 0x1D, 0x15, 0x0F, 0x1F, 0x17, 0x0E, 0x1E, // k-t, a-j with dot 3
+    0x25, 0x27, 0x3A, 0x2D, 0x3D, 0x35, // u v w x y z
+];
+
+fn cell(dots: u32) -> char {
+    char::from_u32(BLANK + dots).unwrap()
+}
+
+fn letter_cell(ch: char) -> Option<char> {
+    ch.is_ascii_alphabetic()
+        .then(|| cell(LETTERS[(ch.to_ascii_lowercase() as u8 - b'a') as usize]))
+}
+
+fn cell_letter(cell: char) -> Option<char> {
+    let dots = (cell as u32).checked_sub(BLANK)?;
+    LETTERS
+        .iter()
+        .position(|letter| *letter == dots)
+        .map(|idx| (b'a' + idx as u8) as char)
+}
+
+// 1-9 are a-i and 0 is j
+fn digit_letter(ch: char) -> char {
+    match ch {
+        '0' => 'j',
+        _ => (b'a' + ch as u8 - b'1') as char,
+    }
+}
+
+fn letter_digit(ch: char) -> Option<char> {
+    match ch {
+        'j' => Some('0'),
+        'a'..='i' => Some((b'1' + ch as u8 - b'a') as char),
+        _ => None,
+    }
+}
+
+// Write ASCII letters and digits as Braille cells, with capital and number
+// signs. Everything else, spaces included, is kept as is.
+pub fn to_braille(text: &str) -> String {
+    let mut res = String::with_capacity(text.len() * 3);
+    let mut in_number = false;
+    for ch in text.chars() {
+        if ch.is_ascii_digit() {
+            if !in_number {
+                res.push(NUMBER_SIGN);
+                in_number = true;
+            }
+            res.push(letter_cell(digit_letter(ch)).unwrap());
+            continue;
+        }
+        match letter_cell(ch) {
+            Some(cell) => {
+                if ch.is_ascii_uppercase() {
+                    res.push(CAPITAL_SIGN);
+                } else if in_number && letter_digit(ch).is_some() {
+                    // a-j would still read as digits
+                    res.push(LETTER_SIGN);
+                }
+                res.push(cell);
+            }
+            None => res.push(ch),
+        }
+        in_number = false;
+    }
+    res
+}
+
+// Read text written by `to_braille` back. Cells that are not letters or
+// signs are kept as is.
+pub fn from_braille(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut in_number = false;
+    let mut capital = false;
+    for ch in text.chars() {
+        match ch {
+            NUMBER_SIGN => in_number = true,
+            LETTER_SIGN => in_number = false,
+            CAPITAL_SIGN => {
+                in_number = false;
+                capital = true;
+            }
+            _ => {
+                match cell_letter(ch) {
+                    Some(letter) => match letter_digit(letter).filter(|_| in_number) {
+                        Some(digit) => res.push(digit),
+                        None => {
+                            in_number = false;
+                            if capital {
+                                res.push(letter.to_ascii_uppercase());
+                            } else {
+                                res.push(letter);
+                            }
+                        }
+                    },
+                    None => {
+                        in_number = false;
+                        res.push(ch);
+                    }
+                }
+                capital = false;
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_braille {
+    use super::*;
+
+    #[test]
+    pub fn test_braille() {
+        assert_eq!("⠓⠑⠇⠇⠕ ⠺⠕⠗⠇⠙", to_braille("hello world"));
+        assert_eq!("⠠⠁⠃⠉ ⠼⠁⠃⠚", to_braille("Abc 120"));
+        // a letter a-j right after a number needs the letter sign
+        assert_eq!("⠼⠁⠃⠰⠁⠼⠉⠅", to_braille("12a3k"));
+        for text in ["Hello, World!", "12a3ka", "Room 101b", "xyz ABC 7"] {
+            assert_eq!(text, from_braille(&to_braille(text)));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{convert_pair, letter::value_name, Decoration, DigitStyle, LetterStyle, StyledLetter};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NamedValue {
+    pub name: String,
+    pub aliases: Vec<String>,
+}
+
+// Which character classes a font supports in a style.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Combination {
+    pub font: String,
+    pub style: String,
+    pub letters: bool,
+    pub digits: bool,
+    pub greek: bool,
+}
+
+// Everything a frontend needs to build its pickers, so it doesn't have to
+// hardcode what this version supports.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Capabilities {
+    pub version: String,
+    // the Unicode version that has every character the conversions output
+    pub unicode_version: String,
+    pub fonts: Vec<NamedValue>,
+    pub styles: Vec<NamedValue>,
+    pub combinations: Vec<Combination>,
+    pub digit_styles: Vec<NamedValue>,
+    pub decorations: Vec<NamedValue>,
+}
+
+// The math alphabets date from Unicode 3.1, the subscript n from 6.0 and the
+// seven-segment digits of `DigitStyle::Segmented` from 13.0. This is about
+// the mapping data, not the Unicode tables of the dependencies.
+const UNICODE_VERSION: &str = "13.0.0";
+
+fn named_values<T: ValueEnum>() -> Vec<NamedValue> {
+    T::value_variants()
+        .iter()
+        .filter_map(|value| value.to_possible_value())
+        .map(|value| NamedValue {
+            name: value.get_name().to_string(),
+            aliases: value
+                .get_name_and_aliases()
+                .skip(1)
+                .map(str::to_string)
+                .collect(),
+        })
+        .collect()
+}
+
+pub fn capabilities() -> Capabilities {
+    let mut combinations = Vec::new();
+    for letter_type in StyledLetter::ALL {
+        for letter_style in LetterStyle::ALL {
+            let supports = |ch: char| convert_pair(ch, letter_type, letter_style).is_ok();
+            combinations.push(Combination {
+                font: value_name(letter_type),
+                style: value_name(letter_style),
+                letters: supports('A'),
+                digits: supports('0'),
+                greek: supports('Α'),
+            });
+        }
+    }
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        unicode_version: UNICODE_VERSION.to_string(),
+        fonts: named_values::<StyledLetter>(),
+        styles: named_values::<LetterStyle>(),
+        combinations,
+        digit_styles: named_values::<DigitStyle>(),
+        decorations: named_values::<Decoration>(),
+    }
+}
+
+#[cfg(test)]
+mod test_capabilities {
+    use super::*;
+
+    #[test]
+    pub fn test_capabilities() {
+        let capabilities = capabilities();
+        assert_eq!("13.0.0", capabilities.unicode_version);
+        assert_eq!(6, capabilities.fonts.len());
+        assert_eq!(6 * 4, capabilities.combinations.len());
+        let double_struck = &capabilities.fonts[5];
+        assert_eq!("doublestruck", double_struck.name);
+        assert!(double_struck.aliases.contains(&"bb".to_string()));
+        let serif_bold = Combination {
+            font: "serif".to_string(),
+            style: "bold".to_string(),
+            letters: true,
+            digits: true,
+            greek: true,
+        };
+        assert!(capabilities.combinations.contains(&serif_bold));
+        let fraktur_italic = Combination {
+            font: "fraktur".to_string(),
+            style: "italic".to_string(),
+            letters: false,
+            digits: false,
+            greek: false,
+        };
+        assert!(capabilities.combinations.contains(&fraktur_italic));
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{display_width, pad_right};
+
+// What a conversion would change, line by line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChangeReport {
+    // characters that differ position by position, plus the difference in
+    // length of each changed line
+    pub changed_chars: usize,
+    // (1-based line number, before, after) of the lines that differ
+    pub changed_lines: Vec<(usize, String, String)>,
+}
+
+impl ChangeReport {
+    pub fn is_unchanged(&self) -> bool {
+        self.changed_lines.is_empty()
+    }
+}
+
+impl Display for ChangeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines = self.changed_lines.len();
+        write!(
+            f,
+            "{} character{} on {} line{} would change",
+            self.changed_chars,
+            if self.changed_chars == 1 { "" } else { "s" },
+            lines,
+            if lines == 1 { "" } else { "s" }
+        )?;
+        for (line, before, after) in self.changed_lines.iter() {
+            write!(f, "\n-{}: {}\n+{}: {}", line, before, line, after)?;
+        }
+        Ok(())
+    }
+}
+
+fn changed_chars(before: &str, after: &str) -> usize {
+    let before: Vec<char> = before.chars().collect();
+    let after: Vec<char> = after.chars().collect();
+    let differing = before
+        .iter()
+        .zip(after.iter())
+        .filter(|(a, b)| a != b)
+        .count();
+    differing + before.len().abs_diff(after.len())
+}
+
+// Compare a text with its converted form without writing the result, e.g.
+// to lint that a document contains no styled text.
+pub fn diff_lines(before: &str, after: &str) -> ChangeReport {
+    let mut report = ChangeReport::default();
+    let mut before_lines = before.split('\n');
+    let mut after_lines = after.split('\n');
+    let mut line = 0;
+    loop {
+        line += 1;
+        let (before, after) = match (before_lines.next(), after_lines.next()) {
+            (None, None) => break,
+            (before, after) => (before.unwrap_or_default(), after.unwrap_or_default()),
+        };
+        if before != after {
+            report.changed_chars += changed_chars(before, after);
+            report
+                .changed_lines
+                .push((line, before.to_string(), after.to_string()));
+        }
+    }
+    report
+}
+
+// How `diff` shows a text next to its converted form.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[value(rename_all = "lower")]
+pub enum DiffStyle {
+    // the lines side by side, changed ones marked with `~`
+    #[default]
+    Side,
+    // changed words inline as [-before-]{+after+}, like git's word diff
+    Words,
+}
+
+// [-before-]{+after+} for the words that differ. Lines that don't split
+// into the same number of words are shown whole.
+fn word_diff_line(before: &str, after: &str) -> String {
+    let before_words: Vec<&str> = before.split_word_bounds().collect();
+    let after_words: Vec<&str> = after.split_word_bounds().collect();
+    if before_words.len() != after_words.len() {
+        return format!("[-{}-]{{+{}+}}", before, after);
+    }
+    let mut res = String::with_capacity(before.len() + after.len());
+    for (before, after) in before_words.iter().zip(after_words.iter()) {
+        if before == after {
+            res.push_str(before);
+        } else {
+            res.push_str(&format!("[-{}-]{{+{}+}}", before, after));
+        }
+    }
+    res
+}
+
+// A text and its converted form for review, line by line.
+pub fn diff(before: &str, after: &str, style: &DiffStyle) -> String {
+    let before_lines: Vec<&str> = before.split('\n').collect();
+    let after_lines: Vec<&str> = after.split('\n').collect();
+    let lines = before_lines.len().max(after_lines.len());
+    let width = before_lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or_default();
+    let mut res = Vec::with_capacity(lines);
+    for idx in 0..lines {
+        let before = before_lines.get(idx).copied().unwrap_or_default();
+        let after = after_lines.get(idx).copied().unwrap_or_default();
+        let marker = if before == after { ' ' } else { '~' };
+        res.push(match style {
+            DiffStyle::Side => format!("{} {} | {}", marker, pad_right(before, width), after),
+            DiffStyle::Words if before == after => before.to_string(),
+            DiffStyle::Words => word_diff_line(before, after),
+        });
+    }
+    res.join("\n")
+}
+
+#[cfg(test)]
+mod test_check {
+    use super::*;
+
+    #[test]
+    pub fn test_diff_lines() {
+        let report = diff_lines("plain\n𝐛𝐨𝐥𝐝 text\nend", "plain\nbold text\nend");
+        assert_eq!(4, report.changed_chars);
+        assert_eq!(
+            vec![(2, "𝐛𝐨𝐥𝐝 text".to_string(), "bold text".to_string())],
+            report.changed_lines
+        );
+        assert_eq!(
+            "4 characters on 1 line would change\n-2: 𝐛𝐨𝐥𝐝 text\n+2: bold text",
+            report.to_string()
+        );
+        assert_eq!(4, diff_lines("ab", "a b c").changed_chars);
+        assert!(diff_lines("same\ntext", "same\ntext").is_unchanged());
+    }
+
+    #[test]
+    pub fn test_diff() {
+        assert_eq!(
+            "  plain     | plain\n~ 𝐛𝐨𝐥𝐝 text | bold text",
+            diff("plain\n𝐛𝐨𝐥𝐝 text", "plain\nbold text", &DiffStyle::Side)
+        );
+        assert_eq!(
+            "plain\n[-𝐛𝐨𝐥𝐝-]{+bold+} text",
+            diff("plain\n𝐛𝐨𝐥𝐝 text", "plain\nbold text", &DiffStyle::Words)
+        );
+        assert_eq!("[-a b-]{+ab+}", diff("a b", "ab", &DiffStyle::Words));
+    }
+}
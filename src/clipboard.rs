@@ -0,0 +1,58 @@
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64(data: &[u8]) -> String {
+    let mut res = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                res.push(BASE64_ALPHABET[(n >> (18 - 6 * idx) & 0x3F) as usize] as char);
+            } else {
+                res.push('=');
+            }
+        }
+    }
+    res
+}
+
+// OSC 52 escape sequence asking the terminal to put `text` on the clipboard.
+// This goes through SSH, and through tmux when wrapped in its passthrough
+// sequence.
+pub fn osc52(text: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+    if tmux {
+        // escapes inside the passthrough are doubled
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod test_clipboard {
+    use super::*;
+
+    #[test]
+    pub fn test_base64() {
+        assert_eq!("", base64(b""));
+        assert_eq!("Zg==", base64(b"f"));
+        assert_eq!("Zm8=", base64(b"fo"));
+        assert_eq!("Zm9v", base64(b"foo"));
+        assert_eq!("Zm9vYmFy", base64(b"foobar"));
+    }
+
+    #[test]
+    pub fn test_osc52() {
+        assert_eq!("\x1b]52;c;8J2Qmg==\x07", osc52("𝐚", false));
+        assert_eq!(
+            "\x1bPtmux;\x1b\x1b]52;c;8J2Qmg==\x07\x1b\\",
+            osc52("𝐚", true)
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    convert_pair, CharacterInfo, CharacterType, LetterStyle, LetterTypeError, StyledLetter,
+};
+
+// A font, style and character class that Unicode has characters for, e.g.
+// serif bold digits but not fraktur italic letters. Converting with one
+// cannot fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyleCombination {
+    letter_type: StyledLetter,
+    letter_style: LetterStyle,
+    class: CharacterType,
+}
+
+// a character every font that has the class styles
+fn probe(class: &CharacterType) -> Option<char> {
+    match class {
+        CharacterType::Letter => Some('A'),
+        CharacterType::Digit => Some('0'),
+        CharacterType::Greek => Some('Α'),
+        CharacterType::Other => None,
+    }
+}
+
+impl StyleCombination {
+    pub fn new(
+        letter_type: StyledLetter,
+        letter_style: LetterStyle,
+        class: CharacterType,
+    ) -> Result<Self, LetterTypeError> {
+        let probe = probe(&class).ok_or(LetterTypeError::InvalidTypeError)?;
+        convert_pair(probe, &letter_type, &letter_style)?;
+        Ok(Self {
+            letter_type,
+            letter_style,
+            class,
+        })
+    }
+
+    // every valid combination
+    pub fn all() -> Vec<StyleCombination> {
+        let mut combinations = Vec::new();
+        for letter_type in StyledLetter::ALL {
+            for letter_style in LetterStyle::ALL {
+                for class in [
+                    CharacterType::Letter,
+                    CharacterType::Digit,
+                    CharacterType::Greek,
+                ] {
+                    if let Ok(combination) = Self::new(*letter_type, *letter_style, class) {
+                        combinations.push(combination);
+                    }
+                }
+            }
+        }
+        combinations
+    }
+
+    pub fn letter_type(&self) -> StyledLetter {
+        self.letter_type
+    }
+
+    pub fn letter_style(&self) -> LetterStyle {
+        self.letter_style
+    }
+
+    pub fn class(&self) -> CharacterType {
+        self.class
+    }
+
+    // Style a character of this combination's class; characters of any
+    // other class are returned unchanged.
+    pub fn convert(&self, ch: char) -> char {
+        match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) if *info.character_type() == self.class => info
+                .convert(&self.letter_type, &self.letter_style)
+                .unwrap_or(ch),
+            _ => ch,
+        }
+    }
+
+    pub fn convert_str(&self, text: &str) -> String {
+        text.chars().map(|ch| self.convert(ch)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test_combination {
+    use super::*;
+
+    #[test]
+    pub fn test_style_combination() {
+        assert!(StyleCombination::new(
+            StyledLetter::Fraktur,
+            LetterStyle::Italic,
+            CharacterType::Letter
+        )
+        .is_err());
+        assert!(StyleCombination::new(
+            StyledLetter::Script,
+            LetterStyle::Bold,
+            CharacterType::Digit
+        )
+        .is_err());
+        assert!(StyleCombination::new(
+            StyledLetter::Serif,
+            LetterStyle::Bold,
+            CharacterType::Other
+        )
+        .is_err());
+        let digits = StyleCombination::new(
+            StyledLetter::SansSerif,
+            LetterStyle::Bold,
+            CharacterType::Digit,
+        )
+        .unwrap();
+        // only digits are styled
+        assert_eq!("𝟭𝟮 ab!", digits.convert_str("12 ab!"));
+        let script = StyleCombination::new(
+            StyledLetter::Script,
+            LetterStyle::Normal,
+            CharacterType::Letter,
+        )
+        .unwrap();
+        assert_eq!("ℬ𝒶ℯ", script.convert_str("Bae"));
+        assert!(StyleCombination::all().contains(&digits));
+        assert_eq!(
+            StyleCombination::all().len(),
+            crate::capabilities()
+                .combinations
+                .iter()
+                .map(|c| c.letters as usize + c.digits as usize + c.greek as usize)
+                .sum::<usize>()
+        );
+    }
+}
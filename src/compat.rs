@@ -0,0 +1,273 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+use crate::{convert_pair, destyle, from_digit_style, CharacterInfo, LetterStyle, StyledLetter};
+
+// Windows Glyph List 4, the character set every core font of Windows, Mac OS
+// and most older devices covers.
+const WGL4_RANGES: [(u32, u32); 94] = [
+    (0x0020, 0x007E),
+    (0x00A0, 0x017F),
+    (0x0192, 0x0192),
+    (0x01FA, 0x01FF),
+    (0x02C6, 0x02C7),
+    (0x02C9, 0x02C9),
+    (0x02D8, 0x02DD),
+    (0x0384, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x03A1),
+    (0x03A3, 0x03CE),
+    (0x0401, 0x040C),
+    (0x040E, 0x044F),
+    (0x0451, 0x045C),
+    (0x045E, 0x045F),
+    (0x0490, 0x0491),
+    (0x1E80, 0x1E85),
+    (0x1EF2, 0x1EF3),
+    (0x2013, 0x2015),
+    (0x2017, 0x201E),
+    (0x2020, 0x2022),
+    (0x2026, 0x2026),
+    (0x2030, 0x2030),
+    (0x2032, 0x2033),
+    (0x2039, 0x203A),
+    (0x203C, 0x203C),
+    (0x203E, 0x203E),
+    (0x2044, 0x2044),
+    (0x207F, 0x207F),
+    (0x20A3, 0x20A4),
+    (0x20A7, 0x20A7),
+    (0x20AC, 0x20AC),
+    (0x2105, 0x2105),
+    (0x2113, 0x2113),
+    (0x2116, 0x2116),
+    (0x2122, 0x2122),
+    (0x2126, 0x2126),
+    (0x212E, 0x212E),
+    (0x215B, 0x215E),
+    (0x2190, 0x2195),
+    (0x21A8, 0x21A8),
+    (0x2202, 0x2202),
+    (0x2206, 0x2206),
+    (0x220F, 0x220F),
+    (0x2211, 0x2212),
+    (0x2215, 0x2215),
+    (0x2219, 0x221A),
+    (0x221E, 0x221F),
+    (0x2229, 0x2229),
+    (0x222B, 0x222B),
+    (0x2248, 0x2248),
+    (0x2260, 0x2261),
+    (0x2264, 0x2265),
+    (0x2302, 0x2302),
+    (0x2310, 0x2310),
+    (0x2320, 0x2321),
+    (0x2500, 0x2500),
+    (0x2502, 0x2502),
+    (0x250C, 0x250C),
+    (0x2510, 0x2510),
+    (0x2514, 0x2514),
+    (0x2518, 0x2518),
+    (0x251C, 0x251C),
+    (0x2524, 0x2524),
+    (0x252C, 0x252C),
+    (0x2534, 0x2534),
+    (0x253C, 0x253C),
+    (0x2550, 0x256C),
+    (0x2580, 0x2580),
+    (0x2584, 0x2584),
+    (0x2588, 0x2588),
+    (0x258C, 0x258C),
+    (0x2590, 0x2593),
+    (0x25A0, 0x25A1),
+    (0x25AA, 0x25AC),
+    (0x25B2, 0x25B2),
+    (0x25BA, 0x25BA),
+    (0x25BC, 0x25BC),
+    (0x25C4, 0x25C4),
+    (0x25CA, 0x25CB),
+    (0x25CF, 0x25CF),
+    (0x25D8, 0x25D9),
+    (0x25E6, 0x25E6),
+    (0x263A, 0x263C),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2660, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x266A, 0x266B),
+    (0xF001, 0xF002),
+    (0xFB01, 0xFB02),
+    // line breaks and tabs are not glyphs but always safe
+    (0x0009, 0x000A),
+    (0x000D, 0x000D),
+];
+
+pub fn is_bmp(ch: char) -> bool {
+    (ch as u32) <= 0xFFFF
+}
+
+pub fn is_wgl4(ch: char) -> bool {
+    let val = ch as u32;
+    WGL4_RANGES
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&val))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompatReport {
+    pub total: usize,
+    // (char index, char) of the characters outside the BMP, which need a
+    // surrogate pair in UTF-16 and are cut or rejected by UCS-2 storage
+    pub outside_bmp: Vec<(usize, char)>,
+    // (char index, char) of the characters outside WGL4, which older devices
+    // and fonts may show as tofu
+    pub outside_wgl4: Vec<(usize, char)>,
+}
+
+impl CompatReport {
+    pub fn is_compatible(&self) -> bool {
+        self.outside_wgl4.is_empty()
+    }
+}
+
+impl Display for CompatReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} characters are outside the BMP and may be truncated by UCS-2 storage, \
+             {} are outside WGL4 and may render as tofu on older devices",
+            self.outside_bmp.len(),
+            self.total,
+            self.outside_wgl4.len()
+        )
+    }
+}
+
+pub fn compat_report(text: &str) -> CompatReport {
+    let mut report = CompatReport::default();
+    for (idx, ch) in text.chars().enumerate() {
+        report.total += 1;
+        if !is_bmp(ch) {
+            report.outside_bmp.push((idx, ch));
+        }
+        if !is_wgl4(ch) {
+            report.outside_wgl4.push((idx, ch));
+        }
+    }
+    report
+}
+
+// The characters recipients are expected to be able to show.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[value(rename_all = "lower")]
+pub enum CompatProfile {
+    // printable ASCII, tabs and line breaks
+    Ascii,
+    // the Windows Glyph List 4, see `is_wgl4`
+    Wgl4,
+    // the Basic Multilingual Plane, i.e. no surrogate pairs in UTF-16
+    Bmp,
+}
+
+impl CompatProfile {
+    pub fn allows(&self, ch: char) -> bool {
+        match *self {
+            CompatProfile::Ascii => {
+                ch.is_ascii() && (!ch.is_ascii_control() || ch.is_ascii_whitespace())
+            }
+            CompatProfile::Wgl4 => is_wgl4(ch),
+            CompatProfile::Bmp => is_bmp(ch),
+        }
+    }
+}
+
+const REGIONAL_INDICATOR_A: u32 = 0x1F1E6;
+const CIRCLED_CAPITAL_A: u32 = 0x24B6;
+const CIRCLED_SMALL_A: u32 = 0x24D0;
+const FULLWIDTH_EXCLAMATION: u32 = 0xFF01;
+
+// the letter, digit or ASCII character a decorated form stands for
+pub(crate) fn undecorated(ch: char) -> Option<char> {
+    let val = ch as u32;
+    let offset = |start: u32, len: u32| (start..start + len).contains(&val).then(|| val - start);
+    if let Some(idx) = offset(REGIONAL_INDICATOR_A, 26).or(offset(CIRCLED_CAPITAL_A, 26)) {
+        return char::from_u32('A' as u32 + idx);
+    }
+    if let Some(idx) = offset(CIRCLED_SMALL_A, 26) {
+        return char::from_u32('a' as u32 + idx);
+    }
+    if let Some(idx) = offset(FULLWIDTH_EXCLAMATION, 94) {
+        return char::from_u32('!' as u32 + idx);
+    }
+    from_digit_style(ch).map(|(digit, _)| digit)
+}
+
+// Approximations of a character, closest first: a script or italic letter
+// as a serif italic one (ℎ is in the BMP), then its plain form.
+fn approximations(ch: char) -> Vec<char> {
+    let info = match CharacterInfo::get_letter_info(ch) {
+        CharacterInfo::Letter(info) => info,
+        CharacterInfo::Other(ch) => return undecorated(ch).into_iter().collect(),
+    };
+    let mut res = Vec::with_capacity(2);
+    let slanted = matches!(info.letter_type, StyledLetter::Script)
+        || matches!(
+            info.letter_style,
+            LetterStyle::Italic | LetterStyle::BoldItalic
+        );
+    if slanted {
+        if let Ok(italic) = convert_pair(ch, &StyledLetter::Serif, &LetterStyle::Italic) {
+            res.push(italic);
+        }
+    }
+    res.push(destyle(ch));
+    res
+}
+
+// Replace the characters the profile does not allow by the closest
+// approximation it does, e.g. 𝒽 by ℎ for the BMP and by h for WGL4. Regional
+// indicators, circled and fullwidth letters and styled digits become plain.
+// Characters without an allowed approximation are kept.
+pub fn downgrade(text: &str, profile: &CompatProfile) -> String {
+    text.chars()
+        .map(|ch| {
+            if profile.allows(ch) {
+                return ch;
+            }
+            approximations(ch)
+                .into_iter()
+                .find(|approximation| profile.allows(*approximation))
+                .unwrap_or(ch)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_compat {
+    use super::*;
+
+    #[test]
+    pub fn test_compat_report() {
+        let report = compat_report("ℎi 𝐇 €");
+        assert_eq!(6, report.total);
+        assert_eq!(vec![(3, '𝐇')], report.outside_bmp);
+        assert_eq!(vec![(0, 'ℎ'), (3, '𝐇')], report.outside_wgl4);
+        assert!(!report.is_compatible());
+        assert!(compat_report("Ünïcødé — “quotes” Ωμέγα\n").is_compatible());
+    }
+
+    #[test]
+    pub fn test_downgrade() {
+        assert_eq!("ℎA ℎ", downgrade("𝒽𝐴 ℎ", &CompatProfile::Bmp));
+        assert_eq!("hA h", downgrade("𝒽𝐴 ℎ", &CompatProfile::Wgl4));
+        assert_eq!(
+            "USA 1 Ab Hi!",
+            downgrade("🇺🇸🇦 ¹ Ⓐⓑ Ｈｉ！", &CompatProfile::Ascii)
+        );
+        // nothing in WGL4 looks like an emoji
+        assert_eq!("😀é", downgrade("😀é", &CompatProfile::Wgl4));
+        assert_eq!("é", downgrade("é", &CompatProfile::Wgl4));
+    }
+}
@@ -0,0 +1,1378 @@
+use crate::{tables::*, CharacterType, LetterInfo, LetterStyle, LetterTypeError, StyledLetter};
+
+// (offsets, characters) of a corner case table
+fn split_corner_cases(cases: &[(usize, char)]) -> (Vec<usize>, Vec<char>) {
+    cases.iter().copied().unzip()
+}
+
+trait TypeStyle {
+    fn get_char(
+        &self,
+        offset: usize,
+        style: &LetterStyle,
+        uppercase: bool,
+    ) -> Result<char, LetterTypeError>;
+}
+
+// a field for the number of characters
+#[derive(Debug)]
+struct SerifType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+macro_rules! impl_type_style {
+    ($type_name: ident) => {
+        impl TypeStyle for $type_name {
+            fn get_char(
+                &self,
+                offset: usize,
+                style: &LetterStyle,
+                uppercase: bool,
+            ) -> Result<char, LetterTypeError> {
+                match *style {
+                    LetterStyle::Normal => self.normal(offset, uppercase),
+                    LetterStyle::Italic => self.italic(offset, uppercase),
+                    LetterStyle::Bold => self.bold(offset, uppercase),
+                    LetterStyle::BoldItalic => self.bold_italic(offset, uppercase),
+                }
+            }
+        }
+    };
+}
+
+impl_type_style!(SerifType);
+impl_type_style!(SansSerifType);
+impl_type_style!(ScriptType);
+impl_type_style!(FrakturType);
+impl_type_style!(MonoSpaceType);
+impl_type_style!(DoubleStruckType);
+impl_type_style!(GreekType);
+impl_type_style!(GreekSansSerifType);
+impl_type_style!(DigitType);
+impl_type_style!(DigitSansSerifType);
+impl_type_style!(DigitMonoSpaceType);
+impl_type_style!(DigitDoubleStruckType);
+
+impl SerifType {
+    fn new() -> Self {
+        Self {
+            number: LETTER_COUNT,
+            normal_start: Some((SERIF_NORMAL_UPPER_START, SERIF_NORMAL_LOWER_START)),
+            bold_start: Some((SERIF_BOLD_UPPER_START, SERIF_BOLD_LOWER_START)),
+            italic_start: Some((SERIF_ITALIC_UPPER_START, SERIF_ITALIC_LOWER_START)),
+            bold_italic_start: Some((SERIF_BOLD_ITALIC_UPPER_START, SERIF_BOLD_ITALIC_LOWER_START)),
+        }
+    }
+
+    fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    // lowercase letters
+    // serif
+    // italic
+    // h 0x210E ℎ
+    fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                let (indices, chs) = split_corner_cases(SERIF_ITALIC_LOWER_CORNER_CASES);
+                if let Ok(index) = indices.binary_search(&offset) {
+                    return Ok(chs[index]);
+                }
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct SansSerifType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl SansSerifType {
+    fn new() -> Self {
+        Self {
+            number: LETTER_COUNT,
+            normal_start: Some((SANS_SERIF_NORMAL_UPPER_START, SANS_SERIF_NORMAL_LOWER_START)),
+            bold_start: Some((SANS_SERIF_BOLD_UPPER_START, SANS_SERIF_BOLD_LOWER_START)),
+            italic_start: Some((SANS_SERIF_ITALIC_UPPER_START, SANS_SERIF_ITALIC_LOWER_START)),
+            bold_italic_start: Some((
+                SANS_SERIF_BOLD_ITALIC_UPPER_START,
+                SANS_SERIF_BOLD_ITALIC_LOWER_START,
+            )),
+        }
+    }
+
+    fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct ScriptType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value.
+    // If the style is not supported, then it is None.
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl ScriptType {
+    pub fn new() -> Self {
+        Self {
+            number: LETTER_COUNT,
+            normal_start: Some((SCRIPT_NORMAL_UPPER_START, SCRIPT_NORMAL_LOWER_START)),
+            bold_start: Some((SCRIPT_BOLD_UPPER_START, SCRIPT_BOLD_LOWER_START)),
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    // # abnormal script unicodes
+    // Uppercase normal
+    // 0x212C ℬ B
+    // 0x2130 ℰ E
+    // 0x2131 ℱ F
+    // 0x210B ℋ H
+    // 0x2110 ℐ J
+    // 0x2112 ℒ L
+    // 0x2133 ℳ M
+    // 0x211B ℛ R
+
+    // script
+    // normal
+    // e 0x212F ℯ
+    // g 0x210A ℊ
+    // o 0x2134 ℴ
+    pub fn get_normal_corner_case(uppercase: bool) -> (Vec<usize>, Vec<char>) {
+        if uppercase {
+            split_corner_cases(SCRIPT_NORMAL_UPPER_CORNER_CASES)
+        } else {
+            split_corner_cases(SCRIPT_NORMAL_LOWER_CORNER_CASES)
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        // input ch -> offset/type/style/uppercase
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                let (uppercase_corner_cases, uppercase_chs) =
+                    Self::get_normal_corner_case(uppercase);
+                if let Ok(index) = uppercase_corner_cases.binary_search(&offset) {
+                    return Ok(uppercase_chs[index]);
+                }
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                let (lowercase_corner_cases, lowercase_chs) =
+                    Self::get_normal_corner_case(uppercase);
+                if let Ok(index) = lowercase_corner_cases.binary_search(&offset) {
+                    return Ok(lowercase_chs[index]);
+                }
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct FrakturType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl FrakturType {
+    pub fn new() -> Self {
+        Self {
+            number: LETTER_COUNT,
+            normal_start: Some((FRAKTUR_NORMAL_UPPER_START, FRAKTUR_NORMAL_LOWER_START)),
+            bold_start: Some((FRAKTUR_BOLD_UPPER_START, FRAKTUR_BOLD_LOWER_START)),
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn get_normal_corner_case() -> (Vec<usize>, Vec<char>) {
+        split_corner_cases(FRAKTUR_NORMAL_UPPER_CORNER_CASES)
+    }
+
+    // # abnormal Fraktur
+    // normal
+    // C 0x212D ℭ
+    // H 0x210C ℌ
+    // I 0x2111 ℑ
+    // R 0x211C ℜ
+    // Z 0x2128 ℨ
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                let (uppercase_corner_cases, uppercase_chs) = Self::get_normal_corner_case();
+                if let Ok(index) = uppercase_corner_cases.binary_search(&offset) {
+                    return Ok(uppercase_chs[index]);
+                }
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct MonoSpaceType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl MonoSpaceType {
+    pub fn new() -> Self {
+        Self {
+            number: LETTER_COUNT,
+            normal_start: Some((MONOSPACE_NORMAL_UPPER_START, MONOSPACE_NORMAL_LOWER_START)),
+            bold_start: None,
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct DoubleStruckType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl DoubleStruckType {
+    pub fn new() -> Self {
+        Self {
+            number: LETTER_COUNT,
+            normal_start: None,
+            bold_start: Some((
+                DOUBLE_STRUCK_BOLD_UPPER_START,
+                DOUBLE_STRUCK_BOLD_LOWER_START,
+            )),
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+    // # abnormal double-struck
+    // bold
+    // C 0x2102 ℂ
+    // H 0x210D ℍ
+    // N 0x2115 ℕ
+    // P 0x2119 ℙ
+    // Q 0x211A ℚ
+    // R 0x211D ℝ
+    // Z 0x2124 ℤ
+    pub fn get_bold_corner_case() -> (Vec<usize>, Vec<char>) {
+        split_corner_cases(DOUBLE_STRUCK_BOLD_UPPER_CORNER_CASES)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                let (indices, chars) = Self::get_bold_corner_case();
+                if let Ok(idx) = indices.binary_search(&offset) {
+                    return Ok(chars[idx]);
+                }
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct GreekType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl GreekType {
+    pub fn new() -> Self {
+        Self {
+            // lowercase runs end with ∂ ϵ ϑ ϰ ϕ ϱ ϖ, uppercase ones stop at ∇
+            number: GREEK_LOWER_COUNT,
+            normal_start: Some((
+                SERIF_NORMAL_GREEK_UPPER_START,
+                SERIF_NORMAL_GREEK_LOWER_START,
+            )),
+            bold_start: Some((SERIF_BOLD_GREEK_UPPER_START, SERIF_BOLD_GREEK_LOWER_START)),
+            italic_start: Some((
+                SERIF_ITALIC_GREEK_UPPER_START,
+                SERIF_ITALIC_GREEK_LOWER_START,
+            )),
+            bold_italic_start: Some((
+                SERIF_BOLD_ITALIC_GREEK_UPPER_START,
+                SERIF_BOLD_ITALIC_GREEK_LOWER_START,
+            )),
+        }
+    }
+
+    pub fn get_normal_corner_case(uppercase: bool) -> (Vec<usize>, Vec<char>) {
+        if uppercase {
+            split_corner_cases(SERIF_NORMAL_GREEK_UPPER_CORNER_CASES)
+        } else {
+            split_corner_cases(SERIF_NORMAL_GREEK_LOWER_CORNER_CASES)
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                let (indices, chs) = Self::get_normal_corner_case(uppercase);
+                if let Ok(idx) = indices.binary_search(&offset) {
+                    return Ok(chs[idx]);
+                }
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                let (indices, chs) = Self::get_normal_corner_case(uppercase);
+                if let Ok(idx) = indices.binary_search(&offset) {
+                    return Ok(chs[idx]);
+                }
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct GreekSansSerifType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl GreekSansSerifType {
+    pub fn new() -> Self {
+        Self {
+            // lowercase runs end with ∂ ϵ ϑ ϰ ϕ ϱ ϖ, uppercase ones stop at ∇
+            number: GREEK_LOWER_COUNT,
+            normal_start: None,
+            bold_start: Some((
+                SANS_SERIF_BOLD_GREEK_UPPER_START,
+                SANS_SERIF_BOLD_GREEK_LOWER_START,
+            )),
+            italic_start: None,
+            bold_italic_start: Some((
+                SANS_SERIF_BOLD_ITALIC_GREEK_UPPER_START,
+                SANS_SERIF_BOLD_ITALIC_GREEK_LOWER_START,
+            )),
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if uppercase && offset >= GREEK_UPPER_COUNT {
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct DigitType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl DigitType {
+    pub fn new() -> Self {
+        Self {
+            number: DIGIT_COUNT,
+            normal_start: Some((SERIF_NORMAL_DIGIT_START, SERIF_NORMAL_DIGIT_START)),
+            bold_start: Some((SERIF_BOLD_DIGIT_START, SERIF_BOLD_DIGIT_START)),
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct DigitDoubleStruckType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl DigitDoubleStruckType {
+    pub fn new() -> Self {
+        Self {
+            number: DIGIT_COUNT,
+            normal_start: Some((
+                DOUBLE_STRUCK_NORMAL_DIGIT_START,
+                DOUBLE_STRUCK_NORMAL_DIGIT_START,
+            )),
+            bold_start: None,
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct DigitSansSerifType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl DigitSansSerifType {
+    pub fn new() -> Self {
+        Self {
+            number: DIGIT_COUNT,
+            normal_start: Some((SANS_SERIF_NORMAL_DIGIT_START, SANS_SERIF_NORMAL_DIGIT_START)),
+            bold_start: Some((SANS_SERIF_BOLD_DIGIT_START, SANS_SERIF_BOLD_DIGIT_START)),
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+#[derive(Debug)]
+struct DigitMonoSpaceType {
+    number: usize,
+    // (uppercase, lowercase)
+    // If there is no cases, then we fill them with the same value
+    normal_start: Option<(u32, u32)>,
+    bold_start: Option<(u32, u32)>,
+    italic_start: Option<(u32, u32)>,
+    bold_italic_start: Option<(u32, u32)>,
+}
+
+impl DigitMonoSpaceType {
+    pub fn new() -> Self {
+        Self {
+            number: DIGIT_COUNT,
+            normal_start: Some((MONOSPACE_NORMAL_DIGIT_START, MONOSPACE_NORMAL_DIGIT_START)),
+            bold_start: None,
+            italic_start: None,
+            bold_italic_start: None,
+        }
+    }
+
+    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+
+    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
+        if offset >= self.number {
+            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
+            return Err(LetterTypeError::ExceedLengthError(offset));
+        }
+
+        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
+            if uppercase {
+                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
+            } else {
+                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
+            }
+        }
+        Err(LetterTypeError::InvalidStyleError)
+    }
+}
+
+// The conversion worked out from the start of each run and its corner cases.
+// Builds without the static-tables feature convert with this; the table is
+// checked against it in tests.
+pub(crate) fn compute(
+    info: &LetterInfo,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> Result<char, LetterTypeError> {
+    match info.character_type {
+        CharacterType::Letter => match *letter_type {
+            StyledLetter::Serif => {
+                let serif = SerifType::new();
+                serif.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::SansSerif => {
+                let sans_serif = SansSerifType::new();
+                sans_serif.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::Script => {
+                let script = ScriptType::new();
+                script.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::Fraktur => {
+                let fraktur = FrakturType::new();
+                fraktur.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::MonoSpace => {
+                let mono = MonoSpaceType::new();
+                mono.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::DoubleStruck => {
+                let double_struck = DoubleStruckType::new();
+                double_struck.get_char(info.offset, letter_style, info.uppercase)
+            }
+        },
+        CharacterType::Greek => match letter_type {
+            StyledLetter::Serif => {
+                let serif = GreekType::new();
+                serif.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::SansSerif => {
+                let sans_serif = GreekSansSerifType::new();
+                sans_serif.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::Script => Err(LetterTypeError::InvalidTypeError),
+            StyledLetter::Fraktur => Err(LetterTypeError::InvalidTypeError),
+            StyledLetter::MonoSpace => Err(LetterTypeError::InvalidTypeError),
+            StyledLetter::DoubleStruck => Err(LetterTypeError::InvalidTypeError),
+        },
+        CharacterType::Digit => match letter_type {
+            StyledLetter::Serif => {
+                let serif = DigitType::new();
+                serif.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::SansSerif => {
+                let sans_serif = DigitSansSerifType::new();
+                sans_serif.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::Script => Err(LetterTypeError::InvalidTypeError),
+            StyledLetter::Fraktur => Err(LetterTypeError::InvalidTypeError),
+            StyledLetter::MonoSpace => {
+                let mono = DigitMonoSpaceType::new();
+                mono.get_char(info.offset, letter_style, info.uppercase)
+            }
+            StyledLetter::DoubleStruck => {
+                let double_struck = DigitDoubleStruckType::new();
+                double_struck.get_char(info.offset, letter_style, info.uppercase)
+            }
+        },
+        CharacterType::Other => Err(LetterTypeError::InvalidTypeError),
+    }
+}
+
+#[cfg(test)]
+mod test_computed {
+    use super::*;
+
+    #[test]
+    pub fn test_script_corner_cases() {
+        let script_type = ScriptType::new();
+        let uppercase_corner_cases: Vec<usize> = vec![1, 4, 5, 7, 8, 11, 12, 17];
+        let lowercase_corner_cases: Vec<usize> = vec![4, 6, 14];
+        let uppercase_chs: Vec<char> = vec![
+            '\u{212C}', // ℬ B
+            '\u{2130}', // ℰ E
+            '\u{2131}', // ℱ F
+            '\u{210B}', // ℋ H
+            '\u{2110}', // ℐ I
+            '\u{2112}', // ℒ L
+            '\u{2133}', // ℳ M
+            '\u{211B}', // ℛ R
+        ];
+        let lowercase_chs: Vec<char> = vec![
+            '\u{212F}', // ℯ e
+            '\u{210A}', // ℊ g
+            '\u{2134}', // ℴ o
+        ];
+
+        for (idx, val) in uppercase_corner_cases.iter().enumerate() {
+            let res = script_type.normal(*val, true);
+            assert!(res.is_ok());
+            let ch = res.unwrap();
+            assert_eq!(uppercase_chs[idx], ch);
+        }
+
+        for (idx, val) in lowercase_corner_cases.iter().enumerate() {
+            let res = script_type.normal(*val, false);
+            assert!(res.is_ok());
+            let ch = res.unwrap();
+            assert_eq!(lowercase_chs[idx], ch);
+        }
+    }
+
+    #[test]
+    pub fn test_fraktur_corner_cases() {
+        let fraktur_type = FrakturType::new();
+        let uppercase_corner_cases: Vec<usize> = vec![2, 7, 8, 17, 25];
+        let uppercase_chs: Vec<char> = vec![
+            '\u{212D}', // C  ℭ
+            '\u{210C}', // H  ℌ
+            '\u{2111}', // I  ℑ
+            '\u{211C}', // R ℜ
+            '\u{2128}', // Z  ℨ
+        ];
+
+        for (idx, val) in uppercase_corner_cases.iter().enumerate() {
+            let res = fraktur_type.normal(*val, true);
+            assert!(res.is_ok());
+            let ch = res.unwrap();
+            assert_eq!(uppercase_chs[idx], ch);
+        }
+    }
+
+    #[test]
+    pub fn test_serif_corner_cases() {
+        let serif_type = SerifType::new();
+        let res = serif_type.italic(7, false);
+        assert!(res.is_ok());
+        let ch = res.unwrap();
+        assert_eq!('\u{210E}', ch);
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use crate::{mappings, DoctorCheck, DoctorReport, StyleRegistry, Styler};
+
+fn alphabet() -> impl Iterator<Item = char> {
+    ('A'..='Z').chain('a'..='z')
+}
+
+// Every letter is styled or a declared gap, and declared gaps really are.
+fn check_coverage(styler: &dyn Styler) -> DoctorCheck {
+    let gaps = styler.gaps();
+    let mut problems = Vec::new();
+    for ch in alphabet() {
+        match (styler.style_char(ch), gaps.contains(&ch)) {
+            (None, false) => problems.push(format!("'{}' has no styled form", ch)),
+            (Some(styled), true) => problems.push(format!(
+                "'{}' is declared a gap but is styled as '{}'",
+                ch, styled
+            )),
+            _ => {}
+        }
+    }
+    DoctorCheck::new("coverage", problems)
+}
+
+// De-styling gives back the character that was styled.
+fn check_reversibility(styler: &dyn Styler) -> DoctorCheck {
+    let mut problems = Vec::new();
+    for ch in alphabet() {
+        if let Some(styled) = styler.style_char(ch) {
+            match styler.unstyle_char(styled) {
+                Some(plain) if plain == ch => {}
+                Some(plain) => problems.push(format!(
+                    "'{}' for '{}' de-styles to '{}'",
+                    styled, ch, plain
+                )),
+                None => problems.push(format!("'{}' for '{}' doesn't de-style", styled, ch)),
+            }
+        }
+    }
+    DoctorCheck::new("reversibility", problems)
+}
+
+// No two letters share a styled form, and no styled form is one the built-in
+// fonts output, which would make de-styling ambiguous.
+fn check_collisions(styler: &dyn Styler) -> DoctorCheck {
+    let built_in: HashMap<char, String> = mappings()
+        .into_iter()
+        .map(|mapping| {
+            (
+                mapping.styled,
+                format!("{} {} '{}'", mapping.font, mapping.style, mapping.char),
+            )
+        })
+        .collect();
+    let mut seen: HashMap<char, char> = HashMap::new();
+    let mut problems = Vec::new();
+    for ch in alphabet() {
+        let styled = match styler.style_char(ch) {
+            Some(styled) => styled,
+            None => continue,
+        };
+        if let Some(other) = seen.insert(styled, ch) {
+            problems.push(format!(
+                "'{}' and '{}' are both styled as '{}'",
+                other, ch, styled
+            ));
+        }
+        if let Some(mapping) = built_in.get(&styled) {
+            problems.push(format!("'{}' for '{}' is already {}", styled, ch, mapping));
+        }
+    }
+    DoctorCheck::new("collisions", problems)
+}
+
+// Check that a custom style keeps the invariants the built-in fonts have.
+pub fn conformance(styler: &dyn Styler) -> DoctorReport {
+    DoctorReport {
+        checks: vec![
+            check_coverage(styler),
+            check_reversibility(styler),
+            check_collisions(styler),
+        ],
+    }
+}
+
+// (name, report) of every registered style
+pub fn registry_conformance(registry: &StyleRegistry) -> Vec<(String, DoctorReport)> {
+    registry
+        .stylers()
+        .map(|styler| (styler.name().to_string(), conformance(styler)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_conformance {
+    use super::*;
+
+    // Ⓐ..Ⓩ and ⓐ..ⓩ
+    #[derive(Debug)]
+    struct Circled;
+
+    impl Styler for Circled {
+        fn name(&self) -> &str {
+            "circled"
+        }
+
+        fn style_char(&self, ch: char) -> Option<char> {
+            match ch {
+                'A'..='Z' => char::from_u32(ch as u32 - 'A' as u32 + 0x24B6),
+                'a'..='z' => char::from_u32(ch as u32 - 'a' as u32 + 0x24D0),
+                _ => None,
+            }
+        }
+
+        fn unstyle_char(&self, ch: char) -> Option<char> {
+            match ch {
+                'Ⓐ'..='Ⓩ' => char::from_u32(ch as u32 - 0x24B6 + 'A' as u32),
+                'ⓐ'..='ⓩ' => char::from_u32(ch as u32 - 0x24D0 + 'a' as u32),
+                _ => None,
+            }
+        }
+    }
+
+    // only a few letters, one of them clashing with serif bold
+    #[derive(Debug)]
+    struct Broken;
+
+    impl Styler for Broken {
+        fn name(&self) -> &str {
+            "broken"
+        }
+
+        fn style_char(&self, ch: char) -> Option<char> {
+            match ch {
+                'a' => Some('𝐚'),
+                'b' | 'c' => Some('ß'),
+                _ => None,
+            }
+        }
+
+        fn unstyle_char(&self, ch: char) -> Option<char> {
+            (ch == 'ß').then_some('b')
+        }
+
+        fn gaps(&self) -> Vec<char> {
+            alphabet()
+                .filter(|ch| !"abcd".contains(*ch))
+                .chain(['c'])
+                .collect()
+        }
+    }
+
+    #[test]
+    pub fn test_conformance() {
+        let report = conformance(&Circled);
+        assert!(report.is_ready(), "{}", report);
+
+        let report = conformance(&Broken);
+        assert_eq!(
+            vec![
+                vec![
+                    "'c' is declared a gap but is styled as 'ß'".to_string(),
+                    "'d' has no styled form".to_string(),
+                ],
+                vec![
+                    "'𝐚' for 'a' doesn't de-style".to_string(),
+                    "'ß' for 'c' de-styles to 'b'".to_string(),
+                ],
+                vec![
+                    "'𝐚' for 'a' is already serif bold 'a'".to_string(),
+                    "'b' and 'c' are both styled as 'ß'".to_string(),
+                ],
+            ],
+            report
+                .checks
+                .into_iter()
+                .map(|check| check.problems)
+                .collect::<Vec<_>>()
+        );
+
+        let mut registry = StyleRegistry::new();
+        registry.register(Box::new(Circled)).unwrap();
+        registry.register(Box::new(Broken)).unwrap();
+        let reports = registry_conformance(&registry);
+        assert_eq!("circled", reports[0].0);
+        assert!(!reports[1].1.is_ready());
+    }
+}
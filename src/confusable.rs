@@ -0,0 +1,102 @@
+use crate::{compat::undecorated, destyle, spoof::latin_lookalike, superscript_letter};
+
+const PARENTHESIZED_SMALL_A: u32 = 0x249C;
+const SQUARED_CAPITAL_A: u32 = 0x1F130;
+const NEGATIVE_CIRCLED_CAPITAL_A: u32 = 0x1F150;
+const NEGATIVE_SQUARED_CAPITAL_A: u32 = 0x1F170;
+
+// small capitals as used for "small caps" fancy text; there is no small
+// capital X in common use
+fn small_capital(ch: char) -> Option<char> {
+    let letter = match ch {
+        'ᴀ' => 'a',
+        'ʙ' => 'b',
+        'ᴄ' => 'c',
+        'ᴅ' => 'd',
+        'ᴇ' => 'e',
+        'ꜰ' => 'f',
+        'ɢ' => 'g',
+        'ʜ' => 'h',
+        'ɪ' => 'i',
+        'ᴊ' => 'j',
+        'ᴋ' => 'k',
+        'ʟ' => 'l',
+        'ᴍ' => 'm',
+        'ɴ' => 'n',
+        'ᴏ' => 'o',
+        'ᴘ' => 'p',
+        'ǫ' => 'q',
+        'ʀ' => 'r',
+        'ꜱ' => 's',
+        'ᴛ' => 't',
+        'ᴜ' => 'u',
+        'ᴠ' => 'v',
+        'ᴡ' => 'w',
+        'ʏ' => 'y',
+        'ᴢ' => 'z',
+        _ => return None,
+    };
+    Some(letter)
+}
+
+// parenthesized, squared and negative circled or squared letters
+fn enclosed_letter(ch: char) -> Option<char> {
+    let val = ch as u32;
+    let offset = |start: u32| (start..start + 26).contains(&val).then(|| val - start);
+    if let Some(idx) = offset(PARENTHESIZED_SMALL_A) {
+        return char::from_u32('a' as u32 + idx);
+    }
+    let idx = offset(SQUARED_CAPITAL_A)
+        .or(offset(NEGATIVE_CIRCLED_CAPITAL_A))
+        .or(offset(NEGATIVE_SQUARED_CAPITAL_A))?;
+    char::from_u32('A' as u32 + idx)
+}
+
+fn from_superscript_letter(ch: char) -> Option<char> {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .find(|letter| superscript_letter(*letter) == Some(ch))
+}
+
+// The ASCII character a non-mathematical lookalike from fancy text stands
+// for: Cyrillic and Greek letters drawn like Latin ones, fullwidth forms,
+// circled, parenthesized and squared letters, regional indicators, small
+// capitals, superscript letters and styled digits. A curated subset of the
+// Unicode confusables, limited to what fancy text generators emit.
+pub fn fold_confusable(ch: char) -> Option<char> {
+    latin_lookalike(ch)
+        .or_else(|| undecorated(ch))
+        .or_else(|| enclosed_letter(ch))
+        .or_else(|| small_capital(ch))
+        .or_else(|| from_superscript_letter(ch))
+}
+
+// `to_plain` that also folds the lookalikes of `fold_confusable`, e.g. for
+// searching or moderating pasted fancy text. Lossy: Greek and Cyrillic
+// words come out as Latin gibberish.
+pub fn to_plain_aggressive(text: &str) -> String {
+    text.chars()
+        .map(|ch| {
+            let plain = destyle(ch);
+            fold_confusable(plain).unwrap_or(plain)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_confusable {
+    use super::*;
+
+    #[test]
+    pub fn test_to_plain_aggressive() {
+        assert_eq!(
+            "Hello world 42!",
+            to_plain_aggressive("Неllо ｗｏｒｌｄ ⁴₂！")
+        );
+        assert_eq!("fancy TEXT", to_plain_aggressive("ꜰᴀɴᴄʏ 🅃🄴🅇🅃"));
+        assert_eq!("abc ABC", to_plain_aggressive("⒜ⓑᶜ 🅰🄱Ⓒ"));
+        // math letters first become plain Greek, then Latin
+        assert_eq!("AB", to_plain_aggressive("𝚨𝚩"));
+        assert_eq!(None, fold_confusable('é'));
+    }
+}
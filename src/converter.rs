@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    is_emoji_sequence, number::convert_number_with, CharacterInfo, CharacterType,
+    GreekVariantPolicy, LetterStyle, LetterTypeError, NumberOptions, StyleSpec, StyledLetter,
+};
+
+// Conversion settings bundled together, so callers configure them once
+// instead of threading every option through each call.
+#[derive(Debug, Clone)]
+pub struct Converter {
+    letter_type: StyledLetter,
+    letter_style: LetterStyle,
+    greek_variants: GreekVariantPolicy,
+    // styles of the same font to try, in order, when a character class has
+    // no glyphs in the requested style (e.g. there are no italic digits)
+    fallbacks: HashMap<CharacterType, Vec<LetterStyle>>,
+    // fonts and styles to try, in order, when neither the requested style
+    // nor the fallbacks above have a glyph
+    chain: Vec<StyleSpec>,
+}
+
+// A character `Converter::convert_str_lossy` kept as it was.
+#[derive(Debug)]
+pub struct ConversionFailure {
+    // byte offset in the input
+    pub offset: usize,
+    pub ch: char,
+    pub error: LetterTypeError,
+}
+
+#[derive(Debug)]
+pub struct LossyConversion {
+    pub output: String,
+    pub failures: Vec<ConversionFailure>,
+}
+
+// Script italic -> script normal -> serif italic: first the same font
+// without italic, then plain, then bold, then the requested style in serif.
+pub fn default_fallback_chain(
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> Vec<StyleSpec> {
+    let upright = match letter_style {
+        LetterStyle::BoldItalic => LetterStyle::Bold,
+        LetterStyle::Italic => LetterStyle::Normal,
+        style => *style,
+    };
+    let candidates = [
+        StyleSpec::new(*letter_type, upright),
+        StyleSpec::new(*letter_type, LetterStyle::Normal),
+        StyleSpec::new(*letter_type, LetterStyle::Bold),
+        StyleSpec::new(StyledLetter::Serif, *letter_style),
+    ];
+    let requested = StyleSpec::new(*letter_type, *letter_style);
+    let mut chain: Vec<StyleSpec> = Vec::new();
+    for spec in candidates {
+        if spec != requested && !chain.contains(&spec) {
+            chain.push(spec);
+        }
+    }
+    chain
+}
+
+// The chars of the text with their byte offsets, and whether they belong to
+// an emoji sequence, which conversions keep whole.
+pub(crate) fn walk_chars(text: &str) -> impl Iterator<Item = (usize, char, bool)> + '_ {
+    text.grapheme_indices(true).flat_map(|(offset, grapheme)| {
+        let emoji = is_emoji_sequence(grapheme);
+        grapheme
+            .char_indices()
+            .map(move |(idx, ch)| (offset + idx, ch, emoji))
+    })
+}
+
+impl Converter {
+    pub fn new(letter_type: StyledLetter, letter_style: LetterStyle) -> Self {
+        Self {
+            letter_type,
+            letter_style,
+            greek_variants: GreekVariantPolicy::default(),
+            fallbacks: HashMap::new(),
+            chain: Vec::new(),
+        }
+    }
+
+    pub fn with_greek_variants(mut self, policy: GreekVariantPolicy) -> Self {
+        self.greek_variants = policy;
+        self
+    }
+
+    // Styles to fall back to for `character_type` when the requested style is
+    // not available. Without a fallback such characters are an error.
+    pub fn with_fallback(
+        mut self,
+        character_type: CharacterType,
+        styles: Vec<LetterStyle>,
+    ) -> Self {
+        self.fallbacks.insert(character_type, styles);
+        self
+    }
+
+    // Fonts and styles to convert with, in order, when a character has no
+    // glyph in the requested ones; see `default_fallback_chain`.
+    pub fn with_fallback_chain(mut self, chain: Vec<StyleSpec>) -> Self {
+        self.chain = chain;
+        self
+    }
+
+    pub fn with_default_fallback_chain(self) -> Self {
+        let chain = default_fallback_chain(&self.letter_type, &self.letter_style);
+        self.with_fallback_chain(chain)
+    }
+
+    // The same settings for another font and style.
+    pub fn restyled(&self, letter_type: StyledLetter, letter_style: LetterStyle) -> Self {
+        Self {
+            letter_type,
+            letter_style,
+            ..self.clone()
+        }
+    }
+
+    pub fn letter_type(&self) -> &StyledLetter {
+        &self.letter_type
+    }
+
+    pub fn letter_style(&self) -> &LetterStyle {
+        &self.letter_style
+    }
+
+    pub fn convert_char(&self, ch: char) -> Result<char, LetterTypeError> {
+        let mut info = match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => info,
+            CharacterInfo::Other(not_supported_ch) => return Ok(not_supported_ch),
+        };
+        if let CharacterType::Greek = info.character_type {
+            info.offset = self
+                .greek_variants
+                .resolve_offset(info.offset, info.uppercase);
+        }
+        let res = info.convert(&self.letter_type, &self.letter_style);
+        match res {
+            Err(LetterTypeError::InvalidStyleError | LetterTypeError::InvalidTypeError) => {}
+            _ => return res,
+        }
+        let styles = self.fallbacks.get(&info.character_type);
+        let fallback = styles
+            .into_iter()
+            .flatten()
+            .find_map(|style| info.convert(&self.letter_type, style).ok())
+            .or_else(|| {
+                self.chain
+                    .iter()
+                    .find_map(|spec| info.convert(&spec.letter_type, &spec.letter_style).ok())
+            });
+        #[cfg(feature = "tracing")]
+        match fallback {
+            Some(styled) => tracing::debug!(
+                ch = %ch,
+                styled = %styled,
+                font = %self.letter_type,
+                style = %self.letter_style,
+                "converted with a fallback"
+            ),
+            None => tracing::debug!(
+                ch = %ch,
+                font = %self.letter_type,
+                style = %self.letter_style,
+                "no glyph or fallback"
+            ),
+        }
+        fallback.ok_or(res.unwrap_err())
+    }
+
+    // The walk behind the string conversions: `visit` gets the byte offset
+    // of every char of the text, the char and its conversion. Chars of emoji
+    // sequences are passed through unconverted, so keycaps and ZWJ sequences
+    // stay whole. Stops at the first error `visit` returns.
+    pub fn convert_each<F>(&self, text: &str, mut visit: F) -> Result<(), LetterTypeError>
+    where
+        F: FnMut(usize, char, Result<char, LetterTypeError>) -> Result<(), LetterTypeError>,
+    {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "convert",
+            font = %self.letter_type,
+            style = %self.letter_style,
+            bytes = text.len(),
+            failures = tracing::field::Empty,
+        )
+        .entered();
+        // chars without a glyph, whether `visit` goes on or not
+        let mut failures = 0;
+        let mut res = Ok(());
+        for (offset, ch, emoji) in walk_chars(text) {
+            let converted = if emoji { Ok(ch) } else { self.convert_char(ch) };
+            failures += usize::from(converted.is_err());
+            res = visit(offset, ch, converted);
+            if res.is_err() {
+                break;
+            }
+        }
+        #[cfg(feature = "tracing")]
+        span.record("failures", failures);
+        #[cfg(not(feature = "tracing"))]
+        let _ = failures;
+        res
+    }
+
+    // Convert every char of the text, keeping emoji sequences whole; fails
+    // on the first char without a glyph.
+    pub fn convert_str(&self, text: &str) -> Result<String, LetterTypeError> {
+        let mut res = String::with_capacity(text.len() * 4);
+        self.convert_each(text, |_, _, converted| {
+            res.push(converted?);
+            Ok(())
+        })?;
+        Ok(res)
+    }
+
+    // Like `convert_str`, but chars without a glyph are kept as they are and
+    // reported, like the command line does.
+    pub fn convert_str_lossy(&self, text: &str) -> LossyConversion {
+        let mut output = String::with_capacity(text.len() * 4);
+        let mut failures = Vec::new();
+        let walk = self.convert_each(text, |offset, ch, converted| {
+            match converted {
+                Ok(converted) => output.push(converted),
+                Err(error) => {
+                    failures.push(ConversionFailure { offset, ch, error });
+                    output.push(ch);
+                }
+            }
+            Ok(())
+        });
+        debug_assert!(walk.is_ok(), "the lossy walk never stops");
+        LossyConversion { output, failures }
+    }
+
+    pub fn convert_number(
+        &self,
+        number: &str,
+        options: &NumberOptions,
+    ) -> Result<String, LetterTypeError> {
+        convert_number_with(number, options, |ch| self.convert_char(ch))
+    }
+}
+
+#[cfg(test)]
+mod test_converter {
+    use super::*;
+
+    #[test]
+    pub fn test_digit_fallback() {
+        let converter = Converter::new(StyledLetter::Serif, LetterStyle::Italic);
+        assert!(converter.convert_char('3').is_err());
+        let converter = converter.with_fallback(
+            CharacterType::Digit,
+            vec![LetterStyle::BoldItalic, LetterStyle::Bold],
+        );
+        assert_eq!('𝐶', converter.convert_char('C').unwrap());
+        assert_eq!('𝟑', converter.convert_char('3').unwrap());
+        // monospace digits only come in normal
+        let converter = Converter::new(StyledLetter::MonoSpace, LetterStyle::Bold)
+            .with_fallback(CharacterType::Digit, vec![LetterStyle::Normal]);
+        assert_eq!('𝟹', converter.convert_char('3').unwrap());
+        assert!(converter.convert_char('a').is_err());
+    }
+
+    #[test]
+    pub fn test_fallback_chain() {
+        assert_eq!(
+            vec![
+                StyleSpec::new(StyledLetter::Script, LetterStyle::Normal),
+                StyleSpec::new(StyledLetter::Script, LetterStyle::Bold),
+                StyleSpec::new(StyledLetter::Serif, LetterStyle::Italic),
+            ],
+            default_fallback_chain(&StyledLetter::Script, &LetterStyle::Italic)
+        );
+        let converter =
+            Converter::new(StyledLetter::Script, LetterStyle::Italic).with_default_fallback_chain();
+        assert_eq!('𝒜', converter.convert_char('A').unwrap());
+        // script has no digits or Greek
+        assert!(converter.convert_char('1').is_err());
+        assert_eq!('𝛼', converter.convert_char('α').unwrap());
+        let converter =
+            Converter::new(StyledLetter::Fraktur, LetterStyle::Bold).with_fallback_chain(vec![
+                StyleSpec::new(StyledLetter::MonoSpace, LetterStyle::Normal),
+            ]);
+        assert_eq!('𝟷', converter.convert_char('1').unwrap());
+        assert_eq!('𝖆', converter.convert_char('a').unwrap());
+    }
+
+    #[test]
+    pub fn test_convert_str() {
+        let converter = Converter::new(StyledLetter::Script, LetterStyle::Normal);
+        assert_eq!("𝒜𝒷𝒸 👍🏽", converter.convert_str("Abc 👍🏽").unwrap());
+        assert!(converter.convert_str("abc 123").is_err());
+        let lossy = converter.convert_str_lossy("é1 b2");
+        assert_eq!("é1 𝒷2", lossy.output);
+        let failed: Vec<(usize, char)> = lossy
+            .failures
+            .iter()
+            .map(|failure| (failure.offset, failure.ch))
+            .collect();
+        assert_eq!(vec![(2, '1'), (5, '2')], failed);
+        // keycaps are passed through whole, the digit included
+        let mut visited = Vec::new();
+        converter
+            .convert_each("1️⃣a", |offset, ch, converted| {
+                visited.push((offset, ch, converted?));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(
+            vec![
+                (0, '1', '1'),
+                (1, '\u{FE0F}', '\u{FE0F}'),
+                (4, '\u{20E3}', '\u{20E3}'),
+                (7, 'a', '𝒶')
+            ],
+            visited
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+// Default font stacks of common platforms.
+//
+// Experimental: the gap lists below were written by hand from when each range
+// entered Unicode and what the fonts are known to lack, not extracted from
+// the font files, and no particular font version was checked against them.
+// A coverage report is a hint to look at the output on the platform, not a
+// verdict.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum Platform {
+    // Roboto, Noto Sans Symbols, Noto Sans Math
+    Android,
+    // San Francisco, Apple Symbols, STIX Two Math
+    Ios,
+    // Segoe UI, Segoe UI Symbol, Cambria Math
+    Windows,
+    // the full Noto family
+    Noto,
+}
+
+impl Platform {
+    pub const ALL: [Platform; 4] = [
+        Platform::Android,
+        Platform::Ios,
+        Platform::Windows,
+        Platform::Noto,
+    ];
+
+    // Styled ranges the font stack is believed to have no glyphs for; see the
+    // caveat above.
+    fn gaps(&self) -> &'static [(u32, u32)] {
+        match *self {
+            Platform::Android => &[
+                // Phonetic Extensions Supplement: modifier small letters
+                (0x1D80, 0x1DBF),
+                // digits with comma
+                (0x1F10B, 0x1F10F),
+            ],
+            Platform::Ios => &[(0x1F10B, 0x1F10F)],
+            Platform::Windows => &[
+                // assumed from Cambria Math predating the Unicode 5.0
+                // additions: dotless ı and ȷ, bold Ϝ and ϝ
+                (0x1D6A4, 0x1D6A5),
+                (0x1D7CA, 0x1D7CB),
+                (0x1D80, 0x1DBF),
+                (0x1F100, 0x1F10F),
+                // subscript letters other than ₐ ₑ ₒ ₓ ₔ
+                (0x2095, 0x209C),
+            ],
+            Platform::Noto => &[],
+        }
+    }
+
+    pub fn covers(&self, ch: char) -> bool {
+        let val = ch as u32;
+        !self
+            .gaps()
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&val))
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Platform::Android => f.write_str("android"),
+            Platform::Ios => f.write_str("ios"),
+            Platform::Windows => f.write_str("windows"),
+            Platform::Noto => f.write_str("noto"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoverageReport {
+    // (platform, (char index, char) of the characters it likely shows as
+    // tofu) for every platform that misses some
+    pub missing: Vec<(Platform, Vec<(usize, char)>)>,
+}
+
+impl CoverageReport {
+    pub fn is_covered(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl Display for CoverageReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, (platform, chars)) in self.missing.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("\n")?;
+            }
+            let chars: String = chars.iter().map(|(_, ch)| *ch).collect();
+            write!(
+                f,
+                "{}: {} characters may render as tofu: {}",
+                platform,
+                chars.chars().count(),
+                chars
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn coverage_report(text: &str) -> CoverageReport {
+    let mut report = CoverageReport::default();
+    for platform in Platform::ALL {
+        let missing: Vec<(usize, char)> = text
+            .chars()
+            .enumerate()
+            .filter(|(_, ch)| !platform.covers(*ch))
+            .collect();
+        if !missing.is_empty() {
+            report.missing.push((platform, missing));
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod test_coverage {
+    use super::*;
+
+    #[test]
+    pub fn test_coverage_report() {
+        let report = coverage_report("𝐇𝐢 𝟋 xₘ");
+        assert_eq!(
+            vec![(Platform::Windows, vec![(3, '𝟋'), (6, 'ₘ')])],
+            report.missing
+        );
+        assert_eq!(
+            "windows: 2 characters may render as tofu: 𝟋ₘ",
+            report.to_string()
+        );
+        assert!(coverage_report("𝐇𝐢 𝒮𝓉𝓎𝓁𝑒").is_covered());
+    }
+}
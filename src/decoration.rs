@@ -0,0 +1,77 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+// Combining marks used to decorate text rather than spell it: lines and
+// strokes drawn over, through or under a character, enclosing shapes.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+pub enum Decoration {
+    // U+0305 COMBINING OVERLINE
+    Overline,
+    // U+033F COMBINING DOUBLE OVERLINE
+    DoubleOverline,
+    // U+0332 COMBINING LOW LINE
+    Underline,
+    // U+0333 COMBINING DOUBLE LOW LINE
+    DoubleUnderline,
+    // U+0334 COMBINING TILDE OVERLAY
+    Tilde,
+    // U+0335 COMBINING SHORT STROKE OVERLAY
+    ShortStroke,
+    // U+0336 COMBINING LONG STROKE OVERLAY
+    Strikethrough,
+    // U+0337 COMBINING SHORT SOLIDUS OVERLAY
+    ShortSlash,
+    // U+0338 COMBINING LONG SOLIDUS OVERLAY
+    Slash,
+    // U+20D2 COMBINING LONG VERTICAL LINE OVERLAY
+    VerticalLine,
+    // U+20D3 COMBINING SHORT VERTICAL LINE OVERLAY
+    ShortVerticalLine,
+    // U+20DD COMBINING ENCLOSING CIRCLE
+    Circle,
+    // U+20DE COMBINING ENCLOSING SQUARE
+    Square,
+}
+
+impl Decoration {
+    pub fn mark(&self) -> char {
+        match *self {
+            Decoration::Overline => '\u{0305}',
+            Decoration::DoubleOverline => '\u{033F}',
+            Decoration::Underline => '\u{0332}',
+            Decoration::DoubleUnderline => '\u{0333}',
+            Decoration::Tilde => '\u{0334}',
+            Decoration::ShortStroke => '\u{0335}',
+            Decoration::Strikethrough => '\u{0336}',
+            Decoration::ShortSlash => '\u{0337}',
+            Decoration::Slash => '\u{0338}',
+            Decoration::VerticalLine => '\u{20D2}',
+            Decoration::ShortVerticalLine => '\u{20D3}',
+            Decoration::Circle => '\u{20DD}',
+            Decoration::Square => '\u{20DE}',
+        }
+    }
+
+    pub fn from_mark(ch: char) -> Option<Decoration> {
+        Decoration::value_variants()
+            .iter()
+            .find(|decoration| decoration.mark() == ch)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod test_decoration {
+    use super::*;
+
+    #[test]
+    pub fn test_decoration_marks() {
+        for decoration in Decoration::value_variants() {
+            assert_eq!(Some(*decoration), Decoration::from_mark(decoration.mark()));
+        }
+        assert_eq!(None, Decoration::from_mark('\u{0301}'));
+    }
+}
@@ -0,0 +1,262 @@
+use std::ops::Range;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    convert_pair, display_width, letter::value_name, CharacterInfo, CharacterType, LetterStyle,
+    LetterTypeError, StyledLetter,
+};
+
+// A problem with a part of the input, rendered with the offending
+// characters underlined.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    // a stable kebab-case identifier of the kind of problem for tools, e.g.
+    // "unsupported-style"; the messages may change or be translated
+    pub code: &'static str,
+    // char indices into the source
+    pub span: Range<usize>,
+    pub message: String,
+    pub label: String,
+    pub help: Option<String>,
+}
+
+fn class_name(character_type: &CharacterType) -> &'static str {
+    match *character_type {
+        CharacterType::Letter => "letters",
+        CharacterType::Digit => "digits",
+        CharacterType::Greek => "Greek letters",
+        CharacterType::Other => "characters",
+    }
+}
+
+// a character of the class to probe which types and styles support it
+fn representative(character_type: &CharacterType) -> Option<char> {
+    match *character_type {
+        CharacterType::Letter => Some('A'),
+        CharacterType::Digit => Some('0'),
+        CharacterType::Greek => Some('Α'),
+        CharacterType::Other => None,
+    }
+}
+
+pub(crate) fn join_names<T: ValueEnum>(values: &[T]) -> String {
+    let names: Vec<String> = values.iter().map(value_name).collect();
+    names.join(", ")
+}
+
+// Why a character failed to convert, for building the messages of a
+// diagnostic.
+pub(crate) enum ConversionProblem {
+    // the letter type has the character's class, in these styles only
+    MissingStyle(CharacterType, Vec<LetterStyle>),
+    // the letter type lacks the class, these types have it
+    MissingClass(CharacterType, Vec<StyledLetter>),
+    Other,
+}
+
+impl ConversionProblem {
+    // the code of diagnostics for this problem, see `Diagnostic::code`
+    pub(crate) fn code(&self, error: &LetterTypeError) -> &'static str {
+        match (self, error) {
+            (ConversionProblem::MissingStyle(..), _) => "unsupported-style",
+            (ConversionProblem::MissingClass(..), _) => "unsupported-class",
+            (ConversionProblem::Other, LetterTypeError::InvalidCodeError(_)) => {
+                "invalid-code-point"
+            }
+            (ConversionProblem::Other, LetterTypeError::ExceedLengthError(_)) => "out-of-range",
+            (ConversionProblem::Other, _) => "unsupported-character",
+        }
+    }
+
+    pub(crate) fn of(ch: char, error: &LetterTypeError, letter_type: &StyledLetter) -> Self {
+        let character_type = match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => info.character_type,
+            CharacterInfo::Other(_) => CharacterType::Other,
+        };
+        let probe = match (error, representative(&character_type)) {
+            (
+                LetterTypeError::InvalidTypeError | LetterTypeError::InvalidStyleError,
+                Some(probe),
+            ) => probe,
+            _ => return ConversionProblem::Other,
+        };
+        let styles: Vec<LetterStyle> = LetterStyle::ALL
+            .iter()
+            .filter(|style| convert_pair(probe, letter_type, style).is_ok())
+            .cloned()
+            .collect();
+        if !styles.is_empty() {
+            return ConversionProblem::MissingStyle(character_type, styles);
+        }
+        let types: Vec<StyledLetter> = StyledLetter::ALL
+            .iter()
+            .filter(|letter_type| {
+                LetterStyle::ALL
+                    .iter()
+                    .any(|style| convert_pair(probe, letter_type, style).is_ok())
+            })
+            .cloned()
+            .collect();
+        ConversionProblem::MissingClass(character_type, types)
+    }
+}
+
+impl Diagnostic {
+    pub fn new(span: Range<usize>, message: String, label: String, help: Option<String>) -> Self {
+        Self {
+            code: "error",
+            span,
+            message,
+            label,
+            help,
+        }
+    }
+
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = code;
+        self
+    }
+
+    // Explain why `ch` can't be converted to `letter_type` `letter_style` and
+    // what would work instead.
+    pub fn conversion(
+        span: Range<usize>,
+        ch: char,
+        error: &LetterTypeError,
+        letter_type: &StyledLetter,
+        letter_style: &LetterStyle,
+    ) -> Self {
+        let label = "not converted".to_string();
+        let problem = ConversionProblem::of(ch, error, letter_type);
+        let code = problem.code(error);
+        let diagnostic = match problem {
+            ConversionProblem::MissingStyle(character_type, styles) => {
+                let class = class_name(&character_type);
+                Self::new(
+                    span,
+                    format!(
+                        "{} has no {} {}",
+                        value_name(letter_type),
+                        value_name(letter_style),
+                        class
+                    ),
+                    label,
+                    Some(format!(
+                        "{} {} exist in: {}",
+                        value_name(letter_type),
+                        class,
+                        join_names(&styles)
+                    )),
+                )
+            }
+            ConversionProblem::MissingClass(character_type, types) => {
+                let class = class_name(&character_type);
+                Self::new(
+                    span,
+                    format!("{} has no {}", value_name(letter_type), class),
+                    label,
+                    Some(format!("{} exist in: {}", class, join_names(&types))),
+                )
+            }
+            ConversionProblem::Other => Self::new(span, error.to_string(), label, None),
+        };
+        diagnostic.with_code(code)
+    }
+
+    // error: script has no digits
+    //   |
+    // 1 | Hi 42
+    //   |    ^^ not converted
+    //   = help: digits exist in: serif, sansserif, monospace, doublestruck
+    pub fn render(&self, source: &str) -> String {
+        self.render_with(source, "error", "help")
+    }
+
+    // `render` with the words for "error" and "help" given
+    pub(crate) fn render_with(&self, source: &str, error: &str, help: &str) -> String {
+        let mut res = format!("{}: {}", error, self.message);
+        let mut line_start = 0;
+        for (line_idx, line) in source.split('\n').enumerate() {
+            let line_len = line.chars().count();
+            if self.span.start > line_start + line_len {
+                line_start += line_len + 1;
+                continue;
+            }
+            let start = self.span.start - line_start;
+            let end = self
+                .span
+                .end
+                .min(line_start + line_len)
+                .max(self.span.start)
+                - line_start;
+            let prefix: String = line.chars().take(start).collect();
+            let marked: String = line.chars().skip(start).take(end - start).collect();
+            let line_number = (line_idx + 1).to_string();
+            let gutter = " ".repeat(line_number.len());
+            res.push_str(&format!("\n{} |", gutter));
+            res.push_str(&format!("\n{} | {}", line_number, line));
+            res.push_str(&format!(
+                "\n{} | {}{} {}",
+                gutter,
+                " ".repeat(display_width(&prefix)),
+                "^".repeat(display_width(&marked).max(1)),
+                self.label
+            ));
+            if let Some(ref text) = self.help {
+                res.push_str(&format!("\n{} = {}: {}", gutter, help, text));
+            }
+            return res;
+        }
+        if let Some(ref text) = self.help {
+            res.push_str(&format!("\n  = {}: {}", help, text));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test_diagnostic {
+    use super::*;
+
+    #[test]
+    pub fn test_conversion_diagnostic() {
+        let diagnostic = Diagnostic::conversion(
+            3..5,
+            '4',
+            &LetterTypeError::InvalidTypeError,
+            &StyledLetter::Script,
+            &LetterStyle::Normal,
+        );
+        assert_eq!(
+            "error: script has no digits\n  \
+               |\n\
+             1 | Hi 42\n  \
+               |    ^^ not converted\n  \
+               = help: digits exist in: serif, sansserif, monospace, doublestruck",
+            diagnostic.render("Hi 42")
+        );
+        let diagnostic = Diagnostic::conversion(
+            6..7,
+            'c',
+            &LetterTypeError::InvalidStyleError,
+            &StyledLetter::Fraktur,
+            &LetterStyle::Italic,
+        );
+        assert_eq!("fraktur has no italic letters", diagnostic.message);
+        assert_eq!("unsupported-style", diagnostic.code);
+        assert_eq!(
+            serde_json::json!({"start": 6, "end": 7}),
+            serde_json::json!(diagnostic)["span"]
+        );
+        assert_eq!(
+            Some("fraktur letters exist in: normal, bold".to_string()),
+            diagnostic.help
+        );
+        // the underline accounts for wide characters before the span
+        assert!(diagnostic
+            .render("ab\n中文 c")
+            .contains("\n2 | 中文 c\n  |      ^ not converted"));
+    }
+}
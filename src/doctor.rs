@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use crate::{
+    convert_pair, destyle,
+    export::{combinations, sources},
+    letter::value_name,
+    to_plain, CharacterInfo, PANGRAM,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoctorCheck {
+    pub name: String,
+    // what is wrong, empty when the check passed
+    pub problems: Vec<String>,
+}
+
+impl DoctorCheck {
+    pub fn new(name: &str, problems: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            problems,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DoctorReport {
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    pub fn is_ready(&self) -> bool {
+        self.checks.iter().all(DoctorCheck::passed)
+    }
+}
+
+impl Display for DoctorReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for check in self.checks.iter() {
+            writeln!(
+                f,
+                "{:<4}  {}",
+                if check.passed() { "ok" } else { "FAIL" },
+                check.name
+            )?;
+            for problem in check.problems.iter() {
+                writeln!(f, "      {}", problem)?;
+            }
+        }
+        let failed = self.checks.iter().filter(|check| !check.passed()).count();
+        if failed == 0 {
+            write!(f, "ready")
+        } else {
+            write!(f, "{} of {} checks failed", failed, self.checks.len())
+        }
+    }
+}
+
+// Every styled character has to be recognized as its font and style again
+// and de-style to the character it came from.
+fn check_tables() -> DoctorCheck {
+    let mut problems = Vec::new();
+    for (letter_type, letter_style) in combinations() {
+        let name = format!("{} {}", value_name(letter_type), value_name(letter_style));
+        for ch in sources() {
+            if matches!(CharacterInfo::get_letter_info(ch), CharacterInfo::Other(_)) {
+                continue;
+            }
+            let styled = match convert_pair(ch, letter_type, letter_style) {
+                Ok(styled) => styled,
+                Err(_) => continue,
+            };
+            let recognized = match CharacterInfo::get_letter_info(styled) {
+                CharacterInfo::Letter(info) => {
+                    info.letter_type == *letter_type && info.letter_style == *letter_style
+                }
+                CharacterInfo::Other(_) => false,
+            };
+            if !recognized {
+                problems.push(format!(
+                    "{}: '{}' for '{}' isn't recognized as {}",
+                    name, styled, ch, name
+                ));
+            } else if destyle(styled) != ch {
+                problems.push(format!(
+                    "{}: '{}' de-styles to '{}', not '{}'",
+                    name,
+                    styled,
+                    destyle(styled),
+                    ch
+                ));
+            }
+        }
+    }
+    DoctorCheck::new("style tables", problems)
+}
+
+// Converting text and de-styling it gives back the text.
+fn check_round_trips() -> DoctorCheck {
+    let mut problems = Vec::new();
+    for (letter_type, letter_style) in combinations() {
+        let styled: String = PANGRAM
+            .chars()
+            .map(|ch| convert_pair(ch, letter_type, letter_style).unwrap_or(ch))
+            .collect();
+        if to_plain(&styled) != PANGRAM {
+            problems.push(format!(
+                "{} {}: \"{}\" de-styles to \"{}\"",
+                value_name(letter_type),
+                value_name(letter_style),
+                styled,
+                to_plain(&styled)
+            ));
+        }
+    }
+    DoctorCheck::new("round trips", problems)
+}
+
+// Self-checks of the conversion tables, e.g. after editing them.
+pub fn doctor() -> DoctorReport {
+    DoctorReport {
+        checks: vec![check_tables(), check_round_trips()],
+    }
+}
+
+#[cfg(test)]
+mod test_doctor {
+    use super::*;
+
+    #[test]
+    pub fn test_doctor() {
+        let report = doctor();
+        assert!(report.is_ready(), "{}", report);
+        let report = DoctorReport {
+            checks: vec![
+                DoctorCheck::new("tables", vec![]),
+                DoctorCheck::new("locale", vec!["LANG=C is not UTF-8".to_string()]),
+            ],
+        };
+        assert!(!report.is_ready());
+        assert_eq!(
+            "ok    tables\nFAIL  locale\n      LANG=C is not UTF-8\n1 of 2 checks failed",
+            report.to_string()
+        );
+    }
+}
@@ -0,0 +1,492 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{parse, AstNode, Converter, Decoration, LetterStyle, StyledLetter};
+
+// Plain text with a font, style and decorations. Unstyled characters
+// (spaces, punctuation, ...) are serif normal like plain letters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub text: String,
+    pub letter_type: StyledLetter,
+    pub letter_style: LetterStyle,
+    pub decorations: Vec<Decoration>,
+}
+
+impl Run {
+    pub fn new(text: &str, letter_type: StyledLetter, letter_style: LetterStyle) -> Self {
+        Self {
+            text: text.to_string(),
+            letter_type,
+            letter_style,
+            decorations: Vec::new(),
+        }
+    }
+
+    pub fn with_decorations(mut self, decorations: Vec<Decoration>) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    fn same_format(&self, other: &Run) -> bool {
+        self.letter_type == other.letter_type
+            && self.letter_style == other.letter_style
+            && self.decorations == other.decorations
+    }
+
+    fn render(&self) -> String {
+        render_text(
+            &self.text,
+            &self.letter_type,
+            &self.letter_style,
+            &self.decorations,
+        )
+    }
+}
+
+// `text` in a font and style with the marks of `decorations` after every
+// grapheme cluster, so emoji sequences and accented letters stay whole.
+// Characters that can't be styled that way stay as they are.
+pub(crate) fn render_text(
+    text: &str,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+    decorations: &[Decoration],
+) -> String {
+    let converter = Converter::new(*letter_type, *letter_style);
+    let mut res = String::with_capacity(text.len() * 4);
+    for grapheme in text.graphemes(true) {
+        res.push_str(&converter.convert_str_lossy(grapheme).output);
+        res.extend(decorations.iter().map(Decoration::mark));
+    }
+    res
+}
+
+// Styled text as a sequence of runs. Positions in a document are char
+// indices into its plain text.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(into = "DocumentJson", try_from = "DocumentJson")]
+pub struct StyledDocument {
+    pub runs: Vec<Run>,
+}
+
+impl StyledDocument {
+    pub fn new(runs: Vec<Run>) -> Self {
+        Self { runs }
+    }
+
+    pub fn parse(text: &str) -> Self {
+        let runs = parse(text)
+            .nodes
+            .into_iter()
+            .map(|node| match node {
+                AstNode::Run {
+                    letter_type,
+                    letter_style,
+                    decorations,
+                    plain,
+                    ..
+                } => Run::new(&plain, letter_type, letter_style).with_decorations(decorations),
+                AstNode::PassThrough {
+                    decorations, text, ..
+                } => Run::new(&text, StyledLetter::Serif, LetterStyle::Normal)
+                    .with_decorations(decorations),
+                AstNode::Decoration { decoration, .. } => Run::new(
+                    &decoration.mark().to_string(),
+                    StyledLetter::Serif,
+                    LetterStyle::Normal,
+                ),
+            })
+            .collect();
+        let mut document = Self { runs };
+        document.normalize();
+        document
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    pub fn plain(&self) -> String {
+        self.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+
+    pub fn render(&self) -> String {
+        self.runs.iter().map(Run::render).collect()
+    }
+
+    // Split the run containing char index `at` so a run boundary falls on it.
+    fn split_at(&mut self, at: usize) {
+        let mut start = 0;
+        for idx in 0..self.runs.len() {
+            let len = self.runs[idx].text.chars().count();
+            if start < at && at < start + len {
+                let run = &mut self.runs[idx];
+                let offset = run
+                    .text
+                    .char_indices()
+                    .nth(at - start)
+                    .map(|(offset, _)| offset)
+                    .unwrap_or(run.text.len());
+                let tail = Run {
+                    text: run.text.split_off(offset),
+                    ..run.clone()
+                };
+                self.runs.insert(idx + 1, tail);
+                return;
+            }
+            start += len;
+        }
+    }
+
+    // Bring the document into its canonical form: no empty runs and no two
+    // neighbouring runs with the same format, so equal documents compare
+    // and serialize equal.
+    pub fn normalize(&mut self) {
+        let mut runs: Vec<Run> = Vec::with_capacity(self.runs.len());
+        for run in self.runs.drain(..).filter(|run| !run.text.is_empty()) {
+            match runs.last_mut() {
+                Some(last) if last.same_format(&run) => last.text.push_str(&run.text),
+                _ => runs.push(run),
+            }
+        }
+        self.runs = runs;
+    }
+}
+
+// Give the chars in `range` of the document's plain text a font and style,
+// keeping their decorations.
+pub fn apply_range(
+    document: &mut StyledDocument,
+    range: Range<usize>,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) {
+    document.split_at(range.start);
+    document.split_at(range.end);
+    let mut start = 0;
+    for run in document.runs.iter_mut() {
+        let len = run.text.chars().count();
+        if range.start <= start && start + len <= range.end && len > 0 {
+            run.letter_type = *letter_type;
+            run.letter_style = *letter_style;
+        }
+        start += len;
+    }
+    document.normalize();
+}
+
+// Version of the JSON form below, bumped on incompatible changes.
+pub const SCHEMA_VERSION: u32 = 1;
+
+// The JSON form of a document:
+//
+//   {
+//     "version": 1,
+//     "runs": [
+//       {
+//         "text": "Hi",
+//         "range": { "start": 0, "end": 2 },
+//         "font": "serif",
+//         "style": "bold",
+//         "decorations": ["underline"]
+//       }
+//     ]
+//   }
+//
+// `text` is the plain text of the run, `range` its char range in the plain
+// text of the document. Fonts, styles and decorations use the names of the
+// command line options. `range` and `decorations` may be left out when
+// reading; a `range` that is given has to match the text.
+#[derive(Serialize, Deserialize)]
+struct DocumentJson {
+    version: u32,
+    runs: Vec<RunJson>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RunJson {
+    text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    range: Option<Range<usize>>,
+    font: StyledLetter,
+    style: LetterStyle,
+    #[serde(default)]
+    decorations: Vec<Decoration>,
+}
+
+impl From<StyledDocument> for DocumentJson {
+    fn from(document: StyledDocument) -> Self {
+        let mut start = 0;
+        let runs = document
+            .runs
+            .into_iter()
+            .map(|run| {
+                let end = start + run.text.chars().count();
+                let range = Some(start..end);
+                start = end;
+                RunJson {
+                    text: run.text,
+                    range,
+                    font: run.letter_type,
+                    style: run.letter_style,
+                    decorations: run.decorations,
+                }
+            })
+            .collect();
+        DocumentJson {
+            version: SCHEMA_VERSION,
+            runs,
+        }
+    }
+}
+
+impl TryFrom<DocumentJson> for StyledDocument {
+    type Error = String;
+
+    fn try_from(json: DocumentJson) -> Result<Self, Self::Error> {
+        if json.version != SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported document version {}, expected {}",
+                json.version, SCHEMA_VERSION
+            ));
+        }
+        let mut start = 0;
+        let mut runs = Vec::with_capacity(json.runs.len());
+        for run in json.runs {
+            let end = start + run.text.chars().count();
+            match run.range {
+                Some(range) if range != (start..end) => {
+                    return Err(format!(
+                        "run range {}..{} doesn't match its text at {}..{}",
+                        range.start, range.end, start, end
+                    ))
+                }
+                _ => {}
+            }
+            start = end;
+            runs.push(Run::new(&run.text, run.font, run.style).with_decorations(run.decorations));
+        }
+        let mut document = StyledDocument { runs };
+        document.normalize();
+        Ok(document)
+    }
+}
+
+// One style change, as sent between editors instead of the whole re-styled
+// text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleEdit {
+    pub range: Range<usize>,
+    pub letter_type: StyledLetter,
+    pub letter_style: LetterStyle,
+}
+
+impl StyleEdit {
+    pub fn new(range: Range<usize>, letter_type: StyledLetter, letter_style: LetterStyle) -> Self {
+        Self {
+            range,
+            letter_type,
+            letter_style,
+        }
+    }
+}
+
+// Apply edits in order. Nothing is applied if any edit falls outside the
+// document.
+pub fn apply_patch(document: &mut StyledDocument, edits: &[StyleEdit]) -> Result<(), String> {
+    let len = document.plain().chars().count();
+    if let Some(edit) = edits
+        .iter()
+        .find(|edit| edit.range.start > edit.range.end || edit.range.end > len)
+    {
+        return Err(format!(
+            "edit range {}..{} is out of bounds for a document of {} characters",
+            edit.range.start, edit.range.end, len
+        ));
+    }
+    for edit in edits {
+        apply_range(
+            document,
+            edit.range.clone(),
+            &edit.letter_type,
+            &edit.letter_style,
+        );
+    }
+    Ok(())
+}
+
+// char index in the document of `text` for a byte offset into `text`;
+// decoration marks belong to their letter and take no position
+fn document_index(text: &str, byte_offset: usize) -> usize {
+    let mut index = 0;
+    for node in parse(text).nodes {
+        let range = node.range().clone();
+        if range.start >= byte_offset {
+            break;
+        }
+        let end = range.end.min(byte_offset);
+        let chars = text[range.start..end].chars();
+        index += match node {
+            AstNode::Decoration { .. } => chars.count(),
+            _ => chars
+                .filter(|ch| Decoration::from_mark(*ch).is_none())
+                .count(),
+        };
+    }
+    index
+}
+
+// Style the part of `text` in the byte range `range`, the rest keeps its
+// current style.
+pub fn style_range(
+    text: &str,
+    range: Range<usize>,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> String {
+    let mut document = StyledDocument::parse(text);
+    let range = document_index(text, range.start)..document_index(text, range.end);
+    apply_range(&mut document, range, letter_type, letter_style);
+    document.render()
+}
+
+#[cfg(test)]
+mod test_document {
+    use super::*;
+
+    #[test]
+    pub fn test_apply_range() {
+        let mut document = StyledDocument::parse("hello 𝐰𝐨𝐫𝐥𝐝");
+        apply_range(
+            &mut document,
+            3..8,
+            &StyledLetter::Serif,
+            &LetterStyle::Bold,
+        );
+        assert_eq!(
+            vec![
+                Run::new("hel", StyledLetter::Serif, LetterStyle::Normal),
+                Run::new("lo world", StyledLetter::Serif, LetterStyle::Bold),
+            ],
+            document.runs
+        );
+        assert_eq!("hel𝐥𝐨 𝐰𝐨𝐫𝐥𝐝", document.render());
+        apply_range(
+            &mut document,
+            0..11,
+            &StyledLetter::Serif,
+            &LetterStyle::Normal,
+        );
+        assert_eq!("hello world", document.render());
+        assert_eq!(1, document.runs.len());
+    }
+
+    #[test]
+    pub fn test_normalize() {
+        let mut document = StyledDocument::new(vec![
+            Run::new("a", StyledLetter::Serif, LetterStyle::Bold),
+            Run::new("", StyledLetter::Script, LetterStyle::Normal),
+            Run::new("b", StyledLetter::Serif, LetterStyle::Bold),
+            Run::new("c", StyledLetter::Serif, LetterStyle::Bold)
+                .with_decorations(vec![Decoration::Underline]),
+            Run::new("", StyledLetter::Serif, LetterStyle::Normal),
+        ]);
+        document.normalize();
+        assert_eq!(
+            vec![
+                Run::new("ab", StyledLetter::Serif, LetterStyle::Bold),
+                Run::new("c", StyledLetter::Serif, LetterStyle::Bold)
+                    .with_decorations(vec![Decoration::Underline]),
+            ],
+            document.runs
+        );
+        // an orphan mark and the text after it are both plain
+        assert_eq!(1, StyledDocument::parse("\u{332}ab").runs.len());
+    }
+
+    #[test]
+    pub fn test_render_emoji() {
+        let document = StyledDocument::new(vec![Run::new(
+            "a👍🏽🇯🇵",
+            StyledLetter::Serif,
+            LetterStyle::Bold,
+        )
+        .with_decorations(vec![Decoration::Underline])]);
+        // one mark after each cluster, none inside the emoji sequences
+        assert_eq!("𝐚\u{332}👍🏽\u{332}🇯🇵\u{332}", document.render());
+    }
+
+    #[test]
+    pub fn test_apply_patch() {
+        let mut document = StyledDocument::parse("styled text");
+        let edits = [
+            StyleEdit::new(0..6, StyledLetter::Serif, LetterStyle::Bold),
+            StyleEdit::new(7..11, StyledLetter::Script, LetterStyle::Normal),
+        ];
+        assert_eq!(Ok(()), apply_patch(&mut document, &edits));
+        assert_eq!("𝐬𝐭𝐲𝐥𝐞𝐝 𝓉ℯ𝓍𝓉", document.render());
+
+        let edits = [
+            StyleEdit::new(0..6, StyledLetter::Serif, LetterStyle::Normal),
+            StyleEdit::new(7..12, StyledLetter::Serif, LetterStyle::Bold),
+        ];
+        assert_eq!(
+            Err("edit range 7..12 is out of bounds for a document of 11 characters".to_string()),
+            apply_patch(&mut document, &edits)
+        );
+        assert_eq!("𝐬𝐭𝐲𝐥𝐞𝐝 𝓉ℯ𝓍𝓉", document.render());
+    }
+
+    #[test]
+    pub fn test_json() {
+        let document = StyledDocument::parse("𝐇i\u{332}");
+        let json = document.to_json();
+        assert_eq!(
+            r#"{"version":1,"runs":[{"text":"H","range":{"start":0,"end":1},"font":"serif","style":"bold","decorations":[]},{"text":"i","range":{"start":1,"end":2},"font":"serif","style":"normal","decorations":["underline"]}]}"#,
+            json
+        );
+        assert_eq!(Ok(document), StyledDocument::from_json(&json));
+        assert_eq!(
+            "𝔞",
+            StyledDocument::from_json(
+                r#"{"version":1,"runs":[{"text":"a","font":"fraktur","style":"normal"}]}"#
+            )
+            .unwrap()
+            .render()
+        );
+        assert_eq!(
+            Err("unsupported document version 2, expected 1".to_string()),
+            StyledDocument::from_json(r#"{"version":2,"runs":[]}"#)
+        );
+        assert!(StyledDocument::from_json(
+            r#"{"version":1,"runs":[{"text":"a","range":{"start":0,"end":2},"font":"serif","style":"bold"}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_style_range() {
+        let text = "a\u{332}b\u{332}cd";
+        // bytes 3..6 are "b" and its underline
+        assert_eq!(
+            "a\u{332}𝐛\u{332}cd",
+            style_range(text, 3..6, &StyledLetter::Serif, &LetterStyle::Bold)
+        );
+        assert_eq!(
+            "𝕒𝕓",
+            style_range("ab", 0..2, &StyledLetter::DoubleStruck, &LetterStyle::Bold)
+        );
+        assert_eq!(
+            "ab",
+            style_range("ab", 1..1, &StyledLetter::Script, &LetterStyle::Bold)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt::Display;
+
+// Parts of social media posts that stop working when styled: links aren't
+// linkified, mentions don't notify, hashtags aren't searchable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+    Url,
+    Mention,
+    Hashtag,
+    // :thumbsup:
+    EmojiCode,
+}
+
+impl Display for EntityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            EntityKind::Url => f.write_str("url"),
+            EntityKind::Mention => f.write_str("mention"),
+            EntityKind::Hashtag => f.write_str("hashtag"),
+            EntityKind::EmojiCode => f.write_str("emoji code"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntitySegment<'a> {
+    Entity(EntityKind, &'a str),
+    Text(&'a str),
+}
+
+fn is_trailing_punctuation(ch: char) -> bool {
+    matches!(
+        ch,
+        '.' | ',' | '!' | '?' | ':' | ';' | ')' | ']' | '}' | '\'' | '"'
+    )
+}
+
+// Kind and length in bytes of the entity at the start of `rest`, if there
+// is one there.
+fn entity_len(rest: &str) -> Option<(EntityKind, usize)> {
+    let lower = rest.get(..8).unwrap_or(rest).to_ascii_lowercase();
+    if ["http://", "https://", "www."]
+        .iter()
+        .any(|prefix| lower.starts_with(prefix))
+    {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let len = rest[..end].trim_end_matches(is_trailing_punctuation).len();
+        return Some((EntityKind::Url, len));
+    }
+    let mut chars = rest.chars();
+    match chars.next()? {
+        // @user, and @user@instance.social on Mastodon
+        '@' => {
+            let name = chars
+                .as_str()
+                .find(|ch: char| !(ch.is_alphanumeric() || matches!(ch, '_' | '@' | '.' | '-')))
+                .unwrap_or(chars.as_str().len());
+            let name = chars.as_str()[..name].trim_end_matches(['.', '-', '@']);
+            let valid = name.starts_with(|ch: char| ch.is_alphanumeric() || ch == '_');
+            valid.then_some((EntityKind::Mention, 1 + name.len()))
+        }
+        '#' => {
+            let tag = chars
+                .as_str()
+                .find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .unwrap_or(chars.as_str().len());
+            // "#1" is a number, not a tag
+            let valid = chars.as_str()[..tag].chars().any(char::is_alphabetic);
+            valid.then_some((EntityKind::Hashtag, 1 + tag))
+        }
+        ':' => {
+            let code = chars
+                .as_str()
+                .find(|ch: char| !(ch.is_ascii_alphanumeric() || matches!(ch, '_' | '+' | '-')))?;
+            // "10:30:45" isn't one, entities start at the beginning of a word
+            let valid = code > 0 && chars.as_str()[code..].starts_with(':');
+            valid.then_some((EntityKind::EmojiCode, code + 2))
+        }
+        _ => None,
+    }
+}
+
+// Split text into URLs, @mentions, #hashtags, :emoji_codes: and the text
+// between them. Entities only start at the beginning of a word.
+pub fn split_entities(text: &str) -> Vec<EntitySegment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut offset = 0;
+    let mut prev: Option<char> = None;
+    while offset < text.len() {
+        let at_word_start = !prev.is_some_and(|ch| ch.is_alphanumeric() || ch == '_');
+        let entity = if at_word_start {
+            entity_len(&text[offset..]).filter(|(_, len)| *len > 1)
+        } else {
+            None
+        };
+        match entity {
+            Some((kind, len)) => {
+                if text_start < offset {
+                    segments.push(EntitySegment::Text(&text[text_start..offset]));
+                }
+                let entity = &text[offset..offset + len];
+                segments.push(EntitySegment::Entity(kind, entity));
+                offset += len;
+                text_start = offset;
+                prev = entity.chars().last();
+            }
+            None => {
+                let ch = text[offset..].chars().next().unwrap_or_default();
+                offset += ch.len_utf8();
+                prev = Some(ch);
+            }
+        }
+    }
+    if text_start < text.len() {
+        segments.push(EntitySegment::Text(&text[text_start..]));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod test_entity {
+    use super::*;
+
+    #[test]
+    pub fn test_split_entities() {
+        assert_eq!(
+            vec![
+                EntitySegment::Text("Hi "),
+                EntitySegment::Entity(EntityKind::Mention, "@bob"),
+                EntitySegment::Text(", see ("),
+                EntitySegment::Entity(EntityKind::Url, "https://example.com/a?b=c"),
+                EntitySegment::Text(") "),
+                EntitySegment::Entity(EntityKind::Hashtag, "#rust_lang"),
+                EntitySegment::Text(" "),
+                EntitySegment::Entity(EntityKind::EmojiCode, ":+1:"),
+                EntitySegment::Text("."),
+            ],
+            split_entities("Hi @bob, see (https://example.com/a?b=c) #rust_lang :+1:.")
+        );
+        assert_eq!(
+            vec![
+                EntitySegment::Entity(EntityKind::Mention, "@alice@mastodon.social"),
+                EntitySegment::Text(" at 10:30:45, "),
+                EntitySegment::Entity(EntityKind::Url, "www.example.com"),
+            ],
+            split_entities("@alice@mastodon.social at 10:30:45, www.example.com")
+        );
+        for text in ["mail me@example.com", "issue #1", "a @ b", "C#", "x :: y"] {
+            assert_eq!(vec![EntitySegment::Text(text)], split_entities(text));
+        }
+        assert!(split_entities("").is_empty());
+    }
+}
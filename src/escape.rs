@@ -0,0 +1,83 @@
+const RAW_OPEN: &str = "{{raw:";
+const RAW_CLOSE: &str = "}}";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EscapeSegment<'a> {
+    Text(&'a str),
+    // text to leave as it is, and the escape it was written as
+    Raw { raw: &'a str, source: &'a str },
+}
+
+// Split text at escapes: a backslash leaves the character after it
+// unconverted ("\\" for a backslash itself), `{{raw:...}}` a whole span.
+// Unclosed spans and a trailing backslash are text.
+pub fn split_escapes(text: &str) -> Vec<EscapeSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut offset = 0;
+    while offset < text.len() {
+        let rest = &text[offset..];
+        let escape = if let Some(after) = rest.strip_prefix('\\') {
+            after.chars().next().map(|ch| {
+                let len = 1 + ch.len_utf8();
+                (&rest[1..len], len)
+            })
+        } else if let Some(after) = rest.strip_prefix(RAW_OPEN) {
+            after
+                .find(RAW_CLOSE)
+                .map(|end| (&after[..end], RAW_OPEN.len() + end + RAW_CLOSE.len()))
+        } else {
+            None
+        };
+        match escape {
+            Some((raw, len)) => {
+                if text_start < offset {
+                    segments.push(EscapeSegment::Text(&text[text_start..offset]));
+                }
+                segments.push(EscapeSegment::Raw {
+                    raw,
+                    source: &rest[..len],
+                });
+                offset += len;
+                text_start = offset;
+            }
+            None => offset += rest.chars().next().map(char::len_utf8).unwrap_or(1),
+        }
+    }
+    if text_start < text.len() {
+        segments.push(EscapeSegment::Text(&text[text_start..]));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod test_escape {
+    use super::*;
+
+    #[test]
+    pub fn test_split_escapes() {
+        assert_eq!(
+            vec![
+                EscapeSegment::Text("run "),
+                EscapeSegment::Raw {
+                    raw: "ls -la",
+                    source: "{{raw:ls -la}}",
+                },
+                EscapeSegment::Text(" for "),
+                EscapeSegment::Raw {
+                    raw: "I",
+                    source: "\\I",
+                },
+                EscapeSegment::Text("D "),
+                EscapeSegment::Raw {
+                    raw: "\\",
+                    source: "\\\\",
+                },
+                EscapeSegment::Text(" {{raw:open \\"),
+            ],
+            split_escapes("run {{raw:ls -la}} for \\ID \\\\ {{raw:open \\")
+        );
+        assert_eq!(vec![EscapeSegment::Text("plain")], split_escapes("plain"));
+        assert!(split_escapes("").is_empty());
+    }
+}
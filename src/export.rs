@@ -0,0 +1,143 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{convert_pair, letter::value_name, LetterStyle, StyledLetter};
+
+// Every character a style can be applied to.
+pub(crate) fn sources() -> impl Iterator<Item = char> {
+    ('A'..='Z')
+        .chain('a'..='z')
+        .chain('0'..='9')
+        .chain('Α'..='Ω')
+        .chain('α'..='ω')
+        // unassigned, the math alphabets put capital theta symbol there
+        .filter(|ch| *ch != '\u{03A2}')
+}
+
+pub(crate) fn combinations() -> impl Iterator<Item = (&'static StyledLetter, &'static LetterStyle)>
+{
+    StyledLetter::ALL.iter().flat_map(|letter_type| {
+        LetterStyle::ALL
+            .iter()
+            .map(move |letter_style| (letter_type, letter_style))
+    })
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+// One entry of the conversion tables.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Mapping {
+    pub char: char,
+    pub font: String,
+    pub style: String,
+    // "U+1D400"
+    pub codepoint: String,
+    pub styled: char,
+    // taken from Letterlike Symbols (ℎ, ℬ, ℭ, ...) because the
+    // Mathematical Alphanumeric Symbols block has a hole there
+    pub corner_case: bool,
+}
+
+// The mapping of every character in every font and style it exists in.
+pub fn mappings() -> Vec<Mapping> {
+    let mut mappings = Vec::new();
+    for (letter_type, letter_style) in combinations() {
+        for ch in sources() {
+            if let Ok(styled) = convert_pair(ch, letter_type, letter_style) {
+                mappings.push(Mapping {
+                    char: ch,
+                    font: value_name(letter_type),
+                    style: value_name(letter_style),
+                    codepoint: format!("U+{:04X}", styled as u32),
+                    styled,
+                    corner_case: styled != ch && !matches!(styled as u32, 0x1D400..=0x1D7FF),
+                });
+            }
+        }
+    }
+    mappings
+}
+
+const HEADERS: [&str; 6] = [
+    "char",
+    "font",
+    "style",
+    "codepoint",
+    "styled",
+    "corner_case",
+];
+
+fn fields(mapping: &Mapping) -> [String; 6] {
+    [
+        mapping.char.to_string(),
+        mapping.font.clone(),
+        mapping.style.clone(),
+        mapping.codepoint.clone(),
+        mapping.styled.to_string(),
+        mapping.corner_case.to_string(),
+    ]
+}
+
+// The full mapping table in `format`, e.g. to generate lookup tables for
+// other languages from it.
+pub fn export(format: ExportFormat) -> String {
+    let mappings = mappings();
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&mappings).unwrap_or_default(),
+        ExportFormat::Csv => {
+            let mut res = HEADERS.join(",");
+            for mapping in mappings.iter() {
+                res.push('\n');
+                res.push_str(&fields(mapping).join(","));
+            }
+            res
+        }
+        ExportFormat::Markdown => {
+            let mut res = format!("| {} |\n|{}", HEADERS.join(" | "), "---|".repeat(6));
+            for mapping in mappings.iter() {
+                res.push_str(&format!("\n| {} |", fields(mapping).join(" | ")));
+            }
+            res
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_export {
+    use super::*;
+
+    #[test]
+    pub fn test_export() {
+        let mappings = mappings();
+        let script_b = Mapping {
+            char: 'B',
+            font: "script".to_string(),
+            style: "normal".to_string(),
+            codepoint: "U+212C".to_string(),
+            styled: 'ℬ',
+            corner_case: true,
+        };
+        assert!(mappings.contains(&script_b));
+        assert!(!mappings
+            .iter()
+            .any(|mapping| mapping.font == "fraktur" && mapping.style == "italic"));
+
+        let csv = export(ExportFormat::Csv);
+        assert!(csv.starts_with(
+            "char,font,style,codepoint,styled,corner_case\nA,serif,normal,U+0041,A,false\n"
+        ));
+        assert!(csv.contains("\nB,script,normal,U+212C,ℬ,true\n"));
+        let markdown = export(ExportFormat::Markdown);
+        assert!(markdown.starts_with(
+            "| char | font | style | codepoint | styled | corner_case |\n|---|---|---|---|---|---|\n| A | serif | normal | U+0041 | A | false |"
+        ));
+        assert!(export(ExportFormat::Json).contains("\"codepoint\": \"U+212C\""));
+    }
+}
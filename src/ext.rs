@@ -0,0 +1,113 @@
+use crate::{convert_str, Font, LetterStyle, LetterTypeError, StyledLetter};
+
+// Conversions as methods on strings, e.g. `format!("{}", "Note".to_serif_bold())`.
+// The font shortcuts never fail: characters the font has no glyphs for, like
+// script digits, are kept as they are.
+pub trait StyledTextExt {
+    fn to_styled(
+        &self,
+        letter_type: StyledLetter,
+        letter_style: LetterStyle,
+    ) -> Result<String, LetterTypeError>;
+
+    fn to_font(&self, font: Font) -> String;
+
+    // back to plain ASCII and Greek
+    fn to_unstyled(&self) -> String {
+        self.to_font(Font::Serif)
+    }
+
+    fn to_serif_bold(&self) -> String {
+        self.to_font(Font::SerifBold)
+    }
+
+    fn to_serif_italic(&self) -> String {
+        self.to_font(Font::SerifItalic)
+    }
+
+    fn to_serif_bold_italic(&self) -> String {
+        self.to_font(Font::SerifBoldItalic)
+    }
+
+    fn to_sans_serif(&self) -> String {
+        self.to_font(Font::SansSerif)
+    }
+
+    fn to_sans_serif_bold(&self) -> String {
+        self.to_font(Font::SansSerifBold)
+    }
+
+    fn to_sans_serif_italic(&self) -> String {
+        self.to_font(Font::SansSerifItalic)
+    }
+
+    fn to_sans_serif_bold_italic(&self) -> String {
+        self.to_font(Font::SansSerifBoldItalic)
+    }
+
+    fn to_script(&self) -> String {
+        self.to_font(Font::Script)
+    }
+
+    fn to_script_bold(&self) -> String {
+        self.to_font(Font::ScriptBold)
+    }
+
+    fn to_fraktur(&self) -> String {
+        self.to_font(Font::Fraktur)
+    }
+
+    fn to_fraktur_bold(&self) -> String {
+        self.to_font(Font::FrakturBold)
+    }
+
+    fn to_double_struck(&self) -> String {
+        self.to_font(Font::DoubleStruck)
+    }
+
+    fn to_monospace(&self) -> String {
+        self.to_font(Font::Monospace)
+    }
+}
+
+impl StyledTextExt for str {
+    fn to_styled(
+        &self,
+        letter_type: StyledLetter,
+        letter_style: LetterStyle,
+    ) -> Result<String, LetterTypeError> {
+        convert_str(self, &letter_type, &letter_style)
+    }
+
+    fn to_font(&self, font: Font) -> String {
+        font.convert_str_lossy(self).output
+    }
+}
+
+#[cfg(test)]
+mod test_ext {
+    use super::*;
+
+    #[test]
+    pub fn test_styled_text_ext() {
+        assert_eq!(
+            "𝐍𝐨𝐭𝐞: 𝟏",
+            format!("{}: {}", "Note".to_serif_bold(), "1".to_serif_bold())
+        );
+        assert_eq!("𝒜𝒷𝒸 1", "Abc 1".to_script());
+        assert_eq!("𝔸𝕓 𝟙", String::from("Ab 1").to_double_struck());
+        assert_eq!("Note", "𝐍𝐨𝐭𝐞".to_unstyled());
+        assert_eq!("1️⃣ 𝟷", "1️⃣ 1".to_monospace());
+        assert_eq!("#️⃣ 𝕒", "#️⃣ a".to_double_struck());
+        // a ZWJ sequence with a letter-like part stays whole
+        assert_eq!("🏳️‍⚧️ 𝔞", "🏳️‍⚧️ a".to_fraktur());
+        assert_eq!(
+            "𝖠𝖻",
+            "Ab".to_styled(StyledLetter::SansSerif, LetterStyle::Normal)
+                .unwrap()
+        );
+        assert!("1"
+            .to_styled(StyledLetter::Script, LetterStyle::Normal)
+            .is_err());
+    }
+}
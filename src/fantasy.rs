@@ -0,0 +1,197 @@
+use crate::{StyleRegistry, Styler};
+
+// Letters of historic scripts picked for looking like (or sounding like)
+// Latin letters, for "aesthetic rune" text. These are not transliterations:
+// the output is gibberish in the real script and reads as such.
+#[derive(Debug)]
+struct Lookalike {
+    name: &'static str,
+    // scripts without case write lowercase letters with the capital's form
+    caseless: bool,
+    // (capital, lookalike), capitals without a lookalike are left out
+    table: &'static [(char, char)],
+}
+
+const RUNIC: &[(char, char)] = &[
+    ('A', 'ᚨ'),
+    ('B', 'ᛒ'),
+    ('C', 'ᚲ'),
+    ('D', 'ᛞ'),
+    ('E', 'ᛖ'),
+    ('F', 'ᚠ'),
+    ('G', 'ᚷ'),
+    ('H', 'ᚺ'),
+    ('I', 'ᛁ'),
+    ('J', 'ᛃ'),
+    ('K', 'ᚴ'),
+    ('L', 'ᛚ'),
+    ('M', 'ᛗ'),
+    ('N', 'ᚾ'),
+    ('O', 'ᛟ'),
+    ('P', 'ᛈ'),
+    ('Q', 'ᛩ'),
+    ('R', 'ᚱ'),
+    ('S', 'ᛊ'),
+    ('T', 'ᛏ'),
+    ('U', 'ᚢ'),
+    ('V', 'ᚡ'),
+    ('W', 'ᚹ'),
+    ('X', 'ᛪ'),
+    ('Y', 'ᛦ'),
+    ('Z', 'ᛉ'),
+];
+
+const OLD_ITALIC: &[(char, char)] = &[
+    ('A', '𐌀'),
+    ('B', '𐌁'),
+    ('C', '𐌂'),
+    ('D', '𐌃'),
+    ('E', '𐌄'),
+    ('F', '𐌅'),
+    ('H', '𐌇'),
+    ('I', '𐌉'),
+    ('K', '𐌊'),
+    ('L', '𐌋'),
+    ('M', '𐌌'),
+    ('N', '𐌍'),
+    ('O', '𐌏'),
+    ('P', '𐌐'),
+    ('Q', '𐌒'),
+    ('R', '𐌓'),
+    ('S', '𐌔'),
+    ('T', '𐌕'),
+    ('V', '𐌖'),
+    ('X', '𐌗'),
+    ('Y', '𐌙'),
+    ('Z', '𐌆'),
+];
+
+// lowercase forms come from Unicode's case mapping
+const COPTIC: &[(char, char)] = &[
+    ('A', 'Ⲁ'),
+    ('B', 'Ⲃ'),
+    ('C', 'Ⲥ'),
+    ('D', 'Ⲇ'),
+    ('E', 'Ⲉ'),
+    ('F', 'Ϥ'),
+    ('H', 'Ⲏ'),
+    ('I', 'Ⲓ'),
+    ('K', 'Ⲕ'),
+    ('L', 'Ⲗ'),
+    ('M', 'Ⲙ'),
+    ('N', 'Ⲛ'),
+    ('O', 'Ⲟ'),
+    ('P', 'Ⲣ'),
+    ('T', 'Ⲧ'),
+    ('W', 'Ⲱ'),
+    ('X', 'Ⲭ'),
+    ('Y', 'Ⲩ'),
+    ('Z', 'Ⲍ'),
+];
+
+impl Styler for Lookalike {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn style_char(&self, ch: char) -> Option<char> {
+        if !ch.is_ascii_alphabetic() || (self.caseless && ch.is_ascii_lowercase()) {
+            return None;
+        }
+        let capital = ch.to_ascii_uppercase();
+        let (_, styled) = self.table.iter().find(|(letter, _)| *letter == capital)?;
+        if ch.is_ascii_lowercase() {
+            styled.to_lowercase().next()
+        } else {
+            Some(*styled)
+        }
+    }
+
+    fn unstyle_char(&self, ch: char) -> Option<char> {
+        let capital = ch.to_uppercase().next()?;
+        let (letter, _) = self.table.iter().find(|(_, styled)| *styled == capital)?;
+        if capital != ch {
+            Some(letter.to_ascii_lowercase())
+        } else {
+            Some(*letter)
+        }
+    }
+
+    fn gaps(&self) -> Vec<char> {
+        ('A'..='Z')
+            .chain('a'..='z')
+            .filter(|ch| self.style_char(*ch).is_none())
+            .collect()
+    }
+
+    fn lookalike(&self) -> bool {
+        true
+    }
+
+    fn style(&self, text: &str) -> String {
+        text.chars()
+            .map(|ch| {
+                let letter = if self.caseless {
+                    ch.to_ascii_uppercase()
+                } else {
+                    ch
+                };
+                self.style_char(letter).unwrap_or(ch)
+            })
+            .collect()
+    }
+}
+
+// The runic, old-italic and coptic lookalike styles.
+pub fn fantasy_pack() -> Vec<Box<dyn Styler>> {
+    vec![
+        Box::new(Lookalike {
+            name: "runic",
+            caseless: true,
+            table: RUNIC,
+        }),
+        Box::new(Lookalike {
+            name: "old-italic",
+            caseless: true,
+            table: OLD_ITALIC,
+        }),
+        Box::new(Lookalike {
+            name: "coptic",
+            caseless: false,
+            table: COPTIC,
+        }),
+    ]
+}
+
+pub fn register_fantasy_pack(registry: &mut StyleRegistry) -> Result<(), String> {
+    for styler in fantasy_pack() {
+        registry.register(styler)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_fantasy {
+    use super::*;
+    use crate::conformance;
+
+    #[test]
+    pub fn test_fantasy_pack() {
+        let mut registry = StyleRegistry::new();
+        register_fantasy_pack(&mut registry).unwrap();
+        for styler in registry.stylers() {
+            let report = conformance(styler);
+            assert!(report.is_ready(), "{}: {}", styler.name(), report);
+        }
+        assert_eq!("ᚺᛖᛚᛚᛟ!", registry.get("runic").unwrap().style("Hello!"));
+        assert_eq!(
+            "𐌇𐌄𐌋𐌋𐌏 g𐌏",
+            registry.get("old-italic").unwrap().style("Hello go")
+        );
+        let coptic = registry.get("coptic").unwrap();
+        assert_eq!("Ⲏⲉⲗⲗⲟ", coptic.style("Hello"));
+        assert_eq!("Hello", coptic.unstyle("Ⲏⲉⲗⲗⲟ"));
+        // lookalikes never count as strict styles
+        assert_eq!(0, registry.strict_stylers().count());
+    }
+}
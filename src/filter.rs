@@ -0,0 +1,83 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{to_plain, Converter};
+
+// What a git filter does to a file on its way into or out of the
+// repository.
+#[derive(Debug, Clone)]
+pub enum FilterMode {
+    // de-style, for the repository copy
+    Clean,
+    // style, for the working tree copy
+    Smudge(Converter),
+}
+
+impl FilterMode {
+    // Characters the style has no form for stay as they are, so that
+    // applying the filter again changes nothing.
+    pub fn apply(&self, text: &str) -> String {
+        let converter = match self {
+            FilterMode::Clean => return to_plain(text),
+            FilterMode::Smudge(converter) => converter,
+        };
+        let converted = converter.convert_str_lossy(text);
+        let skipped = converted.failures.len();
+        #[cfg(feature = "tracing")]
+        if skipped > 0 {
+            tracing::debug!(skipped, "characters left unconverted by the smudge filter");
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = skipped;
+        converted.output
+    }
+}
+
+// Stream `input` to `output` line by line. Lines that aren't UTF-8 are
+// copied byte for byte, so binary files pass through unharmed.
+pub fn filter<R: BufRead, W: Write>(
+    mut input: R,
+    mut output: W,
+    mode: &FilterMode,
+) -> io::Result<()> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("filter", clean = matches!(mode, FilterMode::Clean)).entered();
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if input.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        match std::str::from_utf8(&line) {
+            Ok(text) => output.write_all(mode.apply(text).as_bytes())?,
+            Err(_) => output.write_all(&line)?,
+        }
+    }
+    output.flush()
+}
+
+#[cfg(test)]
+mod test_filter {
+    use super::*;
+    use crate::{LetterStyle, StyledLetter};
+
+    fn run(input: &[u8], mode: &FilterMode) -> Vec<u8> {
+        let mut output = Vec::new();
+        filter(input, &mut output, mode).unwrap();
+        output
+    }
+
+    #[test]
+    pub fn test_filter() {
+        let smudge = FilterMode::Smudge(Converter::new(StyledLetter::Serif, LetterStyle::Italic));
+        let plain = "Hello 12 👩‍💻\nsecond line".as_bytes();
+        let styled = run(plain, &smudge);
+        assert_eq!("𝐻𝑒𝑙𝑙𝑜 12 👩‍💻\n𝑠𝑒𝑐𝑜𝑛𝑑 𝑙𝑖𝑛𝑒", String::from_utf8_lossy(&styled));
+        // idempotent both ways, and clean undoes smudge
+        assert_eq!(styled, run(&styled, &smudge));
+        assert_eq!(plain, run(&styled, &FilterMode::Clean));
+        assert_eq!(plain, run(plain, &FilterMode::Clean));
+
+        let binary = b"\xff\xfeab\n\x00\x80";
+        assert_eq!(binary.to_vec(), run(binary, &smudge));
+    }
+}
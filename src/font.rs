@@ -0,0 +1,200 @@
+use std::{fmt::Display, str::FromStr};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    convert_pair,
+    letter::{parse_value_enum, value_name},
+    CharacterInfo, CharacterType, Converter, LetterStyle, LetterTypeError, LossyConversion,
+    StyleSpec, StyledLetter,
+};
+
+// One of the alphabets of the Mathematical Alphanumeric Symbols block, plus
+// plain serif. Unlike a `StyledLetter` and `LetterStyle` pair, every value
+// exists: there is no fraktur italic to ask for. Double-struck letters are
+// bold and its digits normal in the pair model; here both are double-struck.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[value(rename_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Font {
+    // plain ASCII and Greek
+    Serif,
+    SerifBold,
+    SerifItalic,
+    SerifBoldItalic,
+    SansSerif,
+    SansSerifBold,
+    SansSerifItalic,
+    SansSerifBoldItalic,
+    Script,
+    ScriptBold,
+    Fraktur,
+    FrakturBold,
+    DoubleStruck,
+    Monospace,
+}
+
+impl Font {
+    pub const ALL: &[Font] = &[
+        Font::Serif,
+        Font::SerifBold,
+        Font::SerifItalic,
+        Font::SerifBoldItalic,
+        Font::SansSerif,
+        Font::SansSerifBold,
+        Font::SansSerifItalic,
+        Font::SansSerifBoldItalic,
+        Font::Script,
+        Font::ScriptBold,
+        Font::Fraktur,
+        Font::FrakturBold,
+        Font::DoubleStruck,
+        Font::Monospace,
+    ];
+
+    // the letter type and style of its letters
+    pub fn parts(&self) -> (StyledLetter, LetterStyle) {
+        match *self {
+            Font::Serif => (StyledLetter::Serif, LetterStyle::Normal),
+            Font::SerifBold => (StyledLetter::Serif, LetterStyle::Bold),
+            Font::SerifItalic => (StyledLetter::Serif, LetterStyle::Italic),
+            Font::SerifBoldItalic => (StyledLetter::Serif, LetterStyle::BoldItalic),
+            Font::SansSerif => (StyledLetter::SansSerif, LetterStyle::Normal),
+            Font::SansSerifBold => (StyledLetter::SansSerif, LetterStyle::Bold),
+            Font::SansSerifItalic => (StyledLetter::SansSerif, LetterStyle::Italic),
+            Font::SansSerifBoldItalic => (StyledLetter::SansSerif, LetterStyle::BoldItalic),
+            Font::Script => (StyledLetter::Script, LetterStyle::Normal),
+            Font::ScriptBold => (StyledLetter::Script, LetterStyle::Bold),
+            Font::Fraktur => (StyledLetter::Fraktur, LetterStyle::Normal),
+            Font::FrakturBold => (StyledLetter::Fraktur, LetterStyle::Bold),
+            Font::DoubleStruck => (StyledLetter::DoubleStruck, LetterStyle::Bold),
+            Font::Monospace => (StyledLetter::MonoSpace, LetterStyle::Normal),
+        }
+    }
+
+    // the letter type and style to convert a character of `class` with
+    fn parts_for(&self, class: &CharacterType) -> (StyledLetter, LetterStyle) {
+        match (self, class) {
+            (Font::DoubleStruck, CharacterType::Digit) => {
+                (StyledLetter::DoubleStruck, LetterStyle::Normal)
+            }
+            _ => self.parts(),
+        }
+    }
+
+    // None for pairs that have no characters at all, e.g. fraktur italic
+    pub fn from_parts(letter_type: StyledLetter, letter_style: LetterStyle) -> Option<Font> {
+        match (letter_type, letter_style) {
+            (StyledLetter::DoubleStruck, LetterStyle::Normal) => Some(Font::DoubleStruck),
+            pair => Font::ALL.iter().find(|font| font.parts() == pair).copied(),
+        }
+    }
+
+    // the font a character is in; None for characters that have no styled
+    // forms
+    pub fn of(ch: char) -> Option<Font> {
+        match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => Font::from_parts(info.letter_type, info.letter_style),
+            CharacterInfo::Other(_) => None,
+        }
+    }
+
+    // Like `convert`; fails for characters of a class the font has no glyphs
+    // for, e.g. script digits.
+    pub fn convert(&self, ch: char) -> Result<char, LetterTypeError> {
+        let class = match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => *info.character_type(),
+            CharacterInfo::Other(ch) => return Ok(ch),
+        };
+        let (letter_type, letter_style) = self.parts_for(&class);
+        convert_pair(ch, &letter_type, &letter_style)
+    }
+
+    // A converter for the font, for string conversions and the options of
+    // `Converter`
+    pub fn converter(&self) -> Converter {
+        let (letter_type, letter_style) = self.parts();
+        let converter = Converter::new(letter_type, letter_style);
+        match self {
+            Font::DoubleStruck => {
+                converter.with_fallback(CharacterType::Digit, vec![LetterStyle::Normal])
+            }
+            _ => converter,
+        }
+    }
+
+    // Like `convert` for every char, keeping emoji sequences such as keycaps
+    // whole.
+    pub fn convert_str(&self, text: &str) -> Result<String, LetterTypeError> {
+        self.converter().convert_str(text)
+    }
+
+    // `convert_str` that keeps the chars the font has no glyphs for
+    pub fn convert_str_lossy(&self, text: &str) -> LossyConversion {
+        self.converter().convert_str_lossy(text)
+    }
+}
+
+impl Display for Font {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&value_name(self))
+    }
+}
+
+impl From<Font> for StyleSpec {
+    fn from(font: Font) -> StyleSpec {
+        let (letter_type, letter_style) = font.parts();
+        StyleSpec::new(letter_type, letter_style)
+    }
+}
+
+impl FromStr for Font {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_value_enum(s, "font")
+    }
+}
+
+#[cfg(test)]
+mod test_font {
+    use super::*;
+
+    #[test]
+    pub fn test_font() {
+        assert_eq!(Font::value_variants(), Font::ALL);
+        for font in Font::ALL {
+            let (letter_type, letter_style) = font.parts();
+            assert_eq!(Some(*font), Font::from_parts(letter_type, letter_style));
+        }
+        assert_eq!(
+            None,
+            Font::from_parts(StyledLetter::Fraktur, LetterStyle::Italic)
+        );
+        assert_eq!("𝕒𝟙!", Font::DoubleStruck.convert_str("a1!").unwrap());
+        assert_eq!("𝕒 1️⃣", Font::DoubleStruck.convert_str("a 1️⃣").unwrap());
+        for font in Font::ALL {
+            let text = "Aa1 αΩ!";
+            let expected: Result<String, _> = text.chars().map(|ch| font.convert(ch)).collect();
+            assert_eq!(expected.ok(), font.convert_str(text).ok(), "{}", font);
+        }
+        assert_eq!(Some(Font::DoubleStruck), Font::of('𝟙'));
+        assert_eq!(Some(Font::ScriptBold), Font::of('𝓪'));
+        assert_eq!(None, Font::of('!'));
+        assert!(Font::Script.convert('1').is_err());
+        #[allow(deprecated)]
+        let styled = crate::convert('a', &StyledLetter::Script, &LetterStyle::Bold);
+        assert_eq!(Font::ScriptBold.convert('a').ok(), styled.ok());
+        assert_eq!(
+            "sans-serif-bold-italic",
+            Font::SansSerifBoldItalic.to_string()
+        );
+        assert_eq!(Ok(Font::FrakturBold), "Fraktur-Bold".parse());
+        assert_eq!(
+            StyleSpec::new(StyledLetter::MonoSpace, LetterStyle::Normal),
+            StyleSpec::from(Font::Monospace)
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::io;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::render::escape_html;
+
+// How converted text is written out.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    // the characters themselves
+    #[default]
+    Text,
+    // non-ASCII characters as escapes, see `EscapeFlavor`
+    Escapes,
+    // non-ASCII characters as numeric character references, see
+    // `html_entities`
+    #[value(name = "html-entities")]
+    #[serde(rename = "html-entities")]
+    HtmlEntities,
+    // percent-encoded UTF-8, see `percent_encode`
+    Url,
+}
+
+// The escape syntax of a programming language's string literals.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+pub enum EscapeFlavor {
+    // \u{1D400}, also ES2015 JavaScript and Swift
+    #[default]
+    #[value(alias = "js")]
+    Rust,
+    // \uD835\uDC00, UTF-16 code units as in JSON, Java and C#
+    #[value(alias = "json", alias = "java")]
+    Utf16,
+    // \U0001D400 for characters outside the BMP, \u00E9 for the others
+    Python,
+}
+
+fn escape_char(ch: char, flavor: &EscapeFlavor, res: &mut String) {
+    match *flavor {
+        EscapeFlavor::Rust => res.push_str(&format!("\\u{{{:X}}}", ch as u32)),
+        EscapeFlavor::Utf16 => {
+            for unit in ch.encode_utf16(&mut [0; 2]) {
+                res.push_str(&format!("\\u{:04X}", unit));
+            }
+        }
+        EscapeFlavor::Python if (ch as u32) > 0xFFFF => {
+            res.push_str(&format!("\\U{:08X}", ch as u32))
+        }
+        EscapeFlavor::Python => res.push_str(&format!("\\u{:04X}", ch as u32)),
+    }
+}
+
+// Write text as the body of a string literal: characters outside printable
+// ASCII are escaped, and so are backslashes and double quotes.
+pub fn escape_unicode(text: &str, flavor: &EscapeFlavor) -> String {
+    let mut res = String::with_capacity(text.len() * 3);
+    for ch in text.chars() {
+        match ch {
+            '\\' | '"' => {
+                res.push('\\');
+                res.push(ch);
+            }
+            ' '..='~' => res.push(ch),
+            _ => escape_char(ch, flavor, &mut res),
+        }
+    }
+    res
+}
+
+fn hex_value(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+// the code unit or code point of an escape at the start of `text`, and the
+// length of the escape
+fn parse_escape(text: &str) -> Option<(u32, usize)> {
+    let rest = text.strip_prefix('\\')?;
+    if let Some(braced) = rest.strip_prefix("u{") {
+        let end = braced.find('}')?;
+        return (end <= 6)
+            .then(|| hex_value(&braced[..end]))
+            .flatten()
+            .map(|value| (value, 3 + end + 1));
+    }
+    let (digits, len) = match rest.chars().next()? {
+        'u' => (4, 2 + 4),
+        'U' => (8, 2 + 8),
+        _ => return None,
+    };
+    hex_value(rest.get(1..1 + digits)?).map(|value| (value, len))
+}
+
+// Decode the escapes `escape_unicode` writes in any flavor: \u{1D400},
+// \uXXXX (with UTF-16 surrogate pairs), \UXXXXXXXX, \\ and \". Anything that
+// does not decode to a character is kept as it is.
+pub fn unescape_unicode(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut offset = 0;
+    while offset < text.len() {
+        let rest = &text[offset..];
+        if let Some(quoted) = rest
+            .strip_prefix('\\')
+            .and_then(|after| after.chars().next())
+            .filter(|ch| *ch == '\\' || *ch == '"')
+        {
+            res.push(quoted);
+            offset += 2;
+            continue;
+        }
+        let decoded = parse_escape(rest).and_then(|(value, len)| {
+            if !(0xD800..0xDC00).contains(&value) {
+                return char::from_u32(value).map(|ch| (ch, len));
+            }
+            // a high surrogate is only a character with the low one after it
+            let (low, low_len) = parse_escape(&rest[len..])?;
+            char::decode_utf16([value as u16, low as u16])
+                .next()?
+                .ok()
+                .map(|ch| (ch, len + low_len))
+        });
+        match decoded {
+            Some((ch, len)) => {
+                res.push(ch);
+                offset += len;
+            }
+            None => {
+                let ch = rest.chars().next().unwrap();
+                res.push(ch);
+                offset += ch.len_utf8();
+            }
+        }
+    }
+    res
+}
+
+// Escape text for HTML and XML with numeric character references for every
+// character outside ASCII, &#x1D400; for 𝐀, so it survives systems that
+// mangle multi-byte or astral characters.
+pub fn html_entities(text: &str) -> String {
+    let mut res = String::with_capacity(text.len() * 4);
+    for ch in escape_html(text).chars() {
+        if ch.is_ascii() {
+            res.push(ch);
+        } else {
+            res.push_str(&format!("&#x{:X};", ch as u32));
+        }
+    }
+    res
+}
+
+// Percent-encode the UTF-8 bytes of text for a URL query or path: every
+// byte but the unreserved ASCII letters, digits and - . _ ~ of RFC 3986.
+pub fn percent_encode(text: &str) -> String {
+    let mut res = String::with_capacity(text.len() * 3);
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                res.push(byte as char)
+            }
+            _ => res.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    res
+}
+
+pub fn format_output(text: &str, format: &OutputFormat, flavor: &EscapeFlavor) -> String {
+    match *format {
+        OutputFormat::Text => text.to_string(),
+        OutputFormat::Escapes => escape_unicode(text, flavor),
+        OutputFormat::HtmlEntities => html_entities(text),
+        OutputFormat::Url => percent_encode(text),
+    }
+}
+
+// The bytes output is written as.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+pub enum OutputEncoding {
+    #[default]
+    Utf8,
+    // UTF-8 after a byte order mark, without which some Windows tools read
+    // the text in the ANSI code page
+    #[value(name = "utf8-bom")]
+    #[serde(rename = "utf8-bom")]
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl OutputEncoding {
+    // the byte order mark to start a file or stream with; UTF-16 always has
+    // one, as Windows tools expect
+    pub fn bom(&self) -> &'static [u8] {
+        match *self {
+            OutputEncoding::Utf8 => b"",
+            OutputEncoding::Utf8Bom => b"\xEF\xBB\xBF",
+            OutputEncoding::Utf16Le => b"\xFF\xFE",
+            OutputEncoding::Utf16Be => b"\xFE\xFF",
+        }
+    }
+
+    // the text in this encoding, without the byte order mark
+    pub fn encode(&self, text: &str) -> Vec<u8> {
+        match *self {
+            OutputEncoding::Utf8 | OutputEncoding::Utf8Bom => text.as_bytes().to_vec(),
+            OutputEncoding::Utf16Le => text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+            OutputEncoding::Utf16Be => text.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+        }
+    }
+
+    // Text written in any of the encodings, and the encoding it is in, told
+    // by its byte order mark. Without one the bytes have to be UTF-8.
+    pub fn decode(bytes: &[u8]) -> io::Result<(String, OutputEncoding)> {
+        let encoding = [
+            OutputEncoding::Utf8Bom,
+            OutputEncoding::Utf16Le,
+            OutputEncoding::Utf16Be,
+        ]
+        .into_iter()
+        .find(|encoding| bytes.starts_with(encoding.bom()))
+        .unwrap_or(OutputEncoding::Utf8);
+        let body = &bytes[encoding.bom().len()..];
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+        let text = match encoding {
+            OutputEncoding::Utf8 | OutputEncoding::Utf8Bom => {
+                String::from_utf8(body.to_vec()).map_err(|e| invalid(e.to_string()))?
+            }
+            OutputEncoding::Utf16Le | OutputEncoding::Utf16Be => {
+                if !body.len().is_multiple_of(2) {
+                    return Err(invalid("UTF-16 text of an odd number of bytes".to_string()));
+                }
+                let units = body.chunks_exact(2).map(|pair| match encoding {
+                    OutputEncoding::Utf16Le => u16::from_le_bytes([pair[0], pair[1]]),
+                    _ => u16::from_be_bytes([pair[0], pair[1]]),
+                });
+                char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .map_err(|e| invalid(e.to_string()))?
+            }
+        };
+        Ok((text, encoding))
+    }
+}
+
+#[cfg(test)]
+mod test_format {
+    use super::*;
+
+    #[test]
+    pub fn test_escape_unicode() {
+        assert_eq!(
+            "\\u{1D400}b \\u{E9}\\\"",
+            escape_unicode("𝐀b é\"", &EscapeFlavor::Rust)
+        );
+        assert_eq!(
+            "\\uD835\\uDC00\\u00E9\\u000A",
+            escape_unicode("𝐀é\n", &EscapeFlavor::Utf16)
+        );
+        assert_eq!(
+            "\\U0001D400\\u00E9\\\\",
+            escape_unicode("𝐀é\\", &EscapeFlavor::Python)
+        );
+        assert_eq!(
+            "𝐀",
+            format_output("𝐀", &OutputFormat::Text, &EscapeFlavor::Rust)
+        );
+    }
+
+    #[test]
+    pub fn test_html_entities() {
+        assert_eq!("&#x1D400;b &amp; &#xE9;", html_entities("𝐀b & é"));
+        assert_eq!(
+            "&lt;&#x210E;&gt;",
+            format_output("<ℎ>", &OutputFormat::HtmlEntities, &EscapeFlavor::Rust)
+        );
+    }
+
+    #[test]
+    pub fn test_percent_encode() {
+        assert_eq!(
+            "%F0%9D%90%80b%20c-d.e_f~%26",
+            percent_encode("𝐀b c-d.e_f~&")
+        );
+        assert_eq!(
+            "%E2%84%8E",
+            format_output("ℎ", &OutputFormat::Url, &EscapeFlavor::Rust)
+        );
+    }
+
+    #[test]
+    pub fn test_unescape_unicode() {
+        for flavor in EscapeFlavor::value_variants() {
+            let text = "𝐀b \"é\"\n\\";
+            assert_eq!(text, unescape_unicode(&escape_unicode(text, flavor)));
+        }
+        assert_eq!("𝐀", unescape_unicode("\\uD835\\uDC00"));
+        // unpaired surrogates, bad digits and unclosed braces are kept
+        for text in ["\\uD835x", "\\u12G4", "\\u{1D400", "\\u{110000}", "\\x"] {
+            assert_eq!(text, unescape_unicode(text));
+        }
+    }
+
+    #[test]
+    pub fn test_output_encoding() {
+        assert_eq!(
+            b"\xF0\x9D\x90\x80".to_vec(),
+            OutputEncoding::Utf8Bom.encode("𝐀")
+        );
+        assert_eq!(
+            vec![0x35, 0xD8, 0x00, 0xDC, 0x0A, 0x00],
+            OutputEncoding::Utf16Le.encode("𝐀\n")
+        );
+        assert_eq!(
+            vec![0xD8, 0x35, 0xDC, 0x00],
+            OutputEncoding::Utf16Be.encode("𝐀")
+        );
+        assert!(OutputEncoding::Utf8.bom().is_empty());
+        assert_eq!(b"\xFF\xFE", OutputEncoding::Utf16Le.bom());
+    }
+
+    #[test]
+    pub fn test_decode() {
+        for encoding in OutputEncoding::value_variants() {
+            let bytes = [encoding.bom(), &encoding.encode("𝐀b\n")].concat();
+            assert_eq!(
+                ("𝐀b\n".to_string(), *encoding),
+                OutputEncoding::decode(&bytes).unwrap()
+            );
+        }
+        assert!(OutputEncoding::decode(b"\xFF\xFEa").is_err());
+        assert!(OutputEncoding::decode(b"\xFF").is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use clap::ValueEnum;
+
+// Playful rewrites applied to the text before it is styled. Unlike the
+// styles they change what the text says, so they can't be undone and read
+// badly with screen readers.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum Transform {
+    Leet,
+}
+
+impl Transform {
+    pub fn apply(&self, text: &str) -> String {
+        match *self {
+            Transform::Leet => Leet::default().apply(text),
+        }
+    }
+}
+
+// Leetspeak: letters replaced by lookalike digits. Substitutions are one
+// character for one so positions in the text stay the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leet {
+    // (lowercase letter, replacement)
+    substitutions: Vec<(char, char)>,
+}
+
+impl Default for Leet {
+    // a -> 4, e -> 3, i -> 1, o -> 0, s -> 5, t -> 7
+    fn default() -> Self {
+        Self::new()
+            .substitute('a', '4')
+            .substitute('e', '3')
+            .substitute('i', '1')
+            .substitute('o', '0')
+            .substitute('s', '5')
+            .substitute('t', '7')
+    }
+}
+
+impl Leet {
+    // no substitutions at all
+    pub fn new() -> Self {
+        Self {
+            substitutions: Vec::new(),
+        }
+    }
+
+    // Replace `from`, in either case, with `to`, overriding an earlier
+    // substitution of the same letter.
+    pub fn substitute(mut self, from: char, to: char) -> Self {
+        let from = from.to_ascii_lowercase();
+        self.substitutions.retain(|(letter, _)| *letter != from);
+        self.substitutions.push((from, to));
+        self
+    }
+
+    pub fn without(mut self, from: char) -> Self {
+        let from = from.to_ascii_lowercase();
+        self.substitutions.retain(|(letter, _)| *letter != from);
+        self
+    }
+
+    pub fn apply_char(&self, ch: char) -> char {
+        let lowercase = ch.to_ascii_lowercase();
+        self.substitutions
+            .iter()
+            .find(|(letter, _)| *letter == lowercase)
+            .map_or(ch, |(_, to)| *to)
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        text.chars().map(|ch| self.apply_char(ch)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test_fun {
+    use super::*;
+
+    #[test]
+    pub fn test_leet() {
+        assert_eq!("L337 5P34K", Transform::Leet.apply("LEET SPEAK"));
+        assert_eq!("h3ll0, w0rld", Leet::default().apply("hello, world"));
+        let leet = Leet::default().without('o').substitute('L', '|');
+        assert_eq!("h3||o", leet.apply("hello"));
+        assert_eq!("hello", Leet::new().apply("hello"));
+    }
+}
@@ -0,0 +1,65 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{convert_pair, Converter, LetterStyle, StyledLetter};
+
+const PLAIN_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789 .,!?'-";
+
+// Text as this crate outputs it: plain text converted to a font and style
+// that has letters. Characters the style has no form for stay plain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledString {
+    pub plain: String,
+    pub letter_type: StyledLetter,
+    pub letter_style: LetterStyle,
+    pub styled: String,
+}
+
+impl<'a> Arbitrary<'a> for StyledString {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let combinations: Vec<(&StyledLetter, &LetterStyle)> = StyledLetter::ALL
+            .iter()
+            .flat_map(|letter_type| {
+                LetterStyle::ALL
+                    .iter()
+                    .map(move |letter_style| (letter_type, letter_style))
+            })
+            .filter(|(letter_type, letter_style)| {
+                convert_pair('A', letter_type, letter_style).is_ok()
+            })
+            .collect();
+        let (letter_type, letter_style) = *u.choose(&combinations)?;
+        let chars: Vec<char> = PLAIN_CHARS.chars().collect();
+        let len = u.arbitrary_len::<u8>()?;
+        let mut plain = String::with_capacity(len);
+        for _ in 0..len {
+            plain.push(*u.choose(&chars)?);
+        }
+        let styled = Converter::new(*letter_type, *letter_style)
+            .convert_str_lossy(&plain)
+            .output;
+        Ok(Self {
+            plain,
+            letter_type: *letter_type,
+            letter_style: *letter_style,
+            styled,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_fuzz {
+    use super::*;
+    use crate::to_plain;
+
+    #[test]
+    pub fn test_styled_string() {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..16 {
+            let styled = StyledString::arbitrary(&mut u).unwrap();
+            assert_eq!(styled.plain, to_plain(&styled.styled));
+        }
+        let mut u = Unstructured::new(&data[7..]);
+        assert!(LetterStyle::arbitrary(&mut u).is_ok());
+    }
+}
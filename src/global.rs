@@ -0,0 +1,60 @@
+use std::sync::OnceLock;
+
+use crate::{Converter, GreekVariantPolicy, LetterStyle, LetterTypeError, StyledLetter};
+
+static DEFAULT: OnceLock<Converter> = OnceLock::new();
+
+// Set the converter `convert_default` and `convert_default_str` use, for
+// programs that only ever convert to one style. It can be set once, before
+// the first conversion; otherwise the converter is handed back.
+pub fn set_default(
+    letter_type: StyledLetter,
+    letter_style: LetterStyle,
+    policy: GreekVariantPolicy,
+) -> Result<(), Converter> {
+    set_default_converter(Converter::new(letter_type, letter_style).with_greek_variants(policy))
+}
+
+// `set_default` with a fully configured converter, e.g. with fallbacks
+pub fn set_default_converter(converter: Converter) -> Result<(), Converter> {
+    DEFAULT.set(converter)
+}
+
+// The converter set by `set_default`. Without one, this is serif normal,
+// i.e. conversions de-style.
+pub fn default_converter() -> &'static Converter {
+    DEFAULT.get_or_init(|| Converter::new(StyledLetter::default(), LetterStyle::default()))
+}
+
+pub fn convert_default(ch: char) -> Result<char, LetterTypeError> {
+    default_converter().convert_char(ch)
+}
+
+pub fn convert_default_str(text: &str) -> Result<String, LetterTypeError> {
+    default_converter().convert_str(text)
+}
+
+#[cfg(test)]
+mod test_global {
+    use super::*;
+
+    // the default is process wide, so everything is checked in one test
+    #[test]
+    pub fn test_default_converter() {
+        set_default(
+            StyledLetter::Fraktur,
+            LetterStyle::Bold,
+            GreekVariantPolicy::default(),
+        )
+        .unwrap();
+        assert_eq!('𝖆', convert_default('a').unwrap());
+        assert_eq!("𝕳𝖎 👍🏽!", convert_default_str("Hi 👍🏽!").unwrap());
+        assert!(set_default(
+            StyledLetter::Script,
+            LetterStyle::Normal,
+            GreekVariantPolicy::default()
+        )
+        .is_err());
+        assert_eq!(StyledLetter::Fraktur, *default_converter().letter_type());
+    }
+}
@@ -0,0 +1,160 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    sanitize::is_combining, CharacterInfo, CharacterType, Decoration, LetterStyle, StyledLetter,
+};
+
+// A user-perceived character: a letter with its accents and decorations, an
+// emoji sequence, a flag, ...
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledGrapheme<'a> {
+    // byte range in the iterated string
+    pub range: Range<usize>,
+    pub text: &'a str,
+    pub base: char,
+    pub character_type: CharacterType,
+    // font and style of the base char, None when it has none
+    pub style: Option<(StyledLetter, LetterStyle)>,
+    // the combining marks in the cluster, decorations included
+    pub marks: Vec<char>,
+    pub decorations: Vec<Decoration>,
+}
+
+impl<'a> StyledGrapheme<'a> {
+    fn new(offset: usize, text: &'a str) -> Self {
+        let base = text.chars().next().unwrap_or_default();
+        let (character_type, style) = match CharacterInfo::get_letter_info(base) {
+            CharacterInfo::Letter(info) => (
+                info.character_type,
+                Some((info.letter_type, info.letter_style)),
+            ),
+            CharacterInfo::Other(_) => (CharacterType::Other, None),
+        };
+        let marks: Vec<char> = text
+            .chars()
+            .skip(1)
+            .filter(|ch| is_combining(*ch))
+            .collect();
+        let mut decorations = Vec::new();
+        for decoration in marks.iter().filter_map(|mark| Decoration::from_mark(*mark)) {
+            if !decorations.contains(&decoration) {
+                decorations.push(decoration);
+            }
+        }
+        Self {
+            range: offset..offset + text.len(),
+            text,
+            base,
+            character_type,
+            style,
+            marks,
+            decorations,
+        }
+    }
+}
+
+// An emoji made of several code points: ZWJ sequences, keycaps, flags,
+// skin tone modifiers, emoji presentation selectors and tag sequences. Its
+// parts mustn't be converted or separated on their own, "1️⃣" is a digit with
+// a keycap, not a digit to style.
+pub fn is_emoji_sequence(grapheme: &str) -> bool {
+    let mut chars = grapheme.chars();
+    let first = match chars.next() {
+        Some(first) => first,
+        None => return false,
+    };
+    if matches!(first as u32, 0x1F1E6..=0x1F1FF) {
+        return true;
+    }
+    // letters have no emoji form, a selector pasted after one is noise
+    let selector_is_emoji = !has_no_emoji_form(first);
+    chars.any(|ch| match ch as u32 {
+        0xFE0F => selector_is_emoji,
+        0x200D | 0x20E3 | 0x1F3FB..=0x1F3FF | 0xE0020..=0xE007F => true,
+        _ => false,
+    })
+}
+
+// U+FE00..U+FE0F, VS15 (U+FE0E) and VS16 (U+FE0F) choose text or emoji
+// presentation
+pub(crate) fn is_variation_selector(ch: char) -> bool {
+    matches!(ch as u32, 0xFE00..=0xFE0F)
+}
+
+// Letters and Greek letters, plain or styled, and styled digits. Plain
+// digits, # and * do have emoji (keycap) forms.
+fn has_no_emoji_form(ch: char) -> bool {
+    match CharacterInfo::get_letter_info(ch) {
+        CharacterInfo::Letter(info) => match info.character_type {
+            CharacterType::Digit => !ch.is_ascii_digit(),
+            _ => true,
+        },
+        CharacterInfo::Other(_) => false,
+    }
+}
+
+// Iterate over the extended grapheme clusters of `text` with the style of
+// each, so decorated letters and emoji sequences are never split apart.
+pub fn styled_graphemes(text: &str) -> impl Iterator<Item = StyledGrapheme<'_>> {
+    text.grapheme_indices(true)
+        .map(|(offset, grapheme)| StyledGrapheme::new(offset, grapheme))
+}
+
+#[cfg(test)]
+mod test_grapheme {
+    use super::*;
+
+    #[test]
+    pub fn test_is_emoji_sequence() {
+        for emoji in [
+            "👩‍💻",
+            "1️⃣",
+            "🇯🇵",
+            "👍🏽",
+            "❤️",
+            "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+        ] {
+            assert!(is_emoji_sequence(emoji), "{}", emoji);
+        }
+        // selectors pasted after letters
+        for grapheme in [
+            "a",
+            "😀",
+            "e\u{301}",
+            "a\u{332}",
+            "",
+            "𝐀\u{FE0F}",
+            "β\u{FE0E}",
+        ] {
+            assert!(!is_emoji_sequence(grapheme), "{}", grapheme);
+        }
+    }
+
+    #[test]
+    pub fn test_styled_graphemes() {
+        let graphemes: Vec<StyledGrapheme> = styled_graphemes("𝐚\u{301}\u{332}👩‍💻1").collect();
+        assert_eq!(3, graphemes.len());
+        assert_eq!(
+            StyledGrapheme {
+                range: 0..8,
+                text: "𝐚\u{301}\u{332}",
+                base: '𝐚',
+                character_type: CharacterType::Letter,
+                style: Some((StyledLetter::Serif, LetterStyle::Bold)),
+                marks: vec!['\u{301}', '\u{332}'],
+                decorations: vec![Decoration::Underline],
+            },
+            graphemes[0]
+        );
+        assert_eq!("👩‍💻", graphemes[1].text);
+        assert_eq!(None, graphemes[1].style);
+        assert!(graphemes[1].marks.is_empty());
+        assert_eq!(CharacterType::Digit, graphemes[2].character_type);
+        assert_eq!(
+            Some((StyledLetter::Serif, LetterStyle::Normal)),
+            graphemes[2].style
+        );
+    }
+}
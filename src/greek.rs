@@ -0,0 +1,276 @@
+use clap::ValueEnum;
+#[cfg(feature = "normalization")]
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+#[cfg(feature = "normalization")]
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(feature = "normalization")]
+use crate::Converter;
+
+// What to do with the accents and breathings of accented/polytonic Greek
+// once the base letter has been split off.
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+#[value(rename_all = "lower")]
+pub enum GreekMarks {
+    // keep them as combining marks after the converted base letter
+    Keep,
+    // drop them
+    Strip,
+}
+
+// Which codepoint to emit for the Greek letters that have a symbol variant
+// in the math alphabets. Conventions differ by field, e.g. ϕ vs φ for angles.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Default)]
+#[value(rename_all = "lower")]
+pub enum GreekVariantChoice {
+    // keep whichever form the input used
+    #[default]
+    Preserve,
+    // always the letter form: ε θ κ π ρ φ (and Θ)
+    Letter,
+    // always the symbol form: ϵ ϑ ϰ ϖ ϱ ϕ (and ϴ)
+    Symbol,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GreekVariant {
+    Epsilon,
+    Theta,
+    Kappa,
+    Pi,
+    Rho,
+    Phi,
+}
+
+impl GreekVariant {
+    const ALL: [GreekVariant; 6] = [
+        GreekVariant::Epsilon,
+        GreekVariant::Theta,
+        GreekVariant::Kappa,
+        GreekVariant::Pi,
+        GreekVariant::Rho,
+        GreekVariant::Phi,
+    ];
+
+    // (letter offset, symbol offset) in the lowercase Greek runs
+    fn lowercase_offsets(&self) -> (usize, usize) {
+        match *self {
+            GreekVariant::Epsilon => (4, 26), // ε ϵ
+            GreekVariant::Theta => (7, 27),   // θ ϑ
+            GreekVariant::Kappa => (9, 28),   // κ ϰ
+            GreekVariant::Pi => (15, 31),     // π ϖ
+            GreekVariant::Rho => (16, 30),    // ρ ϱ
+            GreekVariant::Phi => (21, 29),    // φ ϕ
+        }
+    }
+}
+
+// Θ Θ-symbol (ϴ) in the uppercase Greek runs
+const UPPERCASE_THETA_OFFSETS: (usize, usize) = (7, 17);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GreekVariantPolicy {
+    choices: [GreekVariantChoice; 6],
+}
+
+impl GreekVariantPolicy {
+    pub fn new(choice: GreekVariantChoice) -> Self {
+        Self {
+            choices: [choice; 6],
+        }
+    }
+
+    pub fn with(mut self, variant: GreekVariant, choice: GreekVariantChoice) -> Self {
+        self.choices[variant as usize] = choice;
+        self
+    }
+
+    pub fn choice(&self, variant: GreekVariant) -> GreekVariantChoice {
+        self.choices[variant as usize]
+    }
+
+    // Map the offset of a classified Greek letter to the offset of the form
+    // selected by the policy.
+    pub(crate) fn resolve_offset(&self, offset: usize, uppercase: bool) -> usize {
+        let pick = |choice: GreekVariantChoice, (letter, symbol): (usize, usize)| {
+            if offset != letter && offset != symbol {
+                return None;
+            }
+            match choice {
+                GreekVariantChoice::Preserve => Some(offset),
+                GreekVariantChoice::Letter => Some(letter),
+                GreekVariantChoice::Symbol => Some(symbol),
+            }
+        };
+        if uppercase {
+            return pick(self.choice(GreekVariant::Theta), UPPERCASE_THETA_OFFSETS)
+                .unwrap_or(offset);
+        }
+        GreekVariant::ALL
+            .iter()
+            .find_map(|variant| pick(self.choice(*variant), variant.lowercase_offsets()))
+            .unwrap_or(offset)
+    }
+}
+
+// Replace σ at the end of a word with ς. Styled text often uses the plain
+// sigma everywhere, so this restores proper spelling after de-styling. A lone
+// σ (a symbol rather than a word) is left alone.
+pub fn restore_final_sigma(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut res = String::with_capacity(text.len());
+    for (idx, ch) in chars.iter().enumerate() {
+        let after_letter = idx > 0 && chars[idx - 1].is_alphabetic();
+        let at_word_end = chars.get(idx + 1).is_none_or(|next| !next.is_alphabetic());
+        if *ch == 'σ' && after_letter && at_word_end {
+            res.push('ς');
+        } else {
+            res.push(*ch);
+        }
+    }
+    res
+}
+
+// Greek and Coptic, Greek Extended
+#[cfg(feature = "normalization")]
+fn is_greek(ch: char) -> bool {
+    matches!(ch as u32, 0x0370..=0x03FF | 0x1F00..=0x1FFF)
+}
+
+// Split accented Greek letters (ά, ΐ, ῆ, ᾳ …) into their base letter and
+// combining marks, so the base letter can be classified and styled and the
+// marks pass through and re-attach to it. Non-Greek text is left untouched.
+#[cfg(feature = "normalization")]
+pub fn decompose_greek(text: &str, marks: &GreekMarks) -> String {
+    decompose_greek_if(text, marks, |_| true)
+}
+
+// `decompose_greek` before converting with `converter`: letters it has no
+// glyph for stay precomposed. The conversion drops such letters, and their
+// marks would end up on whatever comes before them.
+#[cfg(feature = "normalization")]
+pub fn decompose_greek_for(text: &str, marks: &GreekMarks, converter: &Converter) -> String {
+    decompose_greek_if(text, marks, |base| converter.convert_char(base).is_ok())
+}
+
+#[cfg(feature = "normalization")]
+fn decompose_greek_if<F>(text: &str, marks: &GreekMarks, converts: F) -> String
+where
+    F: Fn(char) -> bool,
+{
+    let mut res = String::with_capacity(text.len());
+    for grapheme in text.graphemes(true) {
+        if !grapheme.starts_with(is_greek) {
+            res.push_str(grapheme);
+            continue;
+        }
+        // input that is already decomposed is in the same cluster
+        let decomposed: Vec<char> = grapheme.nfd().collect();
+        if !converts(decomposed[0]) {
+            res.push_str(grapheme);
+            continue;
+        }
+        let keep = |ch: &char| *marks == GreekMarks::Keep || !is_combining_mark(*ch);
+        res.extend(decomposed.into_iter().filter(keep));
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_greek {
+    use super::*;
+    use crate::{convert_pair, convert_with_greek_variants, LetterStyle, StyledLetter};
+
+    fn bold_with(text: &str, policy: &GreekVariantPolicy) -> String {
+        text.chars()
+            .map(|ch| {
+                convert_with_greek_variants(ch, &StyledLetter::Serif, &LetterStyle::Bold, policy)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn test_greek_variant_policy() {
+        let preserve = GreekVariantPolicy::default();
+        assert_eq!("𝛆𝛜𝛉𝛝𝚯", bold_with("εϵθϑΘ", &preserve));
+        let symbol = GreekVariantPolicy::new(GreekVariantChoice::Symbol);
+        assert_eq!("𝛜𝛜𝛝𝛝𝛞𝛟𝛠𝛡𝚹", bold_with("εϵθϑκφρπΘ", &symbol));
+        let letter = GreekVariantPolicy::new(GreekVariantChoice::Letter);
+        assert_eq!("𝛆𝛉𝛋𝛗𝛒𝛑𝚯", bold_with("ϵϑϰϕϱϖΘ", &letter));
+        let phi_only =
+            GreekVariantPolicy::default().with(GreekVariant::Phi, GreekVariantChoice::Symbol);
+        assert_eq!("𝛆𝛟", bold_with("εφ", &phi_only));
+        // the symbol forms also convert back to plain text
+        let plain = convert_pair('𝛜', &StyledLetter::Serif, &LetterStyle::Normal);
+        assert_eq!('ϵ', plain.unwrap());
+    }
+
+    #[test]
+    pub fn test_final_sigma() {
+        // the math alphabets have their own final sigma letters
+        assert_eq!("𝛌𝛐𝛄𝛐𝛓", bold_with("λογος", &GreekVariantPolicy::default()));
+        let plain = convert_pair('𝞁', &StyledLetter::Serif, &LetterStyle::Normal);
+        assert_eq!('ς', plain.unwrap());
+        assert_eq!("λογος λογος", restore_final_sigma("λογοσ λογος"));
+        assert_eq!("σοφος, σ.", restore_final_sigma("σοφοσ, σ."));
+        assert_eq!("ΛΟΓΟΣ", restore_final_sigma("ΛΟΓΟΣ"));
+    }
+
+    #[cfg(feature = "normalization")]
+    fn bold(text: &str) -> String {
+        text.chars()
+            .map(|ch| convert_pair(ch, &StyledLetter::Serif, &LetterStyle::Bold).unwrap())
+            .collect()
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    pub fn test_decompose_greek() {
+        assert_eq!("α\u{301}λφα", decompose_greek("άλφα", &GreekMarks::Keep));
+        assert_eq!("αλφα", decompose_greek("άλφα", &GreekMarks::Strip));
+        assert_eq!("ι", decompose_greek("ΐ", &GreekMarks::Strip));
+        assert_eq!("η", decompose_greek("η\u{342}", &GreekMarks::Strip));
+        assert_eq!("café", decompose_greek("café", &GreekMarks::Strip));
+        assert_eq!("ι\u{308}", decompose_greek("ι\u{308}", &GreekMarks::Keep));
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    pub fn test_decompose_greek_for() {
+        // monospace and script have no Greek, accented letters stay whole
+        for letter_type in [StyledLetter::MonoSpace, StyledLetter::Script] {
+            let converter = Converter::new(letter_type, LetterStyle::Normal);
+            assert_eq!(
+                "xά b",
+                decompose_greek_for("xά b", &GreekMarks::Keep, &converter)
+            );
+            assert_eq!(
+                "ά",
+                decompose_greek_for("ά", &GreekMarks::Strip, &converter)
+            );
+        }
+        let converter = Converter::new(StyledLetter::Serif, LetterStyle::Bold);
+        assert_eq!(
+            "xα\u{301} b",
+            decompose_greek_for("xά b", &GreekMarks::Keep, &converter)
+        );
+        assert_eq!(
+            "xα b",
+            decompose_greek_for("xά b", &GreekMarks::Strip, &converter)
+        );
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    pub fn test_convert_accented_greek() {
+        assert_eq!(
+            "𝛂\u{301}𝛌𝛗𝛂",
+            bold(&decompose_greek("άλφα", &GreekMarks::Keep))
+        );
+        assert_eq!("𝛈", bold(&decompose_greek("ῆ", &GreekMarks::Strip)));
+        assert_eq!("𝚨", bold(&decompose_greek("Ά", &GreekMarks::Strip)));
+        // accented letters are not part of the plain Greek runs
+        assert_eq!("ϊ", bold("ϊ"));
+    }
+}
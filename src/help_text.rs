@@ -0,0 +1,427 @@
+use crate::Lang;
+
+// The command line help in a language other than English, the language it is
+// written in. Options are keyed by their argument id, subcommands by name
+// and their options by "name.id"; those without a translation keep their
+// English help.
+#[derive(Debug)]
+pub struct HelpText {
+    pub about: &'static str,
+    pub exit_status: &'static str,
+    pub help_flag: &'static str,
+    pub version_flag: &'static str,
+    pub args: &'static [(&'static str, &'static str)],
+    pub commands: &'static [(&'static str, &'static str)],
+    pub command_args: &'static [(&'static str, &'static str)],
+}
+
+impl HelpText {
+    pub fn arg(&self, id: &str) -> Option<&'static str> {
+        lookup(self.args, id)
+    }
+
+    pub fn command(&self, name: &str) -> Option<&'static str> {
+        lookup(self.commands, name)
+    }
+
+    pub fn command_arg(&self, name: &str, id: &str) -> Option<&'static str> {
+        self.command_args
+            .iter()
+            .find(|(key, _)| key.split_once('.') == Some((name, id)))
+            .map(|(_, text)| *text)
+    }
+}
+
+fn lookup(entries: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    entries
+        .iter()
+        .find(|(id, _)| *id == key)
+        .map(|(_, text)| *text)
+}
+
+impl Lang {
+    // None for English
+    pub fn help_text(&self) -> Option<&'static HelpText> {
+        match *self {
+            Lang::En => None,
+            Lang::Es => Some(&ES),
+            Lang::Fr => Some(&FR),
+            Lang::Pt => Some(&PT),
+        }
+    }
+}
+
+static ES: HelpText = HelpText {
+    about: "Una herramienta de línea de comandos para convertir texto ASCII a otros formatos Unicode",
+    exit_status: "Estado de salida: 0 si se convirtió todo, 1 si algunos caracteres no se pudieron convertir (se descartan, y los números quedan sin estilo con --numbers), 2 si no se pudo leer la entrada o escribir la salida, 3 si --check encontró cambios",
+    help_flag: "Mostrar la ayuda",
+    version_flag: "Mostrar la versión",
+    args: &[
+        ("text", "texto a convertir; sin él, el texto se lee de --input, si no de stdin cuando no es una terminal. --stdin y --paste obligan a usar stdin o el portapapeles, y más de una fuente es un error"),
+        ("input", "leer el texto a convertir de un archivo"),
+        ("stdin", "leer el texto a convertir de stdin, aunque sea una terminal"),
+        ("paste", "leer el texto a convertir del portapapeles"),
+        ("capabilities", "mostrar las fuentes, estilos y combinaciones admitidos como JSON y salir"),
+        ("spec", "tipo y estilo de letra en un solo valor, p. ej. serif.bold o script"),
+        ("style_specs", "tipo y estilo de letra en un solo valor, p. ej. serif-bold-italic; repítalo para convertir con varios"),
+        ("table", "mostrar las conversiones de todas las especificaciones --as en una tabla alineada"),
+        ("check", "no mostrar la salida, informar lo que cambiaría; sale con 3 si algo cambiaría"),
+        ("diff", "mostrar la entrada junto a la salida (side) o las palabras cambiadas en línea (words) en lugar de la salida"),
+        ("copy_osc52", "copiar la salida al portapapeles a través de la terminal con una secuencia de escape OSC 52 en lugar de mostrarla"),
+        ("letter_type", "convertir las letras ASCII en letras con estilo"),
+        ("random", "convertir cada carácter con un tipo y un estilo de letra al azar"),
+        ("exclude_types", "tipos de letra que --random nunca elige"),
+        ("exclude_styles", "estilos de letra que --random nunca elige"),
+        ("seed", "semilla para --random, para obtener siempre la misma salida"),
+        ("spacing", "insertar un separador entre los caracteres convertidos: none, thin, space o un solo carácter"),
+        ("transform", "reescribir el texto antes de convertirlo, p. ej. leet para leetspeak (a -> 4, e -> 3, ...)"),
+        ("sentence_case", "pasar el texto a minúsculas y poner en mayúscula el comienzo de cada oración antes de convertir"),
+        ("normalize", "normalizar la entrada antes de clasificar sus caracteres"),
+        ("strip_diacritics", "quitar acentos y otros diacríticos (é -> e) antes de convertir; con pérdida"),
+        ("greek_marks", "conservar o quitar los acentos de las letras griegas acentuadas al convertirlas"),
+        ("greek_variants", "emitir la forma de letra o de símbolo de ε/ϵ, θ/ϑ, κ/ϰ, π/ϖ, ρ/ϱ, φ/ϕ"),
+        ("digit_fallback", "estilos a probar, en orden, para los dígitos que no tienen glifos en el estilo pedido"),
+        ("fallback_chain", "especificaciones a probar, en orden, para los caracteres sin glifo en la fuente y el estilo pedidos; sin ninguna, la misma fuente recta, normal y negrita, luego serif"),
+        ("digit_style", "escribir los dígitos y las expresiones numéricas (+ - = ( ) n) como superíndices o subíndices, o los dígitos como dígitos de siete segmentos"),
+        ("only_styled", "convertir solo los caracteres que ya tienen estilo, sin tocar el texto sin formato, p. ej. --only-styled --to sans-bold"),
+        ("numbers", "convertir los números enteros como unidades; un número cuyos dígitos no pueden tener estilo queda sin estilo"),
+        ("unicode_minus", "con --numbers, escribir un guion inicial como U+2212 MINUS SIGN"),
+        ("figure_space", "con --numbers, escribir los espacios entre grupos de dígitos como U+2007 FIGURE SPACE"),
+        ("unescape", "decodificar los escapes \\u{1D400}, \\uXXXX y \\UXXXXXXXX de la entrada antes de convertir"),
+        ("strip_invisible", "quitar de la entrada los caracteres de ancho cero, los controles bidi y los caracteres de etiqueta"),
+        ("reverse", "invertir la salida por grupos de grafemas; las coincidencias de --protect conservan su orden de lectura"),
+        ("protect_entities", "dejar sin convertir las URL, @menciones, #hashtags y :códigos_de_emoji:"),
+        ("protect", "dejar tal cual las coincidencias de una expresión regular, p. ej. identificadores de tickets o direcciones de correo; repetible"),
+        ("escapes", "dejar sin convertir un carácter tras una barra invertida y los tramos escritos como {{raw:...}}"),
+        ("markdown", "tratar la entrada como Markdown: el código, los destinos de enlaces y los enlaces automáticos quedan sin convertir"),
+        ("style_code", "con --markdown, dar al código estilo monospace en lugar de dejarlo sin convertir"),
+        ("presentation", "mostrar los keycaps y los caracteres encerrados (decorados con un círculo o un cuadrado) como texto o como emoji"),
+        ("downgrade", "reemplazar los caracteres fuera de ascii, wgl4 o bmp por su aproximación más cercana dentro, p. ej. 𝒽 por ℎ para bmp"),
+        ("enumerate", "numerar las líneas de la salida con números encerrados: fullstop (⒈), parenthesized (⑴) o circled (①)"),
+        ("limit", "truncar la salida para que quepa en el límite de una plataforma: twitter, discord o un número de caracteres"),
+        ("final_sigma", "con --ascii, escribir σ al final de una palabra griega como ς"),
+        ("ascii", "convertir las letras con estilo en letras ASCII"),
+        ("aggressive", "con --ascii, convertir también a ASCII los parecidos como la а cirílica y las letras de ancho completo y en círculo"),
+        ("quiet", "no mostrar los errores de conversión, solo cuántos hubo; dos veces para no mostrar nada"),
+        ("errors", "escribir los errores y avisos en stderr como texto o como un objeto JSON por línea con un código estable"),
+        ("lang", "idioma de la ayuda y de los mensajes de error; por defecto el de LC_ALL, LC_MESSAGES o LANG"),
+        ("format", "escribir la salida como texto, como escapes para literales de cadena en código fuente, con referencias numéricas de caracteres HTML o codificada en porcentaje para URL"),
+        ("escape_flavor", "con --format escapes, el lenguaje cuyos escapes escribir: rust (o js), utf16 (o json) o python"),
+        ("output_encoding", "codificar la salida como utf8, utf8-bom, utf16le o utf16be; UTF-16 empieza con una marca de orden de bytes"),
+        ("warn_a11y", "informar en stderr qué parte de la salida pueden no leer los lectores de pantalla"),
+        ("warn_compat", "informar en stderr los caracteres de la salida fuera del BMP o del conjunto WGL4 de las fuentes antiguas"),
+        ("warn_coverage", "informar en stderr los caracteres de la salida que las fuentes de las plataformas comunes probablemente no pueden mostrar; experimental, los datos de cobertura de las fuentes están escritos a mano y sin verificar"),
+    ],
+    commands: &[
+        ("a11y", "quitar el estilo para dejar texto sin formato que los lectores de pantalla puedan leer"),
+        ("detect-spoof", "informar las líneas con texto con estilo o con parecidos de escrituras mezcladas; sale con 1 si encuentra alguno"),
+        ("detect-invisible", "informar los caracteres de ancho cero, los controles bidi y los caracteres de etiqueta; sale con 1 si encuentra alguno"),
+        ("grep", "mostrar las líneas que tienen letras o dígitos con estilo; sale con 1 si ninguna los tiene"),
+        ("sub", "reemplazar un texto en todas partes, con estilo o no, por un reemplazo en la misma fuente y estilo"),
+        ("sanitize", "convertir las letras con estilo en texto sin formato y quitar decoraciones, marcas combinadas en exceso y caracteres de etiqueta"),
+        ("doctor", "comprobar las tablas de conversión y si esta terminal puede mostrar texto con estilo; sale con 1 si hay problemas"),
+        ("export", "mostrar la correspondencia de cada carácter en cada fuente y estilo"),
+        ("samples", "mostrar un pangrama, o el texto dado, en cada fuente y estilo"),
+        ("convert", "convertir muchos archivos a la vez, escribiendo los resultados en un directorio de salida; sale con 1 si algunos caracteres no se pudieron convertir, 2 si no se pudo leer o escribir un archivo"),
+        ("filter", "pasar stdin a stdout como filtro clean o smudge de git"),
+        ("count", "contar los caracteres, bytes, unidades de código UTF-16, grupos de grafemas y columnas del texto antes y después de convertirlo"),
+        ("watch-clipboard", "vigilar el portapapeles y reemplazar el texto copiado en él por el texto con estilo, hasta que se interrumpa"),
+        ("braille", "escribir letras y dígitos como patrones Braille de grado 1"),
+        ("inspect", "mostrar el punto de código, el nombre Unicode, la categoría y el bloque, la fuente, el estilo, la clase y la forma sin estilo de cada carácter"),
+        ("parse", "mostrar los tramos de fuente, estilo y decoraciones del texto como JSON"),
+    ],
+    command_args: &[
+        ("a11y.report", "mostrar un informe de los caracteres con estilo en lugar del texto sin formato"),
+        ("detect-spoof.files", "archivos a revisar; lee stdin si no se da ninguno"),
+        ("detect-invisible.files", "archivos a revisar; lee stdin si no se da ninguno"),
+        ("grep.files", "archivos donde buscar; lee stdin si no se da ninguno"),
+        ("grep.invert", "mostrar en cambio las líneas sin letras ni dígitos con estilo"),
+        ("grep.font", "contar como con estilo solo las letras y dígitos de estas fuentes"),
+        ("sub.pattern", "texto a reemplazar, buscado en la entrada sin estilo"),
+        ("sub.replacement", "texto por el que reemplazarlo"),
+        ("sub.files", "archivos a editar; lee stdin si no se da ninguno"),
+        ("sanitize.text", "texto a sanear; lee stdin si no se da"),
+        ("sanitize.report", "mostrar en stderr lo que se quitó"),
+        ("samples.text", "texto a mostrar en lugar del pangrama"),
+        ("convert.glob", "archivos a convertir, p. ej. 'posts/**/*.txt'"),
+        ("convert.out_dir", "directorio donde escribir; se conservan las rutas bajo el directorio donde empieza el glob"),
+        ("convert.in_place", "editar los archivos en su lugar, guardando los originales con el sufijo dado, p. ej. -i.bak"),
+        ("convert.spec", "tipo y estilo de letra, p. ej. serif-bold"),
+        ("convert.ascii", "convertir en cambio las letras con estilo en letras ASCII"),
+        ("convert.jobs", "número de archivos a convertir en paralelo"),
+        ("convert.output_encoding", "codificar los archivos escritos como utf8, utf8-bom, utf16le o utf16be"),
+        ("filter.clean", "quitar el estilo al texto, para la copia guardada en el repositorio"),
+        ("filter.smudge", "dar estilo al texto, para la copia del directorio de trabajo"),
+        ("filter.spec", "tipo y estilo de letra con que dar estilo, p. ej. serif-bold"),
+        ("count.text", "texto a contar; lee stdin si no se da"),
+        ("count.spec", "tipo y estilo de letra con que convertir, p. ej. serif-bold"),
+        ("count.ascii", "quitar el estilo al texto en lugar de convertirlo"),
+        ("watch-clipboard.spec", "tipo y estilo de letra con que convertir, p. ej. script-bold"),
+        ("watch-clipboard.ascii", "quitar el estilo al texto copiado en lugar de darle estilo"),
+        ("watch-clipboard.interval", "cada cuánto mirar el portapapeles, en milisegundos"),
+        ("braille.text", "texto a escribir; lee stdin si no se da"),
+        ("braille.decode", "leer en cambio patrones Braille y devolverlos a texto"),
+        ("inspect.text", "texto a inspeccionar; lee stdin si no se da"),
+        ("parse.text", "texto a analizar; lee stdin si no se da"),
+    ],
+};
+
+static FR: HelpText = HelpText {
+    about: "Un outil en ligne de commande pour convertir du texte ASCII vers d'autres formes Unicode",
+    exit_status: "Code de sortie : 0 si tout a été converti, 1 si certains caractères n'ont pas pu être convertis (ils sont supprimés, et les nombres restent sans style avec --numbers), 2 si l'entrée n'a pas pu être lue ou la sortie écrite, 3 si --check a trouvé des changements",
+    help_flag: "Afficher l'aide",
+    version_flag: "Afficher la version",
+    args: &[
+        ("text", "texte à convertir ; sans lui, le texte est lu depuis --input, sinon depuis stdin quand ce n'est pas un terminal. --stdin et --paste imposent stdin ou le presse-papiers, et plus d'une source est une erreur"),
+        ("input", "lire le texte à convertir depuis un fichier"),
+        ("stdin", "lire le texte à convertir depuis stdin, même si c'est un terminal"),
+        ("paste", "lire le texte à convertir depuis le presse-papiers"),
+        ("capabilities", "afficher les polices, styles et combinaisons pris en charge en JSON et quitter"),
+        ("spec", "type et style de lettre en une seule valeur, p. ex. serif.bold ou script"),
+        ("style_specs", "type et style de lettre en une seule valeur, p. ex. serif-bold-italic ; à répéter pour convertir avec plusieurs"),
+        ("table", "afficher les conversions de toutes les spécifications --as dans un tableau aligné"),
+        ("check", "ne pas afficher la sortie, signaler ce qui changerait ; sort avec 3 si quelque chose changerait"),
+        ("diff", "afficher l'entrée à côté de la sortie (side) ou les mots modifiés en ligne (words) au lieu de la sortie"),
+        ("copy_osc52", "copier la sortie dans le presse-papiers via le terminal avec une séquence d'échappement OSC 52 au lieu de l'afficher"),
+        ("letter_type", "transformer les lettres ASCII en lettres stylisées"),
+        ("random", "convertir chaque caractère avec un type et un style de lettre au hasard"),
+        ("exclude_types", "types de lettre que --random ne choisit jamais"),
+        ("exclude_styles", "styles de lettre que --random ne choisit jamais"),
+        ("seed", "graine pour --random, pour obtenir toujours la même sortie"),
+        ("spacing", "insérer un séparateur entre les caractères convertis : none, thin, space ou un seul caractère"),
+        ("transform", "réécrire le texte avant de le convertir, p. ex. leet pour le leetspeak (a -> 4, e -> 3, ...)"),
+        ("sentence_case", "mettre le texte en minuscules et une majuscule au début de chaque phrase avant de convertir"),
+        ("normalize", "normaliser l'entrée avant de classer ses caractères"),
+        ("strip_diacritics", "supprimer les accents et autres diacritiques (é -> e) avant de convertir ; avec perte"),
+        ("greek_marks", "garder ou supprimer les accents des lettres grecques accentuées en les convertissant"),
+        ("greek_variants", "produire la forme lettre ou la forme symbole de ε/ϵ, θ/ϑ, κ/ϰ, π/ϖ, ρ/ϱ, φ/ϕ"),
+        ("digit_fallback", "styles à essayer, dans l'ordre, pour les chiffres sans glyphe dans le style demandé"),
+        ("fallback_chain", "spécifications à essayer, dans l'ordre, pour les caractères sans glyphe dans la police et le style demandés ; sans aucune, la même police droite, normale et grasse, puis serif"),
+        ("digit_style", "écrire les chiffres et les expressions numériques (+ - = ( ) n) en exposant ou en indice, ou les chiffres en chiffres à sept segments"),
+        ("only_styled", "ne convertir que les caractères déjà stylisés, sans toucher au texte brut, p. ex. --only-styled --to sans-bold"),
+        ("numbers", "convertir les nombres entiers comme des unités ; un nombre dont les chiffres ne peuvent pas être stylisés reste brut"),
+        ("unicode_minus", "avec --numbers, écrire un trait d'union initial en U+2212 MINUS SIGN"),
+        ("figure_space", "avec --numbers, écrire les espaces entre groupes de chiffres en U+2007 FIGURE SPACE"),
+        ("unescape", "décoder les échappements \\u{1D400}, \\uXXXX et \\UXXXXXXXX de l'entrée avant de convertir"),
+        ("strip_invisible", "supprimer de l'entrée les caractères de largeur nulle, les contrôles bidi et les caractères d'étiquette"),
+        ("reverse", "inverser la sortie par groupes de graphèmes ; les résultats de --protect gardent leur sens de lecture"),
+        ("protect_entities", "laisser non convertis les URL, @mentions, #hashtags et :codes_emoji:"),
+        ("protect", "laisser tels quels les résultats d'une expression régulière, p. ex. des identifiants de tickets ou des adresses e-mail ; répétable"),
+        ("escapes", "laisser non converti un caractère après une barre oblique inverse et les passages écrits {{raw:...}}"),
+        ("markdown", "traiter l'entrée comme du Markdown : le code, les destinations des liens et les liens automatiques restent non convertis"),
+        ("style_code", "avec --markdown, styliser le code en monospace au lieu de le laisser non converti"),
+        ("presentation", "afficher les keycaps et les caractères encadrés (décorés d'un cercle ou d'un carré) en texte ou en emoji"),
+        ("downgrade", "remplacer les caractères hors de ascii, wgl4 ou bmp par leur plus proche approximation à l'intérieur, p. ex. 𝒽 par ℎ pour bmp"),
+        ("enumerate", "numéroter les lignes de la sortie avec des nombres encadrés : fullstop (⒈), parenthesized (⑴) ou circled (①)"),
+        ("limit", "tronquer la sortie pour respecter la limite d'une plateforme : twitter, discord ou un nombre de caractères"),
+        ("final_sigma", "avec --ascii, écrire σ en fin de mot grec en ς"),
+        ("ascii", "transformer les lettres stylisées en lettres ASCII"),
+        ("aggressive", "avec --ascii, ramener aussi en ASCII les sosies comme le а cyrillique et les lettres pleine chasse ou cerclées"),
+        ("quiet", "ne pas afficher les erreurs de conversion, seulement leur nombre ; deux fois pour ne rien afficher"),
+        ("errors", "écrire les erreurs et avertissements sur stderr en texte ou en un objet JSON par ligne avec un code stable"),
+        ("lang", "langue de l'aide et des messages d'erreur ; par défaut celle de LC_ALL, LC_MESSAGES ou LANG"),
+        ("format", "écrire la sortie en texte, en échappements pour les littéraux de chaîne du code source, en références numériques de caractères HTML ou encodée en pourcentage pour les URL"),
+        ("escape_flavor", "avec --format escapes, le langage dont écrire les échappements : rust (ou js), utf16 (ou json) ou python"),
+        ("output_encoding", "encoder la sortie en utf8, utf8-bom, utf16le ou utf16be ; l'UTF-16 commence par une marque d'ordre des octets"),
+        ("warn_a11y", "signaler sur stderr la part de la sortie que les lecteurs d'écran risquent de ne pas lire"),
+        ("warn_compat", "signaler sur stderr les caractères de la sortie hors du BMP ou du jeu WGL4 des anciennes polices"),
+        ("warn_coverage", "signaler sur stderr les caractères de la sortie que les polices des plateformes courantes ne savent probablement pas afficher ; expérimental, les données de couverture des polices sont écrites à la main et non vérifiées"),
+    ],
+    commands: &[
+        ("a11y", "retirer le style pour revenir à un texte brut que les lecteurs d'écran peuvent lire"),
+        ("detect-spoof", "signaler les lignes contenant du texte stylisé ou des sosies d'écritures mélangées ; sort avec 1 s'il y en a"),
+        ("detect-invisible", "signaler les caractères de largeur nulle, les contrôles bidi et les caractères d'étiquette ; sort avec 1 s'il y en a"),
+        ("grep", "afficher les lignes qui contiennent des lettres ou des chiffres stylisés ; sort avec 1 si aucune n'en contient"),
+        ("sub", "remplacer un texte partout où il apparaît, stylisé ou non, par un remplacement dans la même police et le même style"),
+        ("sanitize", "ramener les lettres stylisées au texte brut et supprimer les décorations, l'abus de marques combinantes et les caractères d'étiquette"),
+        ("doctor", "vérifier les tables de conversion et si ce terminal peut afficher du texte stylisé ; sort avec 1 en cas de problème"),
+        ("export", "afficher la correspondance de chaque caractère dans chaque police et style"),
+        ("samples", "afficher un pangramme, ou le texte donné, dans chaque police et style"),
+        ("convert", "convertir de nombreux fichiers à la fois, en écrivant les résultats dans un répertoire de sortie ; sort avec 1 si certains caractères n'ont pas pu être convertis, 2 si un fichier n'a pas pu être lu ou écrit"),
+        ("filter", "transmettre stdin vers stdout comme filtre clean ou smudge de git"),
+        ("count", "compter les caractères, octets, unités de code UTF-16, groupes de graphèmes et colonnes du texte avant et après sa conversion"),
+        ("watch-clipboard", "surveiller le presse-papiers et remplacer le texte qui y est copié par le texte stylisé, jusqu'à interruption"),
+        ("braille", "écrire les lettres et les chiffres en motifs Braille de niveau 1"),
+        ("inspect", "afficher le point de code, le nom Unicode, la catégorie et le bloc, la police, le style, la classe et la forme brute de chaque caractère"),
+        ("parse", "afficher les passages de police, de style et de décorations du texte en JSON"),
+    ],
+    command_args: &[
+        ("a11y.report", "afficher un rapport des caractères stylisés au lieu du texte brut"),
+        ("detect-spoof.files", "fichiers à analyser ; lit stdin si aucun n'est donné"),
+        ("detect-invisible.files", "fichiers à analyser ; lit stdin si aucun n'est donné"),
+        ("grep.files", "fichiers où chercher ; lit stdin si aucun n'est donné"),
+        ("grep.invert", "afficher plutôt les lignes sans lettres ni chiffres stylisés"),
+        ("grep.font", "ne compter comme stylisés que les lettres et chiffres de ces polices"),
+        ("sub.pattern", "texte à remplacer, recherché dans l'entrée sans style"),
+        ("sub.replacement", "texte par lequel le remplacer"),
+        ("sub.files", "fichiers à modifier ; lit stdin si aucun n'est donné"),
+        ("sanitize.text", "texte à nettoyer ; lit stdin s'il n'est pas donné"),
+        ("sanitize.report", "afficher sur stderr ce qui a été supprimé"),
+        ("samples.text", "texte à afficher à la place du pangramme"),
+        ("convert.glob", "fichiers à convertir, p. ex. 'posts/**/*.txt'"),
+        ("convert.out_dir", "répertoire où écrire ; les chemins sous le répertoire où commence le glob sont conservés"),
+        ("convert.in_place", "modifier les fichiers sur place, en gardant les originaux avec le suffixe donné, p. ex. -i.bak"),
+        ("convert.spec", "type et style de lettre, p. ex. serif-bold"),
+        ("convert.ascii", "transformer plutôt les lettres stylisées en lettres ASCII"),
+        ("convert.jobs", "nombre de fichiers à convertir en parallèle"),
+        ("convert.output_encoding", "encoder les fichiers écrits en utf8, utf8-bom, utf16le ou utf16be"),
+        ("filter.clean", "retirer le style du texte, pour la copie stockée dans le dépôt"),
+        ("filter.smudge", "styliser le texte, pour la copie dans l'arbre de travail"),
+        ("filter.spec", "type et style de lettre pour styliser, p. ex. serif-bold"),
+        ("count.text", "texte à compter ; lit stdin s'il n'est pas donné"),
+        ("count.spec", "type et style de lettre pour convertir, p. ex. serif-bold"),
+        ("count.ascii", "retirer le style du texte au lieu de le convertir"),
+        ("watch-clipboard.spec", "type et style de lettre pour convertir, p. ex. script-bold"),
+        ("watch-clipboard.ascii", "retirer le style du texte copié au lieu de le styliser"),
+        ("watch-clipboard.interval", "à quelle fréquence regarder le presse-papiers, en millisecondes"),
+        ("braille.text", "texte à écrire ; lit stdin s'il n'est pas donné"),
+        ("braille.decode", "relire plutôt des motifs Braille en texte"),
+        ("inspect.text", "texte à inspecter ; lit stdin s'il n'est pas donné"),
+        ("parse.text", "texte à analyser ; lit stdin s'il n'est pas donné"),
+    ],
+};
+
+static PT: HelpText = HelpText {
+    about: "Uma ferramenta de linha de comando para converter texto ASCII em outros formatos Unicode",
+    exit_status: "Status de saída: 0 quando tudo foi convertido, 1 quando alguns caracteres não puderam ser convertidos (eles são descartados, e os números ficam sem estilo com --numbers), 2 quando não foi possível ler a entrada ou escrever a saída, 3 quando --check encontrou mudanças",
+    help_flag: "Mostrar a ajuda",
+    version_flag: "Mostrar a versão",
+    args: &[
+        ("text", "texto a converter; sem ele, o texto é lido de --input, senão de stdin quando não é um terminal. --stdin e --paste forçam stdin ou a área de transferência, e mais de uma fonte é um erro"),
+        ("input", "ler o texto a converter de um arquivo"),
+        ("stdin", "ler o texto a converter de stdin, mesmo quando é um terminal"),
+        ("paste", "ler o texto a converter da área de transferência"),
+        ("capabilities", "mostrar as fontes, estilos e combinações suportados como JSON e sair"),
+        ("spec", "tipo e estilo de letra em um só valor, p. ex. serif.bold ou script"),
+        ("style_specs", "tipo e estilo de letra em um só valor, p. ex. serif-bold-italic; repita para converter com vários"),
+        ("table", "mostrar as conversões de todas as especificações --as em uma tabela alinhada"),
+        ("check", "não mostrar a saída, relatar o que mudaria; sai com 3 se algo mudaria"),
+        ("diff", "mostrar a entrada ao lado da saída (side) ou as palavras alteradas em linha (words) em vez da saída"),
+        ("copy_osc52", "copiar a saída para a área de transferência pelo terminal com uma sequência de escape OSC 52 em vez de mostrá-la"),
+        ("letter_type", "transformar letras ASCII em letras estilizadas"),
+        ("random", "converter cada caractere com um tipo e um estilo de letra aleatórios"),
+        ("exclude_types", "tipos de letra que --random nunca escolhe"),
+        ("exclude_styles", "estilos de letra que --random nunca escolhe"),
+        ("seed", "semente para --random, para obter sempre a mesma saída"),
+        ("spacing", "inserir um separador entre os caracteres convertidos: none, thin, space ou um único caractere"),
+        ("transform", "reescrever o texto antes de convertê-lo, p. ex. leet para leetspeak (a -> 4, e -> 3, ...)"),
+        ("sentence_case", "passar o texto para minúsculas e pôr em maiúscula o início de cada frase antes de converter"),
+        ("normalize", "normalizar a entrada antes de classificar seus caracteres"),
+        ("strip_diacritics", "remover acentos e outros diacríticos (é -> e) antes de converter; com perda"),
+        ("greek_marks", "manter ou remover os acentos das letras gregas acentuadas ao convertê-las"),
+        ("greek_variants", "emitir a forma de letra ou de símbolo de ε/ϵ, θ/ϑ, κ/ϰ, π/ϖ, ρ/ϱ, φ/ϕ"),
+        ("digit_fallback", "estilos a tentar, em ordem, para dígitos sem glifos no estilo pedido"),
+        ("fallback_chain", "especificações a tentar, em ordem, para caracteres sem glifo na fonte e no estilo pedidos; sem nenhuma, a mesma fonte reta, normal e negrito, depois serif"),
+        ("digit_style", "escrever dígitos e expressões numéricas (+ - = ( ) n) como sobrescritos ou subscritos, ou dígitos como dígitos de sete segmentos"),
+        ("only_styled", "converter só os caracteres que já têm estilo, sem mexer no texto simples, p. ex. --only-styled --to sans-bold"),
+        ("numbers", "converter números inteiros como unidades; um número cujos dígitos não podem ter estilo fica sem estilo"),
+        ("unicode_minus", "com --numbers, escrever um hífen inicial como U+2212 MINUS SIGN"),
+        ("figure_space", "com --numbers, escrever os espaços entre grupos de dígitos como U+2007 FIGURE SPACE"),
+        ("unescape", "decodificar os escapes \\u{1D400}, \\uXXXX e \\UXXXXXXXX da entrada antes de converter"),
+        ("strip_invisible", "remover da entrada os caracteres de largura zero, os controles bidi e os caracteres de tag"),
+        ("reverse", "inverter a saída por grupos de grafemas; as correspondências de --protect mantêm a ordem de leitura"),
+        ("protect_entities", "deixar sem converter URLs, @menções, #hashtags e :códigos_de_emoji:"),
+        ("protect", "deixar como estão as correspondências de uma expressão regular, p. ex. IDs de tickets ou endereços de e-mail; repetível"),
+        ("escapes", "deixar sem converter um caractere após uma barra invertida e trechos escritos como {{raw:...}}"),
+        ("markdown", "tratar a entrada como Markdown: código, destinos de links e links automáticos ficam sem converter"),
+        ("style_code", "com --markdown, dar ao código o estilo monospace em vez de deixá-lo sem converter"),
+        ("presentation", "mostrar keycaps e caracteres delimitados (decorados com um círculo ou um quadrado) como texto ou como emoji"),
+        ("downgrade", "substituir os caracteres fora de ascii, wgl4 ou bmp pela aproximação mais próxima dentro dele, p. ex. 𝒽 por ℎ para bmp"),
+        ("enumerate", "numerar as linhas da saída com números delimitados: fullstop (⒈), parenthesized (⑴) ou circled (①)"),
+        ("limit", "truncar a saída para caber no limite de uma plataforma: twitter, discord ou um número de caracteres"),
+        ("final_sigma", "com --ascii, escrever σ no fim de uma palavra grega como ς"),
+        ("ascii", "transformar letras estilizadas em letras ASCII"),
+        ("aggressive", "com --ascii, converter também para ASCII sósias como o а cirílico e letras de largura total e circuladas"),
+        ("quiet", "não mostrar os erros de conversão, só quantos foram; duas vezes para não mostrar nada"),
+        ("errors", "escrever erros e avisos em stderr como texto ou como um objeto JSON por linha com um código estável"),
+        ("lang", "idioma da ajuda e das mensagens de erro; por padrão o de LC_ALL, LC_MESSAGES ou LANG"),
+        ("format", "escrever a saída como texto, como escapes para literais de string em código-fonte, com referências numéricas de caracteres HTML ou codificada em porcentagem para URLs"),
+        ("escape_flavor", "com --format escapes, a linguagem cujos escapes escrever: rust (ou js), utf16 (ou json) ou python"),
+        ("output_encoding", "codificar a saída como utf8, utf8-bom, utf16le ou utf16be; UTF-16 começa com uma marca de ordem de bytes"),
+        ("warn_a11y", "relatar em stderr quanto da saída os leitores de tela podem não conseguir ler"),
+        ("warn_compat", "relatar em stderr os caracteres da saída fora do BMP ou do conjunto WGL4 das fontes antigas"),
+        ("warn_coverage", "relatar em stderr os caracteres da saída que as fontes das plataformas comuns provavelmente não conseguem mostrar; experimental, os dados de cobertura das fontes foram escritos à mão e não verificados"),
+    ],
+    commands: &[
+        ("a11y", "remover o estilo e voltar a um texto simples que os leitores de tela conseguem ler"),
+        ("detect-spoof", "relatar as linhas com texto estilizado ou sósias de escritas misturadas; sai com 1 se encontrar algum"),
+        ("detect-invisible", "relatar caracteres de largura zero, controles bidi e caracteres de tag; sai com 1 se encontrar algum"),
+        ("grep", "mostrar as linhas que têm letras ou dígitos estilizados; sai com 1 se nenhuma tiver"),
+        ("sub", "substituir um texto em todo lugar onde aparece, estilizado ou não, por uma substituição na mesma fonte e estilo"),
+        ("sanitize", "converter letras estilizadas em texto simples e remover decorações, excesso de marcas combinantes e caracteres de tag"),
+        ("doctor", "verificar as tabelas de conversão e se este terminal consegue mostrar texto estilizado; sai com 1 se houver problemas"),
+        ("export", "mostrar o mapeamento de cada caractere em cada fonte e estilo"),
+        ("samples", "mostrar um pangrama, ou o texto dado, em cada fonte e estilo"),
+        ("convert", "converter muitos arquivos de uma vez, escrevendo os resultados em um diretório de saída; sai com 1 se alguns caracteres não puderam ser convertidos, 2 se um arquivo não pôde ser lido ou escrito"),
+        ("filter", "passar stdin para stdout como filtro clean ou smudge do git"),
+        ("count", "contar os caracteres, bytes, unidades de código UTF-16, grupos de grafemas e colunas do texto antes e depois de convertê-lo"),
+        ("watch-clipboard", "observar a área de transferência e substituir o texto copiado para ela pelo texto estilizado, até ser interrompido"),
+        ("braille", "escrever letras e dígitos como padrões Braille de grau 1"),
+        ("inspect", "mostrar o ponto de código, o nome Unicode, a categoria e o bloco, a fonte, o estilo, a classe e a forma simples de cada caractere"),
+        ("parse", "mostrar os trechos de fonte, estilo e decorações do texto como JSON"),
+    ],
+    command_args: &[
+        ("a11y.report", "mostrar um relatório dos caracteres estilizados em vez do texto simples"),
+        ("detect-spoof.files", "arquivos a verificar; lê stdin quando nenhum é dado"),
+        ("detect-invisible.files", "arquivos a verificar; lê stdin quando nenhum é dado"),
+        ("grep.files", "arquivos onde procurar; lê stdin quando nenhum é dado"),
+        ("grep.invert", "mostrar em vez disso as linhas sem letras ou dígitos estilizados"),
+        ("grep.font", "contar como estilizados só as letras e dígitos destas fontes"),
+        ("sub.pattern", "texto a substituir, procurado na entrada sem estilo"),
+        ("sub.replacement", "texto pelo qual substituí-lo"),
+        ("sub.files", "arquivos a editar; lê stdin quando nenhum é dado"),
+        ("sanitize.text", "texto a higienizar; lê stdin quando não é dado"),
+        ("sanitize.report", "mostrar em stderr o que foi removido"),
+        ("samples.text", "texto a mostrar em vez do pangrama"),
+        ("convert.glob", "arquivos a converter, p. ex. 'posts/**/*.txt'"),
+        ("convert.out_dir", "diretório onde escrever; os caminhos abaixo do diretório onde o glob começa são mantidos"),
+        ("convert.in_place", "editar os arquivos no lugar, guardando os originais com o sufixo dado, p. ex. -i.bak"),
+        ("convert.spec", "tipo e estilo de letra, p. ex. serif-bold"),
+        ("convert.ascii", "transformar em vez disso letras estilizadas em letras ASCII"),
+        ("convert.jobs", "número de arquivos a converter em paralelo"),
+        ("convert.output_encoding", "codificar os arquivos escritos como utf8, utf8-bom, utf16le ou utf16be"),
+        ("filter.clean", "remover o estilo do texto, para a cópia guardada no repositório"),
+        ("filter.smudge", "estilizar o texto, para a cópia na árvore de trabalho"),
+        ("filter.spec", "tipo e estilo de letra para estilizar, p. ex. serif-bold"),
+        ("count.text", "texto a contar; lê stdin quando não é dado"),
+        ("count.spec", "tipo e estilo de letra para converter, p. ex. serif-bold"),
+        ("count.ascii", "remover o estilo do texto em vez de convertê-lo"),
+        ("watch-clipboard.spec", "tipo e estilo de letra para converter, p. ex. script-bold"),
+        ("watch-clipboard.ascii", "remover o estilo do texto copiado em vez de estilizá-lo"),
+        ("watch-clipboard.interval", "com que frequência olhar a área de transferência, em milissegundos"),
+        ("braille.text", "texto a escrever; lê stdin quando não é dado"),
+        ("braille.decode", "ler em vez disso padrões Braille de volta para texto"),
+        ("inspect.text", "texto a inspecionar; lê stdin quando não é dado"),
+        ("parse.text", "texto a analisar; lê stdin quando não é dado"),
+    ],
+};
+
+#[cfg(test)]
+mod test_help_text {
+    use super::*;
+
+    #[test]
+    pub fn test_help_text() {
+        assert!(Lang::En.help_text().is_none());
+        let es = Lang::Es.help_text().unwrap();
+        assert_eq!(
+            Some("leer el texto a convertir de un archivo"),
+            es.arg("input")
+        );
+        assert_eq!(None, es.arg("letter_style"));
+        assert_eq!(
+            Some("número de archivos a convertir en paralelo"),
+            es.command_arg("convert", "jobs")
+        );
+        // every translation covers the same options and subcommands
+        fn keys(entries: &'static [(&'static str, &'static str)]) -> Vec<&'static str> {
+            entries.iter().map(|(key, _)| *key).collect()
+        }
+        for lang in &Lang::ALL[1..] {
+            let help = lang.help_text().unwrap();
+            assert_eq!(keys(es.args), keys(help.args), "{:?}", lang);
+            assert_eq!(keys(es.commands), keys(help.commands), "{:?}", lang);
+            assert_eq!(keys(es.command_args), keys(help.command_args), "{:?}", lang);
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::ops::Range;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    diagnostic::{join_names, ConversionProblem},
+    letter::value_name,
+    CharacterType, Diagnostic, LetterStyle, LetterTypeError, StyledLetter,
+};
+
+// A language of the command line messages. Letter type and style names are
+// kept as they are typed on the command line. English is always there, the
+// `i18n` feature lets the command line speak the others.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Lang {
+    #[default]
+    En,
+    Es,
+    Fr,
+    Pt,
+}
+
+impl Lang {
+    pub const ALL: &[Lang] = &[Lang::En, Lang::Es, Lang::Fr, Lang::Pt];
+
+    // The language of a POSIX locale like "pt_BR.UTF-8" or a language tag
+    // like "es-MX"; None for languages without translations.
+    pub fn from_locale(locale: &str) -> Option<Lang> {
+        let language = locale
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "en" | "c" | "posix" => Some(Lang::En),
+            "es" => Some(Lang::Es),
+            "fr" => Some(Lang::Fr),
+            "pt" => Some(Lang::Pt),
+            _ => None,
+        }
+    }
+
+    // The language of the first set of LC_ALL, LC_MESSAGES and LANG, English
+    // when there is no translation for it.
+    pub fn from_env() -> Lang {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| Lang::from_locale(&locale))
+            .unwrap_or_default()
+    }
+
+    fn error(&self) -> &'static str {
+        match *self {
+            Lang::En | Lang::Es => "error",
+            Lang::Fr => "erreur",
+            Lang::Pt => "erro",
+        }
+    }
+
+    fn help(&self) -> &'static str {
+        match *self {
+            Lang::En => "help",
+            Lang::Es => "ayuda",
+            Lang::Fr => "aide",
+            Lang::Pt => "ajuda",
+        }
+    }
+
+    fn not_converted(&self) -> &'static str {
+        match *self {
+            Lang::En => "not converted",
+            Lang::Es => "sin convertir",
+            Lang::Fr => "non converti",
+            Lang::Pt => "não convertido",
+        }
+    }
+
+    fn class_name(&self, character_type: &CharacterType) -> &'static str {
+        match (self, character_type) {
+            (Lang::En, CharacterType::Letter) => "letters",
+            (Lang::En, CharacterType::Digit) => "digits",
+            (Lang::En, CharacterType::Greek) => "Greek letters",
+            (Lang::En, CharacterType::Other) => "characters",
+            (Lang::Es | Lang::Pt, CharacterType::Letter) => "letras",
+            (Lang::Es | Lang::Pt, CharacterType::Digit) => "dígitos",
+            (Lang::Es, CharacterType::Greek) => "letras griegas",
+            (Lang::Pt, CharacterType::Greek) => "letras gregas",
+            (Lang::Es | Lang::Pt, CharacterType::Other) => "caracteres",
+            (Lang::Fr, CharacterType::Letter) => "lettres",
+            (Lang::Fr, CharacterType::Digit) => "chiffres",
+            (Lang::Fr, CharacterType::Greek) => "lettres grecques",
+            (Lang::Fr, CharacterType::Other) => "caractères",
+        }
+    }
+
+    // "Error: message", the way errors are reported on stderr
+    pub fn error_line(&self, message: &str) -> String {
+        match *self {
+            Lang::En | Lang::Es => format!("Error: {}", message),
+            Lang::Fr => format!("Erreur : {}", message),
+            Lang::Pt => format!("Erro: {}", message),
+        }
+    }
+
+    // "Warning: message"
+    pub fn warning_line(&self, message: &str) -> String {
+        match *self {
+            Lang::En => format!("Warning: {}", message),
+            Lang::Es => format!("Advertencia: {}", message),
+            Lang::Fr => format!("Avertissement : {}", message),
+            Lang::Pt => format!("Aviso: {}", message),
+        }
+    }
+
+    // "Error: 3 conversion errors, rerun without --quiet for details"
+    pub fn conversion_summary(&self, errors: usize) -> String {
+        let plural = errors != 1;
+        let message = match *self {
+            Lang::En => format!(
+                "{} conversion error{}, rerun without --quiet for details",
+                errors,
+                if plural { "s" } else { "" }
+            ),
+            Lang::Es => format!(
+                "{} error{} de conversión, vuelva a ejecutar sin --quiet para ver los detalles",
+                errors,
+                if plural { "es" } else { "" }
+            ),
+            Lang::Fr => format!(
+                "{} erreur{} de conversion, relancez sans --quiet pour les détails",
+                errors,
+                if plural { "s" } else { "" }
+            ),
+            Lang::Pt => format!(
+                "{} erro{} de conversão, execute novamente sem --quiet para ver os detalhes",
+                errors,
+                if plural { "s" } else { "" }
+            ),
+        };
+        self.error_line(&message)
+    }
+
+    pub fn no_text(&self) -> &'static str {
+        match *self {
+            Lang::En => {
+                "no text to convert: give it as an argument, or with --input, --stdin or --paste"
+            }
+            Lang::Es => {
+                "no hay texto que convertir: páselo como argumento, o con --input, --stdin o --paste"
+            }
+            Lang::Fr => {
+                "aucun texte à convertir : donnez-le en argument, ou avec --input, --stdin ou --paste"
+            }
+            Lang::Pt => {
+                "não há texto para converter: passe-o como argumento, ou com --input, --stdin ou --paste"
+            }
+        }
+    }
+
+    pub fn no_clipboard(&self) -> &'static str {
+        match *self {
+            Lang::En => "no clipboard found, pbpaste, wl-paste or xclip is needed",
+            Lang::Es => "no se encontró ningún portapapeles, hace falta pbpaste, wl-paste o xclip",
+            Lang::Fr => "aucun presse-papiers trouvé, pbpaste, wl-paste ou xclip est nécessaire",
+            Lang::Pt => "nenhuma área de transferência encontrada, é preciso pbpaste, wl-paste ou xclip",
+        }
+    }
+
+    pub fn invalid_glob(&self, pattern: &str, error: &str) -> String {
+        match *self {
+            Lang::En => format!("invalid glob '{}': {}", pattern, error),
+            Lang::Es => format!("glob no válido '{}': {}", pattern, error),
+            Lang::Fr => format!("glob invalide '{}' : {}", pattern, error),
+            Lang::Pt => format!("glob inválido '{}': {}", pattern, error),
+        }
+    }
+
+    pub fn conversion_panicked(&self) -> &'static str {
+        match *self {
+            Lang::En => "conversion panicked",
+            Lang::Es => "la conversión falló de forma inesperada",
+            Lang::Fr => "la conversion a échoué de manière inattendue",
+            Lang::Pt => "a conversão falhou inesperadamente",
+        }
+    }
+
+    pub fn nothing_to_pick(&self) -> &'static str {
+        match *self {
+            Lang::En => "--exclude-types and --exclude-styles leave nothing for --random to pick",
+            Lang::Es => "--exclude-types y --exclude-styles no dejan nada que --random pueda elegir",
+            Lang::Fr => "--exclude-types et --exclude-styles ne laissent rien à choisir à --random",
+            Lang::Pt => "--exclude-types e --exclude-styles não deixam nada para --random escolher",
+        }
+    }
+}
+
+impl Diagnostic {
+    // `conversion` with the messages in `lang`
+    pub fn conversion_in(
+        span: Range<usize>,
+        ch: char,
+        error: &LetterTypeError,
+        letter_type: &StyledLetter,
+        letter_style: &LetterStyle,
+        lang: &Lang,
+    ) -> Self {
+        if *lang == Lang::En {
+            return Self::conversion(span, ch, error, letter_type, letter_style);
+        }
+        let label = lang.not_converted().to_string();
+        let letter_type_name = value_name(letter_type);
+        let problem = ConversionProblem::of(ch, error, letter_type);
+        let code = problem.code(error);
+        let (message, help) = match problem {
+            ConversionProblem::MissingStyle(character_type, styles) => {
+                let class = lang.class_name(&character_type);
+                let letter_style_name = value_name(letter_style);
+                let styles = join_names(&styles);
+                match *lang {
+                    Lang::Es => (
+                        format!(
+                            "no hay {} en {} {}",
+                            class, letter_type_name, letter_style_name
+                        ),
+                        format!("hay {} de {} en: {}", class, letter_type_name, styles),
+                    ),
+                    Lang::Fr => (
+                        format!(
+                            "pas de {} en {} {}",
+                            class, letter_type_name, letter_style_name
+                        ),
+                        format!(
+                            "les {} {} existent en : {}",
+                            class, letter_type_name, styles
+                        ),
+                    ),
+                    _ => (
+                        format!(
+                            "não há {} em {} {}",
+                            class, letter_type_name, letter_style_name
+                        ),
+                        format!("há {} de {} em: {}", class, letter_type_name, styles),
+                    ),
+                }
+            }
+            ConversionProblem::MissingClass(character_type, types) => {
+                let class = lang.class_name(&character_type);
+                let types = join_names(&types);
+                match *lang {
+                    Lang::Es => (
+                        format!("no hay {} en {}", class, letter_type_name),
+                        format!("hay {} en: {}", class, types),
+                    ),
+                    Lang::Fr => (
+                        format!("pas de {} en {}", class, letter_type_name),
+                        format!("les {} existent en : {}", class, types),
+                    ),
+                    _ => (
+                        format!("não há {} em {}", class, letter_type_name),
+                        format!("há {} em: {}", class, types),
+                    ),
+                }
+            }
+            ConversionProblem::Other => {
+                return Self::new(span, error.to_string(), label, None).with_code(code)
+            }
+        };
+        Self::new(span, message, label, Some(help)).with_code(code)
+    }
+
+    // `render` with the words for "error" and "help" in `lang`
+    pub fn render_in(&self, source: &str, lang: &Lang) -> String {
+        self.render_with(source, lang.error(), lang.help())
+    }
+}
+
+#[cfg(test)]
+mod test_i18n {
+    use super::*;
+
+    #[test]
+    pub fn test_from_locale() {
+        assert_eq!(Some(Lang::Pt), Lang::from_locale("pt_BR.UTF-8"));
+        assert_eq!(Some(Lang::Es), Lang::from_locale("es-MX"));
+        assert_eq!(Some(Lang::Fr), Lang::from_locale("fr_FR@euro"));
+        assert_eq!(Some(Lang::En), Lang::from_locale("C"));
+        assert_eq!(None, Lang::from_locale("ja_JP.UTF-8"));
+    }
+
+    #[test]
+    pub fn test_localized_diagnostic() {
+        let diagnostic = Diagnostic::conversion_in(
+            3..5,
+            '4',
+            &LetterTypeError::InvalidTypeError,
+            &StyledLetter::Script,
+            &LetterStyle::Normal,
+            &Lang::Es,
+        );
+        assert_eq!(
+            "error: no hay dígitos en script\n  \
+               |\n\
+             1 | Hi 42\n  \
+               |    ^^ sin convertir\n  \
+               = ayuda: hay dígitos en: serif, sansserif, monospace, doublestruck",
+            diagnostic.render_in("Hi 42", &Lang::Es)
+        );
+        let diagnostic = Diagnostic::conversion_in(
+            0..1,
+            'c',
+            &LetterTypeError::InvalidStyleError,
+            &StyledLetter::Fraktur,
+            &LetterStyle::Italic,
+            &Lang::Fr,
+        );
+        assert_eq!("pas de lettres en fraktur italic", diagnostic.message);
+        assert_eq!(
+            Some("les lettres fraktur existent en : normal, bold".to_string()),
+            diagnostic.help
+        );
+        assert_eq!(
+            "Erro: 1 erro de conversão, execute novamente sem --quiet para ver os detalhes",
+            Lang::Pt.conversion_summary(1)
+        );
+        assert_eq!(
+            "Error: 2 conversion errors, rerun without --quiet for details",
+            Lang::En.conversion_summary(2)
+        );
+        assert_eq!("Avertissement : a11y", Lang::Fr.warning_line("a11y"));
+        assert_eq!(
+            Diagnostic::conversion(
+                0..1,
+                'c',
+                &LetterTypeError::InvalidStyleError,
+                &StyledLetter::Fraktur,
+                &LetterStyle::Italic,
+            ),
+            Diagnostic::conversion_in(
+                0..1,
+                'c',
+                &LetterTypeError::InvalidStyleError,
+                &StyledLetter::Fraktur,
+                &LetterStyle::Italic,
+                &Lang::En,
+            )
+        );
+    }
+}
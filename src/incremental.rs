@@ -0,0 +1,104 @@
+use std::ops::Range;
+
+use crate::{Converter, LetterTypeError};
+
+// A change to the input: the chars in `range` replaced by `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, text: &str) -> Self {
+        Self {
+            range,
+            text: text.to_string(),
+        }
+    }
+}
+
+// The output after an edit, with the char range of the previous output that
+// was removed and the char range of the new output that replaced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputEdit {
+    pub output: String,
+    pub removed: Range<usize>,
+    pub inserted: Range<usize>,
+}
+
+impl OutputEdit {
+    // Where a char position of the previous output is in the new one.
+    // Positions inside the removed range move to the end of the insertion.
+    pub fn remap(&self, position: usize) -> usize {
+        if position < self.removed.start {
+            position
+        } else if position < self.removed.end {
+            self.inserted.end
+        } else {
+            position - self.removed.len() + self.inserted.len()
+        }
+    }
+
+    pub fn remap_range(&self, range: &Range<usize>) -> Range<usize> {
+        self.remap(range.start)..self.remap(range.end)
+    }
+}
+
+fn byte_offset(text: &str, position: usize) -> usize {
+    text.char_indices()
+        .nth(position)
+        .map(|(offset, _)| offset)
+        .unwrap_or(text.len())
+}
+
+// Apply an edit of the input to the output it was converted to, converting
+// only the new text. Conversion maps every char to one char, so char
+// positions of the input and the output are the same. Positions past the
+// end of the output are clamped to it.
+pub fn convert_edit(
+    previous_output: &str,
+    edit: &TextEdit,
+    converter: &Converter,
+) -> Result<OutputEdit, LetterTypeError> {
+    let converted = converter.convert_str(&edit.text)?;
+    let start = byte_offset(previous_output, edit.range.start);
+    let end = byte_offset(previous_output, edit.range.end).max(start);
+    let removed_len = previous_output[start..end].chars().count();
+    let removed_start = previous_output[..start].chars().count();
+    let inserted_len = converted.chars().count();
+    let mut output = String::with_capacity(previous_output.len() - (end - start) + converted.len());
+    output.push_str(&previous_output[..start]);
+    output.push_str(&converted);
+    output.push_str(&previous_output[end..]);
+    Ok(OutputEdit {
+        output,
+        removed: removed_start..removed_start + removed_len,
+        inserted: removed_start..removed_start + inserted_len,
+    })
+}
+
+#[cfg(test)]
+mod test_incremental {
+    use super::*;
+    use crate::{LetterStyle, StyledLetter};
+
+    #[test]
+    pub fn test_convert_edit() {
+        let converter = Converter::new(StyledLetter::Serif, LetterStyle::Bold);
+        // "hello world" -> "hello brave world"
+        let edit = convert_edit("𝐡𝐞𝐥𝐥𝐨 𝐰𝐨𝐫𝐥𝐝", &TextEdit::new(6..6, "brave "), &converter).unwrap();
+        assert_eq!("𝐡𝐞𝐥𝐥𝐨 𝐛𝐫𝐚𝐯𝐞 𝐰𝐨𝐫𝐥𝐝", edit.output);
+        assert_eq!((6..6, 6..12), (edit.removed.clone(), edit.inserted.clone()));
+        assert_eq!(2, edit.remap(2));
+        assert_eq!(12..17, edit.remap_range(&(6..11)));
+        // replace "hello" by "hi"
+        let edit = convert_edit(&edit.output, &TextEdit::new(0..5, "hi"), &converter).unwrap();
+        assert_eq!("𝐡𝐢 𝐛𝐫𝐚𝐯𝐞 𝐰𝐨𝐫𝐥𝐝", edit.output);
+        assert_eq!(2, edit.remap(3));
+        assert_eq!(3..8, edit.remap_range(&(6..11)));
+        // deleting past the end
+        let edit = convert_edit("𝐡𝐢", &TextEdit::new(1..9, ""), &converter).unwrap();
+        assert_eq!(("𝐡", 1..2), (edit.output.as_str(), edit.removed));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    destyle, from_digit_style, letter::value_name, CharMetadata, CharacterType, Decomposed,
+};
+
+// What a single character of a text is: its code point, Unicode name and
+// block, and the font, style and class it was detected as. Fields that do
+// not apply are empty, e.g. the font of punctuation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharInspection {
+    pub glyph: char,
+    // "U+1D400"
+    pub codepoint: String,
+    pub name: String,
+    // general category, e.g. "Lu"
+    pub category: String,
+    pub block: String,
+    pub font: String,
+    pub style: String,
+    pub class: String,
+    pub plain: char,
+}
+
+impl CharInspection {
+    pub fn of(ch: char) -> Self {
+        let (font, style, class) = match Decomposed::try_from(ch) {
+            Ok(decomposed) => (
+                value_name(&decomposed.font),
+                value_name(&decomposed.style),
+                class_name(&decomposed.class).to_string(),
+            ),
+            Err(_) => (String::new(), String::new(), String::new()),
+        };
+        // superscript, subscript and segmented digits are not a font but still
+        // have a plain form
+        let plain = match from_digit_style(ch) {
+            Some((digit, _)) => digit,
+            None => destyle(ch),
+        };
+        let metadata = CharMetadata::of(ch);
+        CharInspection {
+            glyph: ch,
+            codepoint: format!("U+{:04X}", ch as u32),
+            name: metadata.name.unwrap_or_default(),
+            category: metadata.category.to_string(),
+            block: unicode_blocks::find_unicode_block(ch)
+                .map(|block| block.name().to_string())
+                .unwrap_or_default(),
+            font,
+            style,
+            class,
+            plain,
+        }
+    }
+
+    // the cells of a row in the `inspect` table
+    pub fn row(&self) -> Vec<String> {
+        vec![
+            self.glyph.to_string(),
+            self.codepoint.clone(),
+            self.name.clone(),
+            self.category.clone(),
+            self.block.clone(),
+            self.font.clone(),
+            self.style.clone(),
+            self.class.clone(),
+            self.plain.to_string(),
+        ]
+    }
+}
+
+pub const INSPECT_HEADERS: [&str; 9] = [
+    "GLYPH",
+    "CODEPOINT",
+    "NAME",
+    "CATEGORY",
+    "BLOCK",
+    "FONT",
+    "STYLE",
+    "CLASS",
+    "PLAIN",
+];
+
+fn class_name(class: &CharacterType) -> &'static str {
+    match class {
+        CharacterType::Letter => "letter",
+        CharacterType::Digit => "digit",
+        CharacterType::Greek => "greek",
+        CharacterType::Other => "other",
+    }
+}
+
+pub fn inspect(text: &str) -> Vec<CharInspection> {
+    text.chars().map(CharInspection::of).collect()
+}
+
+#[cfg(test)]
+mod test_inspect {
+    use super::*;
+
+    #[test]
+    pub fn test_inspect() {
+        let chars = inspect("𝐀ℎ²!");
+        assert_eq!(4, chars.len());
+        assert_eq!("U+1D400", chars[0].codepoint);
+        assert_eq!("MATHEMATICAL BOLD CAPITAL A", chars[0].name);
+        assert_eq!("Lu", chars[0].category);
+        assert_eq!("Mathematical Alphanumeric Symbols", chars[0].block);
+        assert_eq!(
+            ("serif", "bold", "letter", 'A'),
+            (
+                chars[0].font.as_str(),
+                chars[0].style.as_str(),
+                chars[0].class.as_str(),
+                chars[0].plain
+            )
+        );
+        // a corner case outside the math block
+        assert_eq!("Letterlike Symbols", chars[1].block);
+        assert_eq!(("italic", 'h'), (chars[1].style.as_str(), chars[1].plain));
+        assert_eq!('2', chars[2].plain);
+        assert_eq!("", chars[3].font);
+        assert_eq!("EXCLAMATION MARK", chars[3].name);
+        assert_eq!(INSPECT_HEADERS.len(), chars[3].row().len());
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+
+use crate::sanitize::is_tag;
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const ZERO_WIDTH_NON_JOINER: char = '\u{200C}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const BLACK_FLAG: char = '\u{1F3F4}';
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvisibleKind {
+    // U+E0000..U+E007F, used to smuggle hidden text
+    Tag,
+    // zero width space, (non-)joiner, word joiner, BOM
+    ZeroWidth,
+    // bidi marks, embeddings, overrides and isolates, which reorder text
+    Bidi,
+}
+
+impl Display for InvisibleKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            InvisibleKind::Tag => f.write_str("tag"),
+            InvisibleKind::ZeroWidth => f.write_str("zero-width"),
+            InvisibleKind::Bidi => f.write_str("bidi control"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvisibleRun {
+    // 1-based line and column (in chars) of the first character
+    pub line: usize,
+    pub column: usize,
+    // number of characters in the run
+    pub len: usize,
+    pub kind: InvisibleKind,
+}
+
+fn invisible_kind(ch: char) -> Option<InvisibleKind> {
+    match ch {
+        '\u{200B}' | ZERO_WIDTH_NON_JOINER | ZERO_WIDTH_JOINER | '\u{2060}' | '\u{FEFF}' => {
+            Some(InvisibleKind::ZeroWidth)
+        }
+        '\u{061C}'
+        | '\u{200E}'
+        | '\u{200F}'
+        | '\u{202A}'..='\u{202E}'
+        | '\u{2066}'..='\u{2069}' => Some(InvisibleKind::Bidi),
+        _ if is_tag(ch) => Some(InvisibleKind::Tag),
+        _ => None,
+    }
+}
+
+// Rough emoji test, good enough to recognize ZWJ sequences (👩‍💻)
+fn is_pictographic(ch: char) -> bool {
+    matches!(ch as u32, 0x2600..=0x27BF | 0x1F000..=0x1FAFF) || ch == VARIATION_SELECTOR_16
+}
+
+// Some invisible characters are required by the text around them: the ZWJ
+// of emoji sequences, the ZWNJ of Persian and Indic scripts, and the tags of
+// subdivision flags (🏴 + tags, e.g. the flag of England).
+fn is_legitimate(chars: &[char], idx: usize) -> bool {
+    let prev = idx.checked_sub(1).map(|prev| chars[prev]);
+    let next = chars.get(idx + 1).copied();
+    match chars[idx] {
+        ZERO_WIDTH_JOINER => prev.is_some_and(is_pictographic) && next.is_some_and(is_pictographic),
+        ZERO_WIDTH_NON_JOINER => {
+            let is_non_latin_letter = |ch: char| ch.is_alphabetic() && !ch.is_ascii();
+            prev.is_some_and(is_non_latin_letter) && next.is_some_and(is_non_latin_letter)
+        }
+        ch if is_tag(ch) => chars[..idx]
+            .iter()
+            .rev()
+            .find(|ch| !is_tag(**ch))
+            .is_some_and(|ch| *ch == BLACK_FLAG),
+        _ => false,
+    }
+}
+
+// Runs of invisible characters, grouped by kind. Characters that are part
+// of an emoji, flag or word spelling are not reported.
+pub fn find_invisible(text: &str) -> Vec<InvisibleRun> {
+    let mut runs: Vec<InvisibleRun> = Vec::new();
+    for (line_idx, line) in text.lines().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut last_end = None;
+        for idx in 0..chars.len() {
+            let kind = match invisible_kind(chars[idx]) {
+                Some(kind) if !is_legitimate(&chars, idx) => kind,
+                _ => continue,
+            };
+            match runs.last_mut() {
+                Some(run) if last_end == Some(idx) && run.kind == kind => run.len += 1,
+                _ => runs.push(InvisibleRun {
+                    line: line_idx + 1,
+                    column: idx + 1,
+                    len: 1,
+                    kind,
+                }),
+            }
+            last_end = Some(idx + 1);
+        }
+    }
+    runs
+}
+
+// Remove the invisible characters `find_invisible` reports.
+pub fn strip_invisible(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(idx, ch)| invisible_kind(**ch).is_none() || is_legitimate(&chars, *idx))
+        .map(|(_, ch)| *ch)
+        .collect()
+}
+
+#[cfg(test)]
+mod test_invisible {
+    use super::*;
+
+    #[test]
+    pub fn test_find_invisible() {
+        let text = "pa\u{200B}\u{200B}ss\u{202E}word\nhi\u{E0068}\u{E0069}";
+        assert_eq!(
+            vec![
+                InvisibleRun {
+                    line: 1,
+                    column: 3,
+                    len: 2,
+                    kind: InvisibleKind::ZeroWidth,
+                },
+                InvisibleRun {
+                    line: 1,
+                    column: 7,
+                    len: 1,
+                    kind: InvisibleKind::Bidi,
+                },
+                InvisibleRun {
+                    line: 2,
+                    column: 3,
+                    len: 2,
+                    kind: InvisibleKind::Tag,
+                },
+            ],
+            find_invisible(text)
+        );
+        assert_eq!("password\nhi", strip_invisible(text));
+    }
+
+    #[test]
+    pub fn test_keep_legitimate() {
+        let emoji = "👩\u{200D}💻 ❤\u{FE0F}\u{200D}🔥";
+        let flag = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}";
+        // Persian "mi-khaham" with a ZWNJ
+        let persian = "می\u{200C}خواهم";
+        for text in [emoji, flag, persian] {
+            assert!(find_invisible(text).is_empty());
+            assert_eq!(text, strip_invisible(text));
+        }
+        assert_eq!("ab", strip_invisible("a\u{200D}b"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::{fmt::Display, str::FromStr};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::display_width;
+
+const ELLIPSIS: char = '\u{2026}';
+
+// twitter-text counts every code point as 2 except for these ranges, which
+// count as 1. The total may not exceed 280.
+const TWITTER_LIGHT_RANGES: [(u32, u32); 4] = [
+    (0x0000, 0x10FF),
+    (0x2000, 0x200D),
+    (0x2010, 0x201F),
+    (0x2032, 0x2037),
+];
+const TWITTER_MAX_WEIGHT: usize = 280;
+// Discord counts message length like JavaScript's String.length
+const DISCORD_MAX_LENGTH: usize = 2000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Limit {
+    Twitter,
+    Discord,
+    Chars(usize),
+}
+
+impl Limit {
+    pub fn max_len(&self) -> usize {
+        match *self {
+            Limit::Twitter => TWITTER_MAX_WEIGHT,
+            Limit::Discord => DISCORD_MAX_LENGTH,
+            Limit::Chars(n) => n,
+        }
+    }
+
+    // length of the text as counted by the platform
+    pub fn len(&self, text: &str) -> usize {
+        match *self {
+            Limit::Twitter => twitter_weighted_len(text),
+            Limit::Discord => utf16_len(text),
+            Limit::Chars(_) => text.chars().count(),
+        }
+    }
+
+    pub fn fits(&self, text: &str) -> bool {
+        self.len(text) <= self.max_len()
+    }
+}
+
+impl FromStr for Limit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "twitter" | "x" => Ok(Limit::Twitter),
+            "discord" => Ok(Limit::Discord),
+            _ => s.parse::<usize>().map(Limit::Chars).map_err(|_| {
+                format!(
+                    "invalid limit '{}', expected twitter, discord or a number of characters",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Limit::Twitter => f.write_str("twitter"),
+            Limit::Discord => f.write_str("discord"),
+            Limit::Chars(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+// number of UTF-16 code units, i.e. JavaScript's String.length; every styled
+// letter outside the BMP takes a surrogate pair
+pub fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+// The lengths of a text in the units platforms limit it by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextCounts {
+    pub chars: usize,
+    pub bytes: usize,
+    pub utf16: usize,
+    pub graphemes: usize,
+    // terminal columns
+    pub width: usize,
+}
+
+impl TextCounts {
+    pub fn of(text: &str) -> Self {
+        TextCounts {
+            chars: text.chars().count(),
+            bytes: text.len(),
+            utf16: utf16_len(text),
+            graphemes: text.graphemes(true).count(),
+            width: display_width(text),
+        }
+    }
+}
+
+// weighted length as counted by twitter-text (URLs are not special-cased)
+pub fn twitter_weighted_len(text: &str) -> usize {
+    text.chars()
+        .map(|ch| {
+            let code = ch as u32;
+            if TWITTER_LIGHT_RANGES
+                .iter()
+                .any(|(start, end)| (*start..=*end).contains(&code))
+            {
+                1
+            } else {
+                2
+            }
+        })
+        .sum()
+}
+
+// Cut the text at a grapheme boundary so that the result, including the
+// trailing ellipsis, fits the limit. Text that already fits is returned as is.
+pub fn truncate(text: &str, limit: &Limit) -> String {
+    if limit.fits(text) {
+        return text.to_string();
+    }
+    let max_len = limit.max_len();
+    let mut res = String::with_capacity(text.len());
+    let mut len = limit.len(&ELLIPSIS.to_string());
+    if len > max_len {
+        return res;
+    }
+    for grapheme in text.graphemes(true) {
+        let grapheme_len = limit.len(grapheme);
+        if len + grapheme_len > max_len {
+            break;
+        }
+        len += grapheme_len;
+        res.push_str(grapheme);
+    }
+    res.push(ELLIPSIS);
+    res
+}
+
+#[cfg(test)]
+mod test_length {
+    use super::*;
+
+    #[test]
+    pub fn test_platform_len() {
+        assert_eq!(3, Limit::Twitter.len("abc"));
+        assert_eq!(6, Limit::Twitter.len("𝐚𝐛𝐜"));
+        assert_eq!(6, Limit::Discord.len("𝐚𝐛𝐜"));
+        assert_eq!(3, Limit::Chars(10).len("𝐚𝐛𝐜"));
+    }
+
+    #[test]
+    pub fn test_len_helpers() {
+        assert_eq!(0, utf16_len(""));
+        assert_eq!(5, utf16_len("hello"));
+        assert_eq!(2, utf16_len("𝐀"));
+        assert_eq!(1, utf16_len("ℎ"));
+        // Latin, Greek and general punctuation are light, the rest is heavy
+        assert_eq!(1, twitter_weighted_len("α"));
+        assert_eq!(1, twitter_weighted_len("\u{2014}"));
+        assert_eq!(2, twitter_weighted_len("ℎ"));
+        assert_eq!(2, twitter_weighted_len("𝐀"));
+        assert_eq!(7, twitter_weighted_len("hi 𝐚𝐛"));
+    }
+
+    #[test]
+    pub fn test_text_counts() {
+        assert_eq!(
+            TextCounts {
+                chars: 4,
+                bytes: 11,
+                utf16: 6,
+                graphemes: 3,
+                width: 3,
+            },
+            TextCounts::of("𝐚e\u{301}𝐛")
+        );
+        assert_eq!(TextCounts::default(), TextCounts::of(""));
+    }
+
+    #[test]
+    pub fn test_truncate() {
+        assert_eq!("abc", truncate("abc", &Limit::Chars(3)));
+        assert_eq!("ab…", truncate("abcd", &Limit::Chars(3)));
+        // every styled letter weighs 2, and so does the ellipsis
+        let styled = "𝐚".repeat(200);
+        let res = truncate(&styled, &Limit::Twitter);
+        assert!(Limit::Twitter.fits(&res));
+        assert_eq!(format!("{}…", "𝐚".repeat(139)), res);
+        assert_eq!("", truncate("abc", &Limit::Chars(0)));
+    }
+}
@@ -1,7 +1,11 @@
-use clap::ValueEnum;
-use std::{collections::HashMap, fmt::Display, str::FromStr};
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug)]
+use crate::{suggest::suggest, tables::*};
+
+/// The class of a character, which decides the runs it has styled forms in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CharacterType {
     Letter,
     Digit,
@@ -9,20 +13,54 @@ pub enum CharacterType {
     Other,
 }
 
-#[derive(ValueEnum, Debug, Clone)]
+/// A font of the Mathematical Alphanumeric Symbols block. Fonts are added in
+/// minor releases, hence non_exhaustive.
+///
+/// The default is `Serif`: unstyled text reads as serif.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum StyledLetter {
+    // the defaults are documented on the enums, clap would list a doc
+    // comment here in the --help values
+    #[default]
     Serif,
+    #[value(alias = "sans", alias = "sans-serif", alias = "sans_serif")]
     SansSerif,
+    #[value(alias = "cal", alias = "calligraphy")]
     Script,
+    #[value(alias = "frak", alias = "gothic")]
     Fraktur,
+    #[value(alias = "tt", alias = "mono")]
     MonoSpace,
+    #[value(
+        alias = "bb",
+        alias = "blackboard",
+        alias = "double-struck",
+        alias = "double_struck"
+    )]
     DoubleStruck,
 }
 
+impl StyledLetter {
+    /// every font, in declaration order
+    pub const ALL: &[StyledLetter] = &[
+        StyledLetter::Serif,
+        StyledLetter::SansSerif,
+        StyledLetter::Script,
+        StyledLetter::Fraktur,
+        StyledLetter::MonoSpace,
+        StyledLetter::DoubleStruck,
+    ];
+}
+
 use std::error::Error;
 
+/// Why a character has no form in a font and style.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum LetterTypeError {
     ExceedLengthError(usize),
     InvalidTypeError,
@@ -43,1087 +81,39 @@ impl Display for LetterTypeError {
 
 impl Error for LetterTypeError {}
 
-#[derive(ValueEnum, Debug, Clone)]
+/// The weight and slant of a font. Styles are added in minor releases, hence
+/// non_exhaustive.
+///
+/// The default is `Normal`, which leaves letters upright and regular weight.
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[value(rename_all = "lower")]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum LetterStyle {
+    #[default]
     Normal,
     Bold,
     Italic,
+    #[value(alias = "bold-italic", alias = "bold_italic")]
     BoldItalic,
 }
 
-trait TypeStyle {
-    fn get_char(
-        &self,
-        offset: usize,
-        style: &LetterStyle,
-        uppercase: bool,
-    ) -> Result<char, LetterTypeError>;
-}
-
-// a field for the number of characters
-#[derive(Debug)]
-struct SerifType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-macro_rules! impl_type_style {
-    ($type_name: ident) => {
-        impl TypeStyle for $type_name {
-            fn get_char(
-                &self,
-                offset: usize,
-                style: &LetterStyle,
-                uppercase: bool,
-            ) -> Result<char, LetterTypeError> {
-                match *style {
-                    LetterStyle::Normal => self.normal(offset, uppercase),
-                    LetterStyle::Italic => self.italic(offset, uppercase),
-                    LetterStyle::Bold => self.bold(offset, uppercase),
-                    LetterStyle::BoldItalic => self.bold_italic(offset, uppercase),
-                }
-            }
-        }
-    };
-}
-
-impl_type_style!(SerifType);
-impl_type_style!(SansSerifType);
-impl_type_style!(ScriptType);
-impl_type_style!(FrakturType);
-impl_type_style!(MonoSpaceType);
-impl_type_style!(DoubleStruckType);
-impl_type_style!(GreekType);
-impl_type_style!(GreekSansSerifType);
-impl_type_style!(DigitType);
-impl_type_style!(DigitSansSerifType);
-impl_type_style!(DigitMonoSpaceType);
-impl_type_style!(DigitDoubleStruckType);
-
-impl SerifType {
-    fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: Some((0x41, 0x61)),
-            bold_start: Some((0x1D400, 0x1D41A)),
-            italic_start: Some((0x1D434, 0x1D44E)),
-            bold_italic_start: Some((0x1D468, 0x1D482)),
-        }
-    }
-
-    fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    // lowercase letters
-    // serif
-    // italic
-    // h 0x210E ℎ
-    fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                if offset == 7 {
-                    return Ok('\u{210E}');
-                }
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct SansSerifType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl SansSerifType {
-    fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: Some((0x1D5A0, 0x1D5BA)),
-            bold_start: Some((0x1D5D4, 0x1D5EE)),
-            italic_start: Some((0x1D608, 0x1D622)),
-            bold_italic_start: Some((0x1D63C, 0x1D656)),
-        }
-    }
-
-    fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct ScriptType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value.
-    // If the style is not supported, then it is None.
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl ScriptType {
-    pub fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: Some((0x1D49C, 0x1D4B6)),
-            bold_start: Some((0x1D4D0, 0x1D4EA)),
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    // # abnormal script unicodes
-    // Uppercase normal
-    // 0x212C ℬ B
-    // 0x2130 ℰ E
-    // 0x2131 ℱ F
-    // 0x210B ℋ H
-    // 0x2110 ℐ J
-    // 0x2112 ℒ L
-    // 0x2133 ℳ M
-    // 0x211B ℛ R
-
-    // script
-    // normal
-    // e 0x212F ℯ
-    // g 0x210A ℊ
-    // o 0x2134 ℴ
-    pub fn get_normal_corner_case(uppercase: bool) -> (Vec<usize>, Vec<char>) {
-        let uppercase_corner_cases: Vec<usize> = vec![1, 4, 5, 7, 8, 11, 12, 17];
-        let lowercase_corner_cases: Vec<usize> = vec![4, 6, 14];
-        let uppercase_chs: Vec<char> = vec![
-            '\u{212C}', // ℬ B
-            '\u{2130}', // ℰ E
-            '\u{2131}', // ℱ F
-            '\u{210B}', // ℋ H
-            '\u{2110}', // ℐ I
-            '\u{2112}', // ℒ L
-            '\u{2133}', // ℳ M
-            '\u{211B}', // ℛ R
-        ];
-        let lowercase_chs: Vec<char> = vec![
-            '\u{212F}', // ℯ e
-            '\u{210A}', // ℊ g
-            '\u{2134}', // ℴ o
-        ];
-        if uppercase {
-            (uppercase_corner_cases, uppercase_chs)
-        } else {
-            (lowercase_corner_cases, lowercase_chs)
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        // input ch -> offset/type/style/uppercase
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                let (uppercase_corner_cases, uppercase_chs) =
-                    Self::get_normal_corner_case(uppercase);
-                if let Ok(index) = uppercase_corner_cases.binary_search(&offset) {
-                    return Ok(uppercase_chs[index]);
-                }
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                let (lowercase_corner_cases, lowercase_chs) =
-                    Self::get_normal_corner_case(uppercase);
-                if let Ok(index) = lowercase_corner_cases.binary_search(&offset) {
-                    return Ok(lowercase_chs[index]);
-                }
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct FrakturType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl FrakturType {
-    pub fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: Some((0x1D504, 0x1D51E)),
-            bold_start: Some((0x1D56C, 0x1D586)),
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn get_normal_corner_case() -> (Vec<usize>, Vec<char>) {
-        let uppercase_corner_cases: Vec<usize> = vec![2, 7, 8, 17, 25];
-        let uppercase_chs: Vec<char> = vec![
-            '\u{212D}', // C  ℭ
-            '\u{210C}', // H  ℌ
-            '\u{2111}', // I  ℑ
-            '\u{211C}', // R ℜ
-            '\u{2128}', // Z  ℨ
-        ];
-        return (uppercase_corner_cases, uppercase_chs);
-    }
-
-    // # abnormal Fraktur
-    // normal
-    // C 0x212D ℭ
-    // H 0x210C ℌ
-    // I 0x2111 ℑ
-    // R 0x211C ℜ
-    // Z 0x2128 ℨ
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                let (uppercase_corner_cases, uppercase_chs) = Self::get_normal_corner_case();
-                if let Ok(index) = uppercase_corner_cases.binary_search(&offset) {
-                    return Ok(uppercase_chs[index]);
-                }
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct MonoSpaceType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl MonoSpaceType {
-    pub fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: Some((0x1D670, 0x1D68A)),
-            bold_start: None,
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct DoubleStruckType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl DoubleStruckType {
-    pub fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: None,
-            bold_start: Some((0x1D538, 0x1D552)),
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-    // # abnormal double-struck
-    // bold
-    // C 0x2102 ℂ
-    // H 0x210D ℍ
-    // N 0x2115 ℕ
-    // P 0x2119 ℙ
-    // Q 0x211A ℚ
-    // R 0x211D ℝ
-    // Z 0x2124 ℤ
-    pub fn get_bold_corner_case() -> (Vec<usize>, Vec<char>) {
-        let indices = vec![2,7,13,15,16,17,25];
-        let chars = vec![
-'\u{2102}',  // C 0x2102 ℂ
-'\u{210D}',  // H 0x210D ℍ
-'\u{2115}',  // N 0x2115 ℕ
-'\u{2119}',  // P 0x2119 ℙ
-'\u{211A}',  // Q 0x211A ℚ
-'\u{211D}',  // R 0x211D ℝ
-'\u{2124}',  // Z 0x2124 ℤ
-        ];
-        (indices, chars)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                let (indices, chars) = Self::get_bold_corner_case();
-                if let Ok(idx) = indices.binary_search(&offset) {
-                    return Ok(chars[idx]);
-                }
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct GreekType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl GreekType {
-    pub fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: Some((0x391, 0x3B1)),
-            bold_start: Some((0x1D6A8, 0x1D6C2)),
-            italic_start: Some((0x1D6E2, 0x1D6FC)),
-            bold_italic_start: Some((0x1D71C, 0x1D736)),
-        }
-    }
-
-    pub fn get_normal_corner_case(uppercase: bool) -> (Vec<usize>, Vec<char>) {
-        let uppercase_chs: Vec<char> = vec![
-           '\u{3F4}', // ϴ 17
-           '\u{2207}', // ∇  25
-        ];
-        let uppercase_corner_cases: Vec<usize> = vec![17,25];
-        let lowercase_chs: Vec<char> = vec![
-           '\u{2202}',// ∂ 25
-           '\u{3F5}', // ϵ 26
-           '\u{3D1}', // ϑ 27
-           '\u{3F0}', //  ϰ 28
-           '\u{3D5}', //  ϕ 29
-           '\u{3F1}', //  ϱ 30
-           '\u{3D6}', //  ϖ 31
-        ];
-        let lowercase_corner_cases: Vec<usize> = vec![25,26,27,28,29,30,31];
-        if uppercase {
-            (uppercase_corner_cases,uppercase_chs)
-        }else{
-            (lowercase_corner_cases,lowercase_chs)
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                let (indices,chs) = Self::get_normal_corner_case(uppercase);
-                if let Ok(idx) = indices.binary_search(&offset)  {
-                    return Ok(chs[idx]);
-                }
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                let (indices,chs) = Self::get_normal_corner_case(uppercase);
-                if let Ok(idx) = indices.binary_search(&offset)  {
-                    return Ok(chs[idx]);
-                }
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct GreekSansSerifType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl GreekSansSerifType {
-    pub fn new() -> Self {
-        Self {
-            number: 26,
-            normal_start: None,
-            bold_start: Some((0x1D756, 0x1D770)),
-            italic_start: None,
-            bold_italic_start: Some((0x1D790, 0x1D7AA)),
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_italic_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct DigitType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl DigitType {
-    pub fn new() -> Self {
-        Self {
-            number: 10,
-            normal_start: Some((0x30, 0x30)),
-            bold_start: Some((0x1D7CE, 0x1D7CE)),
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct DigitDoubleStruckType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl DigitDoubleStruckType {
-    pub fn new() -> Self {
-        Self {
-            number: 10,
-            normal_start: Some((0x1D7D8, 0x1D7D8)),
-            bold_start: None,
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct DigitSansSerifType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
-}
-
-impl DigitSansSerifType {
-    pub fn new() -> Self {
-        Self {
-            number: 10,
-            normal_start: Some((0x1D7E2, 0x1D7E2)),
-            bold_start: Some((0x1D7EC, 0x1D7EC)),
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-        if let Some((uppercase_start, lowercase_start)) = self.bold_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-}
-
-#[derive(Debug)]
-struct DigitMonoSpaceType {
-    number: usize,
-    // (uppercase, lowercase)
-    // If there is no cases, then we fill them with the same value
-    normal_start: Option<(u32, u32)>,
-    bold_start: Option<(u32, u32)>,
-    italic_start: Option<(u32, u32)>,
-    bold_italic_start: Option<(u32, u32)>,
+impl LetterStyle {
+    /// every style, in declaration order
+    pub const ALL: &[LetterStyle] = &[
+        LetterStyle::Normal,
+        LetterStyle::Bold,
+        LetterStyle::Italic,
+        LetterStyle::BoldItalic,
+    ];
 }
 
-impl DigitMonoSpaceType {
-    pub fn new() -> Self {
-        Self {
-            number: 10,
-            normal_start: Some((0x1D7F6, 0x1D7F6)),
-            bold_start: None,
-            italic_start: None,
-            bold_italic_start: None,
-        }
-    }
-
-    pub fn normal(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        if let Some((uppercase_start, lowercase_start)) = self.normal_start {
-            if uppercase {
-                return Ok(char::from_u32(uppercase_start + offset as u32).unwrap());
-            } else {
-                return Ok(char::from_u32(lowercase_start + offset as u32).unwrap());
-            }
-        }
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
-
-    pub fn bold_italic(&self, offset: usize, uppercase: bool) -> Result<char, LetterTypeError> {
-        if offset >= self.number {
-            // eprintln!("Use offset {:?} exceeds the length of SerifType {}", offset, self.number);
-            return Err(LetterTypeError::ExceedLengthError(offset));
-        }
-
-        Err(LetterTypeError::InvalidStyleError)
-    }
+fn corner_case_offset(cases: &[(usize, char)], ch: char) -> Option<usize> {
+    cases.iter().find(|(_, case)| *case == ch).map(|(offset, _)| *offset)
 }
 
+/// Where a character sits: its run and its offset in it.
 #[derive(Debug)]
 pub struct LetterInfo {
     pub(crate) offset: usize,
@@ -1133,12 +123,14 @@ pub struct LetterInfo {
     pub(crate) character_type: CharacterType,
 }
 
+/// A character with styled forms, or any other character as it is.
 pub enum CharacterInfo {
     Letter(LetterInfo),
     Other(char),
 }
 
 impl CharacterInfo {
+    /// Look the character up in every run, plain and styled.
     pub fn get_letter_info(ch: char) -> CharacterInfo {
         let val = ch as u32;
         match ch {
@@ -1246,8 +238,9 @@ impl CharacterInfo {
                 LetterStyle::Bold,
                 CharacterType::Letter,
             )), // bold
-            // Greek
-            '\u{0391}'..='\u{03AA}' => CharacterInfo::Letter(LetterInfo::new(
+            // Greek; U+03A2 is unassigned, the uppercase final sigma that doesn't exist
+            '\u{03A2}' => CharacterInfo::Other(ch),
+            '\u{0391}'..='\u{03A9}' => CharacterInfo::Letter(LetterInfo::new(
                 (val - 0x0391) as usize,
                 true,
                 StyledLetter::Serif,
@@ -1395,7 +388,7 @@ impl CharacterInfo {
                 CharacterType::Letter,
             )), // bold
             // Greek
-            '\u{03B1}'..='\u{03D0}' => CharacterInfo::Letter(LetterInfo::new(
+            '\u{03B1}'..='\u{03C9}' => CharacterInfo::Letter(LetterInfo::new(
                 (val - 0x03B1) as usize,
                 false,
                 StyledLetter::Serif,
@@ -1477,59 +470,86 @@ impl CharacterInfo {
             '\u{1D7F6}'..='\u{1D7FF}' => CharacterInfo::Letter(LetterInfo::new(
                 (val - 0x1D7F6) as usize,
                 false,
-                StyledLetter::DoubleStruck,
+                StyledLetter::MonoSpace,
                 LetterStyle::Normal,
                 CharacterType::Digit,
             )), // normal
             _ => {
                 // corner cases
+                // the corner case tables are ordered by offset, not by code point,
+                // so they have to be searched linearly here
                 // script
-                let (uppercase_script_indices, uppercase_script_chs) =
-                    ScriptType::get_normal_corner_case(true);
-                let (lowercase_script_indices, lowercase_script_chs) =
-                    ScriptType::get_normal_corner_case(false);
-                if let Ok(index) = uppercase_script_chs.binary_search(&ch) {
+                if let Some(offset) = corner_case_offset(SCRIPT_NORMAL_UPPER_CORNER_CASES, ch) {
                     return CharacterInfo::Letter(LetterInfo::new(
-                        uppercase_script_indices[index],
+                        offset,
                         true,
                         StyledLetter::Script,
                         LetterStyle::Normal,
                         CharacterType::Letter,
                     ));
                 }
-                if let Ok(index) = lowercase_script_chs.binary_search(&ch) {
+                if let Some(offset) = corner_case_offset(SCRIPT_NORMAL_LOWER_CORNER_CASES, ch) {
                     return CharacterInfo::Letter(LetterInfo::new(
-                        lowercase_script_indices[index],
-                        true,
+                        offset,
+                        false,
                         StyledLetter::Script,
                         LetterStyle::Normal,
                         CharacterType::Letter,
                     ));
                 }
                 // fraktur
-                let (uppercase_fraktur_indices, uppercase_fraktur_chs) =
-                    FrakturType::get_normal_corner_case();
-                if let Ok(index) = uppercase_fraktur_chs.binary_search(&ch) {
+                if let Some(offset) = corner_case_offset(FRAKTUR_NORMAL_UPPER_CORNER_CASES, ch) {
                     return CharacterInfo::Letter(LetterInfo::new(
-                        uppercase_fraktur_indices[index],
+                        offset,
                         true,
                         StyledLetter::Fraktur,
                         LetterStyle::Normal,
                         CharacterType::Letter,
                     ));
                 }
-                // serif italic
-                if ch == '\u{210E}' {
+                // double-struck
+                if let Some(offset) = corner_case_offset(DOUBLE_STRUCK_BOLD_UPPER_CORNER_CASES, ch) {
                     return CharacterInfo::Letter(LetterInfo::new(
-                        7,
+                        offset,
                         true,
+                        StyledLetter::DoubleStruck,
+                        LetterStyle::Bold,
+                        CharacterType::Letter,
+                    ));
+                }
+                // greek corner cases
+                let greek_uppercase_offset = corner_case_offset(SERIF_NORMAL_GREEK_UPPER_CORNER_CASES, ch);
+                if let Some(offset) = greek_uppercase_offset {
+                    return CharacterInfo::Letter(LetterInfo::new(
+                        offset,
+                        true,
+                        StyledLetter::Serif,
+                        LetterStyle::Normal,
+                        CharacterType::Greek,
+                    ));
+                }
+                let greek_symbol_offset = corner_case_offset(SERIF_NORMAL_GREEK_LOWER_CORNER_CASES, ch);
+                if let Some(offset) = greek_symbol_offset {
+                    return CharacterInfo::Letter(LetterInfo::new(
+                        offset,
+                        false,
+                        StyledLetter::Serif,
+                        LetterStyle::Normal,
+                        CharacterType::Greek,
+                    ));
+                }
+                // serif italic
+                if let Some(offset) = corner_case_offset(SERIF_ITALIC_LOWER_CORNER_CASES, ch) {
+                    return CharacterInfo::Letter(LetterInfo::new(
+                        offset,
+                        false,
                         StyledLetter::Serif,
                         LetterStyle::Italic,
                         CharacterType::Letter,
                     ));
                 }
 
-                return CharacterInfo::Other(ch);
+                CharacterInfo::Other(ch)
             }
         }
     }
@@ -1552,167 +572,207 @@ impl LetterInfo {
         }
     }
 
+    /// the offset in the run, e.g. 0..26 for Latin letters; see `tables`
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn uppercase(&self) -> bool {
+        self.uppercase
+    }
+
+    pub fn letter_type(&self) -> &StyledLetter {
+        &self.letter_type
+    }
+
+    pub fn letter_style(&self) -> &LetterStyle {
+        &self.letter_style
+    }
+
+    pub fn character_type(&self) -> &CharacterType {
+        &self.character_type
+    }
+
+    /// The character at the same offset in another font and style.
     pub fn convert(
         &self,
         letter_type: &StyledLetter,
         letter_style: &LetterStyle,
     ) -> Result<char, LetterTypeError> {
-        match self.character_type {
-            CharacterType::Letter => match *letter_type {
-                StyledLetter::Serif => {
-                    let serif = SerifType::new();
-                    serif.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::SansSerif => {
-                    let sans_serif = SansSerifType::new();
-                    sans_serif.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::Script => {
-                    let script = ScriptType::new();
-                    script.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::Fraktur => {
-                    let fraktur = FrakturType::new();
-                    fraktur.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::MonoSpace => {
-                    let mono = MonoSpaceType::new();
-                    mono.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::DoubleStruck => {
-                    let double_struck = DoubleStruckType::new();
-                    double_struck.get_char(self.offset, letter_style, self.uppercase)
-                }
-            },
-            CharacterType::Greek => match letter_type {
-                StyledLetter::Serif => {
-                    let serif = GreekType::new();
-                    serif.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::SansSerif => {
-                    let sans_serif = GreekSansSerifType::new();
-                    sans_serif.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::Script => Err(LetterTypeError::InvalidTypeError),
-                StyledLetter::Fraktur => Err(LetterTypeError::InvalidTypeError),
-                StyledLetter::MonoSpace => Err(LetterTypeError::InvalidTypeError),
-                StyledLetter::DoubleStruck => Err(LetterTypeError::InvalidTypeError),
-            },
-            CharacterType::Digit => match letter_type {
-                StyledLetter::Serif => {
-                    let serif = DigitType::new();
-                    serif.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::SansSerif => {
-                    let sans_serif = DigitSansSerifType::new();
-                    sans_serif.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::Script => Err(LetterTypeError::InvalidTypeError),
-                StyledLetter::Fraktur => Err(LetterTypeError::InvalidTypeError),
-                StyledLetter::MonoSpace => {
-                    let mono = DigitMonoSpaceType::new();
-                    mono.get_char(self.offset, letter_style, self.uppercase)
-                }
-                StyledLetter::DoubleStruck => {
-                    let double_struck = DigitDoubleStruckType::new();
-                    double_struck.get_char(self.offset, letter_style, self.uppercase)
-                }
-            },
-            CharacterType::Other => Err(LetterTypeError::InvalidTypeError),
-        }
+        #[cfg(feature = "static-tables")]
+        return crate::table::lookup(self, letter_type, letter_style);
+        #[cfg(not(feature = "static-tables"))]
+        crate::computed::compute(self, letter_type, letter_style)
     }
 }
 
-impl ToString for StyledLetter {
-    fn to_string(&self) -> String {
+impl Display for StyledLetter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
-            StyledLetter::Serif => "serif".to_string(),
-            StyledLetter::SansSerif => "sans_serif".to_string(),
-            StyledLetter::Script => "script".to_string(),
-            StyledLetter::Fraktur => "fraktur".to_string(),
-            StyledLetter::MonoSpace => "mono".to_string(),
-            StyledLetter::DoubleStruck => "double_struck".to_string(),
+            StyledLetter::Serif => f.write_str("serif"),
+            StyledLetter::SansSerif => f.write_str("sans_serif"),
+            StyledLetter::Script => f.write_str("script"),
+            StyledLetter::Fraktur => f.write_str("fraktur"),
+            StyledLetter::MonoSpace => f.write_str("mono"),
+            StyledLetter::DoubleStruck => f.write_str("double_struck"),
         }
     }
 }
 
-impl ToString for LetterStyle {
-    fn to_string(&self) -> String {
+impl Display for LetterStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
-            LetterStyle::Bold => "bold".to_string(),
-            LetterStyle::BoldItalic => "bold_italic".to_string(),
-            LetterStyle::Italic => "italic".to_string(),
-            LetterStyle::Normal => "normal".to_string(),
+            LetterStyle::Bold => f.write_str("bold"),
+            LetterStyle::BoldItalic => f.write_str("bold_italic"),
+            LetterStyle::Italic => f.write_str("italic"),
+            LetterStyle::Normal => f.write_str("normal"),
         }
     }
 }
 
+// the name clap uses for a value, e.g. "sansserif"
+pub(crate) fn value_name<T: ValueEnum>(value: &T) -> String {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+// The names and aliases are declared once on the enums for clap; parsing
+// from the library goes through the same table (case-insensitively). An
+// unknown name is reported with the closest valid ones.
+pub(crate) fn parse_value_enum<T: ValueEnum>(s: &str, what: &str) -> Result<T, String> {
+    if let Ok(value) = T::from_str(s, true) {
+        return Ok(value);
+    }
+    let possible_values: Vec<PossibleValue> = T::value_variants()
+        .iter()
+        .filter_map(|value| value.to_possible_value())
+        .collect();
+    let names = possible_values
+        .iter()
+        .flat_map(|value| value.get_name_and_aliases());
+    let suggestions = suggest(s, names);
+    if suggestions.is_empty() {
+        let names: Vec<&str> = possible_values.iter().map(|value| value.get_name()).collect();
+        return Err(format!(
+            "unknown {} '{}', expected one of: {}",
+            what,
+            s,
+            names.join(", ")
+        ));
+    }
+    let suggestions: Vec<String> = suggestions
+        .iter()
+        .map(|suggestion| format!("'{}'", suggestion))
+        .collect();
+    Err(format!(
+        "unknown {} '{}', did you mean {}?",
+        what,
+        s,
+        suggestions.join(" or ")
+    ))
+}
+
+impl FromStr for StyledLetter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_value_enum(s, "letter type")
+    }
+}
+
+impl FromStr for LetterStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_value_enum(s, "letter style")
+    }
+}
+
 #[cfg(test)]
 mod test_ascii {
     use super::*;
     #[test]
-    pub fn test_script_corner_cases() {
-        let script_type = ScriptType::new();
-        let uppercase_corner_cases: Vec<usize> = vec![1, 4, 5, 7, 9, 11, 12, 17];
-        let lowercase_corner_cases: Vec<usize> = vec![4, 6, 14];
-        let uppercase_chs: Vec<char> = vec![
-            '\u{212C}', // ℬ B
-            '\u{2130}', // ℰ E
-            '\u{2131}', // ℱ F
-            '\u{210B}', // ℋ H
-            '\u{2110}', // ℐ J
-            '\u{2112}', // ℒ L
-            '\u{2133}', // ℳ M
-            '\u{211B}', // ℛ R
-        ];
-        let lowercase_chs: Vec<char> = vec![
-            '\u{212F}', // ℯ e
-            '\u{210A}', // ℊ g
-            '\u{2134}', // ℴ o
-        ];
-
-        for (idx, val) in uppercase_corner_cases.iter().enumerate() {
-            let res = script_type.normal(*val, true);
-            assert_eq!(true, res.is_ok());
-            let ch = res.unwrap();
-            assert_eq!(uppercase_chs[idx], ch);
+    pub fn test_parse_aliases() {
+        assert_eq!(StyledLetter::DoubleStruck, "bb".parse().unwrap());
+        assert_eq!(StyledLetter::DoubleStruck, "Blackboard".parse().unwrap());
+        assert_eq!(StyledLetter::Script, "cal".parse().unwrap());
+        assert_eq!(StyledLetter::Fraktur, "gothic".parse().unwrap());
+        assert_eq!(StyledLetter::MonoSpace, "tt".parse().unwrap());
+        assert_eq!(StyledLetter::SansSerif, "sans".parse().unwrap());
+        assert_eq!(LetterStyle::BoldItalic, "bold-italic".parse().unwrap());
+        assert_eq!(
+            Err("unknown letter style 'itlaic', did you mean 'italic'?".to_string()),
+            "itlaic".parse::<LetterStyle>()
+        );
+        assert!("comic".parse::<StyledLetter>().is_err());
+        assert_eq!("double_struck bold_italic", format!("{} {}", StyledLetter::DoubleStruck, LetterStyle::BoldItalic));
+        assert_eq!(Ok(StyledLetter::SansSerif), "SansSerif".parse());
+        // the names `Display` produces parse back
+        for letter_type in StyledLetter::value_variants() {
+            assert_eq!(*letter_type, letter_type.to_string().parse().unwrap());
+        }
+        for letter_style in LetterStyle::value_variants() {
+            assert_eq!(*letter_style, letter_style.to_string().parse().unwrap());
         }
+    }
 
-        for (idx, val) in lowercase_corner_cases.iter().enumerate() {
-            let res = script_type.normal(*val, false);
-            assert_eq!(true, res.is_ok());
-            let ch = res.unwrap();
-            assert_eq!(lowercase_chs[idx], ch);
-        }
+    #[test]
+    pub fn test_all_variants() {
+        // ALL has to list exactly the variants clap knows about
+        assert_eq!(StyledLetter::value_variants(), StyledLetter::ALL);
+        assert_eq!(LetterStyle::value_variants(), LetterStyle::ALL);
     }
 
     #[test]
-    pub fn test_fraktur_corner_cases() {
-        let fraktur_type = FrakturType::new();
-        let uppercase_corner_cases: Vec<usize> = vec![2, 7, 8, 17, 25];
-        let uppercase_chs: Vec<char> = vec![
-            '\u{212D}', // C  ℭ
-            '\u{210C}', // H  ℌ
-            '\u{2111}', // I  ℑ
-            '\u{211C}', // R ℜ
-            '\u{2128}', // Z  ℨ
-        ];
+    pub fn test_defaults() {
+        assert_eq!(StyledLetter::Serif, StyledLetter::default());
+        assert_eq!(LetterStyle::Normal, LetterStyle::default());
+        // usable as map keys
+        let mut fonts = std::collections::HashMap::new();
+        fonts.insert((StyledLetter::Fraktur, LetterStyle::Bold), 'A');
+        assert_eq!(Some(&'A'), fonts.get(&(StyledLetter::Fraktur, LetterStyle::Bold)));
+    }
 
-        for (idx, val) in uppercase_corner_cases.iter().enumerate() {
-            let res = fraktur_type.normal(*val, true);
-            assert!(res.is_ok());
-            let ch = res.unwrap();
-            assert_eq!(uppercase_chs[idx], ch);
+    #[test]
+    pub fn test_greek_round_trip() {
+        let styles = [
+            (StyledLetter::Serif, LetterStyle::Normal),
+            (StyledLetter::Serif, LetterStyle::Bold),
+            (StyledLetter::Serif, LetterStyle::Italic),
+            (StyledLetter::Serif, LetterStyle::BoldItalic),
+            (StyledLetter::SansSerif, LetterStyle::Bold),
+            (StyledLetter::SansSerif, LetterStyle::BoldItalic),
+        ];
+        for (letter_type, letter_style) in styles.iter() {
+            for (uppercase, number) in [(true, 26), (false, 32)] {
+                for offset in 0..number {
+                    let info = LetterInfo::new(
+                        offset,
+                        uppercase,
+                        StyledLetter::Serif,
+                        LetterStyle::Normal,
+                        CharacterType::Greek,
+                    );
+                    let ch = info.convert(letter_type, letter_style).unwrap();
+                    match CharacterInfo::get_letter_info(ch) {
+                        CharacterInfo::Letter(res) => {
+                            assert_eq!(offset, res.offset, "{} {:?}-{:?}", ch, letter_type, letter_style);
+                            assert_eq!(uppercase, res.uppercase, "{}", ch);
+                            assert!(matches!(res.character_type, CharacterType::Greek));
+                        }
+                        CharacterInfo::Other(_) => panic!("{} is not recognized", ch),
+                    }
+                }
+            }
         }
     }
 
     #[test]
-    pub fn test_serif_corner_cases() {
-        let serif_type = SerifType::new();
-        let res = serif_type.italic(7, false);
-        assert!(res.is_ok());
-        let ch = res.unwrap();
-        assert_eq!('\u{210E}', ch);
+    pub fn test_unassigned_greek() {
+        assert!(matches!(CharacterInfo::get_letter_info('\u{03A2}'), CharacterInfo::Other('\u{03A2}')));
+        assert_eq!('\u{03A2}', crate::convert_pair('\u{03A2}', &StyledLetter::Serif, &LetterStyle::Bold).unwrap());
     }
 
     #[test]
@@ -1,7 +1,175 @@
+mod a11y;
+mod ascii;
+mod ast;
+mod capabilities;
+mod braille;
+mod check;
+mod clipboard;
+mod combination;
+mod compat;
+#[cfg(any(not(feature = "static-tables"), test))]
+mod computed;
+mod conformance;
+mod confusable;
+mod converter;
+mod decoration;
+mod coverage;
+mod diagnostic;
+mod doctor;
+mod document;
+mod entity;
+mod escape;
+mod export;
+mod ext;
+#[cfg(feature = "fantasy")]
+mod fantasy;
+mod filter;
+mod font;
+mod format;
+#[cfg(feature = "fun")]
+mod fun;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod global;
+mod grapheme;
+mod incremental;
+mod greek;
+#[cfg(feature = "i18n")]
+mod help_text;
+mod i18n;
+#[cfg(feature = "inspect")]
+mod inspect;
+mod invisible;
+mod length;
 mod letter;
-pub use letter::{StyledLetter, LetterStyle, CharacterInfo, LetterTypeError};
+mod markdown;
+mod marker;
+#[cfg(feature = "metadata")]
+mod metadata;
+mod number;
+mod offsets;
+mod presentation;
+mod protect;
+#[cfg(feature = "random")]
+mod random;
+mod render;
+mod samples;
+mod sanitize;
+mod select;
+mod spacing;
+mod spec;
+mod spoof;
+mod suggest;
+mod styled_char;
+mod styler;
+mod substitute;
+mod superscript;
+#[cfg(feature = "static-tables")]
+mod table;
+#[cfg(feature = "terminal")]
+mod terminal;
+pub mod tables;
+mod transform;
+mod utf16;
+mod width;
+pub use a11y::{a11y_report, A11yReport};
+pub use ascii::convert_ascii_str;
+pub use ast::{parse, AstNode, StyledAst};
+pub use capabilities::{capabilities, Capabilities, Combination, NamedValue};
+pub use braille::{from_braille, to_braille};
+pub use check::{diff, diff_lines, ChangeReport, DiffStyle};
+pub use clipboard::osc52;
+pub use combination::StyleCombination;
+pub use compat::{compat_report, downgrade, is_bmp, is_wgl4, CompatProfile, CompatReport};
+pub use confusable::{fold_confusable, to_plain_aggressive};
+pub use conformance::{conformance, registry_conformance};
+pub use converter::{default_fallback_chain, ConversionFailure, Converter, LossyConversion};
+pub use coverage::{coverage_report, CoverageReport, Platform};
+pub use decoration::Decoration;
+pub use diagnostic::Diagnostic;
+pub use doctor::{doctor, DoctorCheck, DoctorReport};
+pub use document::{
+    apply_patch, apply_range, style_range, Run, StyleEdit, StyledDocument, SCHEMA_VERSION,
+};
+#[cfg(feature = "arbitrary")]
+pub use fuzz::StyledString;
+pub use entity::{split_entities, EntityKind, EntitySegment};
+pub use escape::{split_escapes, EscapeSegment};
+#[cfg(feature = "fantasy")]
+pub use fantasy::{fantasy_pack, register_fantasy_pack};
+pub use export::{export, mappings, ExportFormat, Mapping};
+pub use ext::StyledTextExt;
+pub use filter::{filter, FilterMode};
+pub use font::Font;
+pub use format::{
+    escape_unicode, format_output, html_entities, percent_encode, unescape_unicode, EscapeFlavor,
+    OutputEncoding, OutputFormat,
+};
+#[cfg(feature = "fun")]
+pub use fun::{Leet, Transform};
+pub use global::{
+    convert_default, convert_default_str, default_converter, set_default, set_default_converter,
+};
+pub use grapheme::{is_emoji_sequence, styled_graphemes, StyledGrapheme};
+pub use incremental::{convert_edit, OutputEdit, TextEdit};
+#[cfg(feature = "i18n")]
+pub use help_text::HelpText;
+pub use i18n::Lang;
+pub use greek::{
+    restore_final_sigma, GreekMarks, GreekVariant, GreekVariantChoice, GreekVariantPolicy,
+};
+#[cfg(feature = "normalization")]
+pub use greek::{decompose_greek, decompose_greek_for};
+#[cfg(feature = "inspect")]
+pub use inspect::{inspect, CharInspection, INSPECT_HEADERS};
+pub use invisible::{find_invisible, strip_invisible, InvisibleKind, InvisibleRun};
+pub use length::{Limit, TextCounts, truncate, twitter_weighted_len, utf16_len};
+pub use letter::{StyledLetter, LetterStyle, CharacterInfo, CharacterType, LetterInfo, LetterTypeError};
+pub use markdown::{split_markdown, MarkdownSegment};
+pub use marker::{enumerate_lines, ListMarker};
+#[cfg(feature = "metadata")]
+pub use metadata::CharMetadata;
+pub use number::{convert_number, split_numbers, NumberOptions, NumberSegment};
+pub use offsets::{convert_with_offsets, ConvertedText, OffsetMap, OffsetUnit};
+pub use presentation::{keycaps, set_presentation, Presentation};
+pub use protect::{split_protected, ProtectedSegment};
+#[cfg(feature = "random")]
+pub use random::{convert_random, random_choices};
+pub use render::{AnsiRenderer, HtmlRenderer, MarkdownRenderer, Render, UnicodeRenderer};
+pub use samples::{sample_rows, samples, SampleFormat, PANGRAM};
+pub use sanitize::{sanitize, SanitizeReport};
+pub use select::{
+    contains_styled, convert_chars_if, convert_with, convert_words_if, Classification,
+    StyleDecision,
+};
+pub use spacing::{Spacing, add_spacing, remove_spacing};
+pub use spec::StyleSpec;
+pub use spoof::{detect_spoof, SpoofFinding, SpoofKind};
+pub use suggest::{edit_distance, suggest};
+pub use styled_char::{Decomposed, StyledChar};
+pub use styler::{StyleRegistry, Styler};
+pub use substitute::substitute;
+pub use superscript::{
+    convert_digit_style, from_digit_style, superscript_letter, to_digit_style,
+    to_superscript_letters, DigitStyle,
+    SuperscriptGap, SUPERSCRIPT_SUBSTITUTES,
+};
+#[cfg(feature = "terminal")]
+pub use terminal::TerminalPreview;
+pub use transform::{reverse, sentence_case, sentence_case_pieces};
+#[cfg(feature = "normalization")]
+pub use transform::{normalize, strip_diacritics, Normalization};
+pub use utf16::{convert_to_utf16, converted_utf16_len};
+pub use width::{center, display_width, format_table, pad_left, pad_right, truncate_width, wrap};
 
+// The letter type and style pair model, where pairs like fraktur italic have
+// no glyphs and fail. Prefer `Font`, which only has alphabets that exist.
+#[deprecated(note = "use Font::convert, or Converter for a letter type and style chosen at run time")]
 pub fn convert(ch: char, letter_type: &StyledLetter, letter_style: &LetterStyle) -> Result<char, LetterTypeError> {
+    convert_pair(ch, letter_type, letter_style)
+}
+
+pub(crate) fn convert_pair(ch: char, letter_type: &StyledLetter, letter_style: &LetterStyle) -> Result<char, LetterTypeError> {
     let character_info = CharacterInfo::get_letter_info(ch);
     match character_info {
         CharacterInfo::Letter(info) => {
@@ -13,3 +181,35 @@ pub fn convert(ch: char, letter_type: &StyledLetter, letter_style: &LetterStyle)
     }
 }
 
+// `convert` for every char of the text; emoji sequences are kept whole
+pub fn convert_str(text: &str, letter_type: &StyledLetter, letter_style: &LetterStyle) -> Result<String, LetterTypeError> {
+    Converter::new(*letter_type, *letter_style).convert_str(text)
+}
+
+// `convert_str` that keeps the chars it can't convert and reports them
+pub fn convert_str_lossy(text: &str, letter_type: &StyledLetter, letter_style: &LetterStyle) -> LossyConversion {
+    Converter::new(*letter_type, *letter_style).convert_str_lossy(text)
+}
+
+// Like `convert`, but Greek letters with a symbol variant (ε/ϵ, θ/ϑ, …) are
+// emitted in the form chosen by the policy.
+pub fn convert_with_greek_variants(
+    ch: char,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+    policy: &GreekVariantPolicy,
+) -> Result<char, LetterTypeError> {
+    Converter::new(*letter_type, *letter_style)
+        .with_greek_variants(policy.clone())
+        .convert_char(ch)
+}
+
+// serif normal is the plain ASCII (or plain Greek) range, so de-styling is
+// just a conversion back to it
+pub fn destyle(ch: char) -> char {
+    convert_pair(ch, &StyledLetter::Serif, &LetterStyle::Normal).unwrap_or(ch)
+}
+
+pub fn to_plain(text: &str) -> String {
+    text.chars().map(destyle).collect()
+}
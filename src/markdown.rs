@@ -0,0 +1,193 @@
+// Markdown split into what may be styled and what must stay as it is.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownSegment<'a> {
+    Text(&'a str),
+    // contents of code spans and code blocks
+    Code(&'a str),
+    // code fences, backticks, link destinations and autolinks
+    Syntax(&'a str),
+}
+
+// The fence a line opens or closes a fenced code block with: "```" or "~~~"
+// or longer, indented by at most three spaces.
+fn fence(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let ch = trimmed
+        .chars()
+        .next()
+        .filter(|ch| matches!(ch, '`' | '~'))?;
+    let len = trimmed.len() - trimmed.trim_start_matches(ch).len();
+    (len >= 3).then(|| &trimmed[..len])
+}
+
+fn is_indented_code(line: &str) -> bool {
+    line.starts_with("    ") || line.starts_with('\t')
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+// Code spans, link destinations and autolinks in a line of text.
+fn split_inline<'a>(line: &'a str, segments: &mut Vec<MarkdownSegment<'a>>) {
+    let mut text_start = 0;
+    let mut offset = 0;
+    while offset < line.len() {
+        let rest = &line[offset..];
+        if rest.starts_with('`') {
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            let after = &rest[ticks..];
+            // closed by a run of exactly as many backticks
+            let mut search = 0;
+            let mut close = None;
+            while let Some(found) = after[search..].find('`') {
+                let start = search + found;
+                let run = after[start..].len() - after[start..].trim_start_matches('`').len();
+                if run == ticks {
+                    close = Some(start);
+                    break;
+                }
+                search = start + run;
+            }
+            match close {
+                Some(close) => {
+                    push_text(line, text_start, offset, segments);
+                    segments.push(MarkdownSegment::Syntax(&rest[..ticks]));
+                    if close > 0 {
+                        segments.push(MarkdownSegment::Code(&after[..close]));
+                    }
+                    segments.push(MarkdownSegment::Syntax(&after[close..close + ticks]));
+                    offset += ticks + close + ticks;
+                    text_start = offset;
+                }
+                None => offset += ticks,
+            }
+            continue;
+        }
+        let syntax_len = if rest.starts_with("](") {
+            rest.find(')').map(|end| end + 1)
+        } else if rest.starts_with('<') {
+            rest.find('>')
+                .filter(|end| {
+                    let inner = &rest[1..*end];
+                    inner.contains(':') && !inner.contains(char::is_whitespace)
+                })
+                .map(|end| end + 1)
+        } else {
+            None
+        };
+        match syntax_len {
+            Some(len) => {
+                // keep "](" with the text, only the destination is protected
+                let skip = if rest.starts_with("](") { 2 } else { 0 };
+                push_text(line, text_start, offset + skip, segments);
+                segments.push(MarkdownSegment::Syntax(&rest[skip..len]));
+                offset += len;
+                text_start = offset;
+            }
+            None => offset += rest.chars().next().map(char::len_utf8).unwrap_or(1),
+        }
+    }
+    push_text(line, text_start, line.len(), segments);
+}
+
+fn push_text<'a>(line: &'a str, start: usize, end: usize, segments: &mut Vec<MarkdownSegment<'a>>) {
+    if start < end {
+        segments.push(MarkdownSegment::Text(&line[start..end]));
+    }
+}
+
+// Split Markdown so converting it leaves code samples and links working.
+pub fn split_markdown(text: &str) -> Vec<MarkdownSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut open_fence: Option<&str> = None;
+    let mut in_indented_code = false;
+    let mut prev_blank = true;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if let Some(open) = open_fence {
+            match fence(content) {
+                Some(close)
+                    if close.starts_with(&open[..1])
+                        && close.len() >= open.len()
+                        && content.trim_start().len() == close.len() =>
+                {
+                    open_fence = None;
+                    segments.push(MarkdownSegment::Syntax(line));
+                }
+                _ => segments.push(MarkdownSegment::Code(line)),
+            }
+            continue;
+        }
+        if let Some(open) = fence(content) {
+            open_fence = Some(open);
+            in_indented_code = false;
+            segments.push(MarkdownSegment::Syntax(line));
+            prev_blank = false;
+            continue;
+        }
+        // an indented code block can't interrupt a paragraph, but goes on
+        // over blank lines
+        in_indented_code = if in_indented_code {
+            is_indented_code(content) || is_blank(content)
+        } else {
+            prev_blank && is_indented_code(content)
+        };
+        if in_indented_code {
+            segments.push(MarkdownSegment::Code(line));
+        } else {
+            split_inline(line, &mut segments);
+        }
+        prev_blank = is_blank(content);
+    }
+    segments
+}
+
+#[cfg(test)]
+mod test_markdown {
+    use super::*;
+
+    #[test]
+    pub fn test_split_inline() {
+        assert_eq!(
+            vec![
+                MarkdownSegment::Text("Run "),
+                MarkdownSegment::Syntax("``"),
+                MarkdownSegment::Code("a ` b"),
+                MarkdownSegment::Syntax("``"),
+                MarkdownSegment::Text(", see [docs]("),
+                MarkdownSegment::Syntax("https://example.com)"),
+                MarkdownSegment::Text(" or "),
+                MarkdownSegment::Syntax("<https://x.y>"),
+                MarkdownSegment::Text(" `open"),
+            ],
+            split_markdown("Run ``a ` b``, see [docs](https://example.com) or <https://x.y> `open")
+        );
+    }
+
+    #[test]
+    pub fn test_split_blocks() {
+        let text = "# Title\n\n```rust\nlet x = 1;\n```\n\n    indented\n\nText\n    not code\n~~~~\n```\n~~~~\n";
+        assert_eq!(
+            vec![
+                MarkdownSegment::Text("# Title\n"),
+                MarkdownSegment::Text("\n"),
+                MarkdownSegment::Syntax("```rust\n"),
+                MarkdownSegment::Code("let x = 1;\n"),
+                MarkdownSegment::Syntax("```\n"),
+                MarkdownSegment::Text("\n"),
+                MarkdownSegment::Code("    indented\n"),
+                MarkdownSegment::Code("\n"),
+                MarkdownSegment::Text("Text\n"),
+                MarkdownSegment::Text("    not code\n"),
+                MarkdownSegment::Syntax("~~~~\n"),
+                MarkdownSegment::Code("```\n"),
+                MarkdownSegment::Syntax("~~~~\n"),
+            ],
+            split_markdown(text)
+        );
+    }
+}
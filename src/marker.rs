@@ -0,0 +1,84 @@
+use clap::ValueEnum;
+
+// Enclosed numbers from the Enclosed Alphanumerics block to number list
+// items with.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum ListMarker {
+    // ⒈ ⒉ ... ⒛
+    #[value(alias = "period")]
+    FullStop,
+    // ⑴ ⑵ ... ⒇
+    Parenthesized,
+    // ① ② ... ⑳, and ㉑ ... ㊿ from Enclosed CJK Letters and Months
+    Circled,
+}
+
+impl ListMarker {
+    // the enclosed form of `index`, None for 0 and numbers past the last
+    // form, 20 or 50 for circled
+    pub fn marker(&self, index: usize) -> Option<char> {
+        let first = match (self, index) {
+            (_, 0) => return None,
+            (ListMarker::FullStop, 1..=20) => 0x2488,
+            (ListMarker::Parenthesized, 1..=20) => 0x2474,
+            (ListMarker::Circled, 1..=20) => 0x2460,
+            (ListMarker::Circled, 21..=35) => 0x3251 - 20,
+            (ListMarker::Circled, 36..=50) => 0x32B1 - 35,
+            _ => return None,
+        };
+        char::from_u32(first + index as u32 - 1)
+    }
+
+    // the marker, or "21." ("(21)" when parenthesized) past the enclosed
+    // forms
+    pub fn format(&self, index: usize) -> String {
+        match (self.marker(index), self) {
+            (Some(marker), _) => marker.to_string(),
+            (None, ListMarker::Parenthesized) => format!("({})", index),
+            (None, _) => format!("{}.", index),
+        }
+    }
+}
+
+// Number the non-empty lines of the text, starting at 1, with the marker
+// and a space in front of each.
+pub fn enumerate_lines(text: &str, marker: &ListMarker) -> String {
+    let mut index = 0;
+    let lines: Vec<String> = text
+        .split('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                return line.to_string();
+            }
+            index += 1;
+            format!("{} {}", marker.format(index), line)
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test_marker {
+    use super::*;
+
+    #[test]
+    pub fn test_list_marker() {
+        assert_eq!(Some('⒈'), ListMarker::FullStop.marker(1));
+        assert_eq!(Some('⒛'), ListMarker::FullStop.marker(20));
+        assert_eq!(Some('⑴'), ListMarker::Parenthesized.marker(1));
+        assert_eq!(Some('⒇'), ListMarker::Parenthesized.marker(20));
+        assert_eq!(Some('⑳'), ListMarker::Circled.marker(20));
+        assert_eq!(Some('㉑'), ListMarker::Circled.marker(21));
+        assert_eq!(Some('㉟'), ListMarker::Circled.marker(35));
+        assert_eq!(Some('㊱'), ListMarker::Circled.marker(36));
+        assert_eq!(Some('㊿'), ListMarker::Circled.marker(50));
+        assert_eq!(None, ListMarker::Circled.marker(0));
+        assert_eq!("(21)", ListMarker::Parenthesized.format(21));
+        assert_eq!("51.", ListMarker::Circled.format(51));
+        assert_eq!(
+            "① 𝐞𝐠𝐠𝐬\n\n② 𝐦𝐢𝐥𝐤",
+            enumerate_lines("𝐞𝐠𝐠𝐬\n\n𝐦𝐢𝐥𝐤", &ListMarker::Circled)
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use unicode_general_category::get_general_category;
+
+use crate::Classification;
+
+// What the Unicode Character Database says about a character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharMetadata {
+    // "MATHEMATICAL BOLD CAPITAL A"; None for characters without a name,
+    // e.g. controls and unassigned code points
+    pub name: Option<String>,
+    // the two-letter abbreviation of the general category, e.g. "Lu"
+    pub category: &'static str,
+}
+
+impl CharMetadata {
+    pub fn of(ch: char) -> Self {
+        CharMetadata {
+            name: unicode_names2::name(ch).map(|name| name.to_string()),
+            category: get_general_category(ch).abbreviation(),
+        }
+    }
+}
+
+impl Classification {
+    // `of` together with the character's Unicode metadata
+    pub fn with_metadata(ch: char) -> (Self, CharMetadata) {
+        (Classification::of(ch), CharMetadata::of(ch))
+    }
+}
+
+#[cfg(test)]
+mod test_metadata {
+    use super::*;
+
+    #[test]
+    pub fn test_metadata() {
+        let (classification, metadata) = Classification::with_metadata('𝔞');
+        assert!(classification.is_styled());
+        assert_eq!(
+            CharMetadata {
+                name: Some("MATHEMATICAL FRAKTUR SMALL A".to_string()),
+                category: "Ll",
+            },
+            metadata
+        );
+        assert_eq!("Nd", CharMetadata::of('𝟙').category);
+        assert_eq!(None, CharMetadata::of('\u{7}').name);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{convert_pair, LetterStyle, LetterTypeError, StyledLetter};
+
+const MINUS_SIGN: char = '\u{2212}';
+const FIGURE_SPACE: char = '\u{2007}';
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberSegment<'a> {
+    // a whole number: optional sign, digits, group separators, decimal part
+    Number(&'a str),
+    Text(&'a str),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NumberOptions {
+    // write a leading hyphen-minus as U+2212 MINUS SIGN
+    pub unicode_minus: bool,
+    // write space group separators as U+2007 FIGURE SPACE, which has the width of a digit
+    pub figure_space: bool,
+}
+
+fn is_sign(ch: char) -> bool {
+    matches!(ch, '-' | '+' | MINUS_SIGN)
+}
+
+fn is_space_separator(ch: char) -> bool {
+    matches!(ch, ' ' | '\u{A0}' | '\u{2009}' | '\u{202F}' | FIGURE_SPACE)
+}
+
+// Length in chars of the number starting at `start`, or 0 if there is none.
+fn number_len(chars: &[char], start: usize) -> usize {
+    // a digit followed by U+FE0F or U+20E3 is a keycap emoji
+    let is_keycap = |idx: usize| {
+        chars
+            .get(idx)
+            .is_some_and(|ch| matches!(ch, '\u{FE0F}' | '\u{20E3}'))
+    };
+    let is_digit =
+        |idx: usize| chars.get(idx).is_some_and(|ch| ch.is_ascii_digit()) && !is_keycap(idx + 1);
+    let mut idx = start;
+    if is_sign(chars[idx]) {
+        let after_word = idx > 0 && chars[idx - 1].is_alphanumeric();
+        if after_word || !is_digit(idx + 1) {
+            return 0;
+        }
+        idx += 1;
+    }
+    if !is_digit(idx) {
+        return 0;
+    }
+    loop {
+        while is_digit(idx) {
+            idx += 1;
+        }
+        match chars.get(idx) {
+            Some(',') | Some('.') if is_digit(idx + 1) => idx += 1,
+            // "1 000 000": a space only separates groups of exactly three digits
+            Some(ch)
+                if is_space_separator(*ch)
+                    && (1..=3).all(|offset| is_digit(idx + offset))
+                    && !is_digit(idx + 4) =>
+            {
+                idx += 1
+            }
+            _ => return idx - start,
+        }
+    }
+}
+
+// Split text into numbers and the text between them.
+pub fn split_numbers(text: &str) -> Vec<NumberSegment<'_>> {
+    let chars: Vec<char> = text.chars().collect();
+    let offsets: Vec<usize> = text
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut idx = 0;
+    while idx < chars.len() {
+        let len = number_len(&chars, idx);
+        if len == 0 {
+            idx += 1;
+            continue;
+        }
+        if text_start < idx {
+            segments.push(NumberSegment::Text(
+                &text[offsets[text_start]..offsets[idx]],
+            ));
+        }
+        segments.push(NumberSegment::Number(
+            &text[offsets[idx]..offsets[idx + len]],
+        ));
+        idx += len;
+        text_start = idx;
+    }
+    if text_start < chars.len() {
+        segments.push(NumberSegment::Text(&text[offsets[text_start]..]));
+    }
+    segments
+}
+
+// Convert the digits of a number as a unit. Signs and separators are kept,
+// or replaced by their typographic forms as requested by `options`.
+pub fn convert_number(
+    number: &str,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+    options: &NumberOptions,
+) -> Result<String, LetterTypeError> {
+    convert_number_with(number, options, |ch| {
+        convert_pair(ch, letter_type, letter_style)
+    })
+}
+
+pub(crate) fn convert_number_with<F>(
+    number: &str,
+    options: &NumberOptions,
+    mut convert_digit: F,
+) -> Result<String, LetterTypeError>
+where
+    F: FnMut(char) -> Result<char, LetterTypeError>,
+{
+    let mut res = String::with_capacity(number.len() * 4);
+    for ch in number.chars() {
+        match ch {
+            '-' if options.unicode_minus => res.push(MINUS_SIGN),
+            _ if is_space_separator(ch) && options.figure_space => res.push(FIGURE_SPACE),
+            _ if ch.is_ascii_digit() => res.push(convert_digit(ch)?),
+            _ => res.push(ch),
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test_number {
+    use super::*;
+
+    #[test]
+    pub fn test_split_numbers() {
+        assert_eq!(
+            vec![
+                NumberSegment::Number("-1,234.56"),
+                NumberSegment::Text(" and "),
+                NumberSegment::Number("1 000 000"),
+                NumberSegment::Text(" items, page "),
+                NumberSegment::Number("3"),
+                NumberSegment::Text(" "),
+                NumberSegment::Number("4"),
+                NumberSegment::Text("."),
+            ],
+            split_numbers("-1,234.56 and 1 000 000 items, page 3 4.")
+        );
+        assert_eq!(
+            vec![NumberSegment::Text("x-"), NumberSegment::Number("2")],
+            split_numbers("x-2")
+        );
+        assert_eq!(
+            vec![NumberSegment::Text("no numbers")],
+            split_numbers("no numbers")
+        );
+        assert_eq!(
+            vec![
+                NumberSegment::Number("12"),
+                NumberSegment::Text(" 1\u{FE0F}\u{20E3}"),
+            ],
+            split_numbers("12 1\u{FE0F}\u{20E3}")
+        );
+        assert!(split_numbers("").is_empty());
+    }
+
+    #[test]
+    pub fn test_convert_number() {
+        let options = NumberOptions {
+            unicode_minus: true,
+            figure_space: true,
+        };
+        let res = convert_number(
+            "-1 234.5",
+            &StyledLetter::Serif,
+            &LetterStyle::Bold,
+            &options,
+        );
+        assert_eq!("\u{2212}𝟏\u{2007}𝟐𝟑𝟒.𝟓", res.unwrap());
+        let res = convert_number(
+            "-1,234",
+            &StyledLetter::Serif,
+            &LetterStyle::Bold,
+            &NumberOptions::default(),
+        );
+        assert_eq!("-𝟏,𝟐𝟑𝟒", res.unwrap());
+        // there are no italic digits
+        let res = convert_number(
+            "12",
+            &StyledLetter::Serif,
+            &LetterStyle::Italic,
+            &NumberOptions::default(),
+        );
+        assert!(res.is_err());
+    }
+}
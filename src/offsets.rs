@@ -0,0 +1,144 @@
+use std::ops::Range;
+
+use crate::{Converter, LetterTypeError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetUnit {
+    // UTF-8 bytes, as in Rust string slices
+    Byte,
+    // Unicode scalar values
+    Char,
+    // UTF-16 code units, as in JavaScript strings and LSP positions
+    Utf16,
+}
+
+// Offsets of the same positions in a text and in its conversion. Conversion
+// maps every char to one char, so char offsets are the same in both, but
+// styled letters take 4 UTF-8 bytes and 2 UTF-16 code units where ASCII
+// letters take 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetMap {
+    // (input, output) offsets of every char start and of the end
+    bytes: Vec<(usize, usize)>,
+    utf16: Vec<(usize, usize)>,
+}
+
+impl OffsetMap {
+    fn boundaries(&self, unit: &OffsetUnit) -> Option<&[(usize, usize)]> {
+        match unit {
+            OffsetUnit::Byte => Some(&self.bytes),
+            OffsetUnit::Char => None,
+            OffsetUnit::Utf16 => Some(&self.utf16),
+        }
+    }
+
+    fn char_count(&self) -> usize {
+        self.bytes.len() - 1
+    }
+
+    // Where an offset of the input is in the output. Offsets inside a char
+    // move to its start and offsets past the end are clamped to it.
+    pub fn output_offset(&self, offset: usize, unit: &OffsetUnit) -> usize {
+        match self.boundaries(unit) {
+            Some(boundaries) => {
+                let idx = boundaries.partition_point(|(input, _)| *input <= offset);
+                boundaries[idx.saturating_sub(1)].1
+            }
+            None => offset.min(self.char_count()),
+        }
+    }
+
+    // Where an offset of the output is in the input, the inverse of
+    // `output_offset`.
+    pub fn input_offset(&self, offset: usize, unit: &OffsetUnit) -> usize {
+        match self.boundaries(unit) {
+            Some(boundaries) => {
+                let idx = boundaries.partition_point(|(_, output)| *output <= offset);
+                boundaries[idx.saturating_sub(1)].0
+            }
+            None => offset.min(self.char_count()),
+        }
+    }
+
+    // e.g. a selection or the span of an entity
+    pub fn output_range(&self, range: &Range<usize>, unit: &OffsetUnit) -> Range<usize> {
+        self.output_offset(range.start, unit)..self.output_offset(range.end, unit)
+    }
+
+    pub fn input_range(&self, range: &Range<usize>, unit: &OffsetUnit) -> Range<usize> {
+        self.input_offset(range.start, unit)..self.input_offset(range.end, unit)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertedText {
+    pub output: String,
+    pub offsets: OffsetMap,
+}
+
+// Convert text like `Converter::convert_str` and record where every char
+// ended up, so editors can move cursors, selections and annotations over to
+// the output.
+pub fn convert_with_offsets(
+    text: &str,
+    converter: &Converter,
+) -> Result<ConvertedText, LetterTypeError> {
+    let mut output = String::with_capacity(text.len() * 4);
+    let mut bytes = Vec::with_capacity(text.len() + 1);
+    let mut utf16 = Vec::with_capacity(text.len() + 1);
+    let (mut input_utf16, mut output_utf16) = (0, 0);
+    converter.convert_each(text, |input_byte, ch, converted| {
+        let converted = converted?;
+        bytes.push((input_byte, output.len()));
+        utf16.push((input_utf16, output_utf16));
+        input_utf16 += ch.len_utf16();
+        output_utf16 += converted.len_utf16();
+        output.push(converted);
+        Ok(())
+    })?;
+    bytes.push((text.len(), output.len()));
+    utf16.push((input_utf16, output_utf16));
+    Ok(ConvertedText {
+        output,
+        offsets: OffsetMap { bytes, utf16 },
+    })
+}
+
+#[cfg(test)]
+mod test_offsets {
+    use super::*;
+    use crate::{LetterStyle, StyledLetter};
+
+    #[test]
+    pub fn test_convert_with_offsets() {
+        let converter = Converter::new(StyledLetter::Serif, LetterStyle::Italic);
+        let converted = convert_with_offsets("hi Bob!", &converter).unwrap();
+        assert_eq!("ℎ𝑖 𝐵𝑜𝑏!", converted.output);
+        let offsets = &converted.offsets;
+        // ℎ takes 3 bytes and 1 UTF-16 code unit, the other letters 4 and 2
+        assert_eq!(8..20, offsets.output_range(&(3..6), &OffsetUnit::Byte));
+        assert_eq!(4..10, offsets.output_range(&(3..6), &OffsetUnit::Utf16));
+        assert_eq!(3..6, offsets.output_range(&(3..6), &OffsetUnit::Char));
+        assert_eq!(3..6, offsets.input_range(&(8..20), &OffsetUnit::Byte));
+        assert_eq!(
+            "𝐵𝑜𝑏",
+            &converted.output[offsets.output_range(&(3..6), &OffsetUnit::Byte)]
+        );
+        // inside a char and past the end
+        assert_eq!(3, offsets.input_offset(9, &OffsetUnit::Byte));
+        assert_eq!(
+            converted.output.len(),
+            offsets.output_offset(99, &OffsetUnit::Byte)
+        );
+        assert_eq!(7, offsets.output_offset(99, &OffsetUnit::Char));
+
+        let converted = convert_with_offsets("é 👍🏽", &converter).unwrap();
+        assert_eq!("é 👍🏽", converted.output);
+        assert_eq!(2, converted.offsets.output_offset(2, &OffsetUnit::Byte));
+        assert!(convert_with_offsets(
+            "a",
+            &Converter::new(StyledLetter::Fraktur, LetterStyle::Italic)
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use clap::ValueEnum;
+
+use crate::grapheme::is_variation_selector;
+
+const KEYCAP: char = '\u{20E3}';
+
+// How an enclosed character or keycap should be shown: as text (VS15) or as
+// a colour emoji (VS16). Without a selector it's up to the platform.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum Presentation {
+    Text,
+    Emoji,
+}
+
+impl Presentation {
+    pub fn selector(&self) -> char {
+        match *self {
+            Presentation::Text => '\u{FE0E}',
+            Presentation::Emoji => '\u{FE0F}',
+        }
+    }
+}
+
+// U+20DD COMBINING ENCLOSING CIRCLE, U+20DE COMBINING ENCLOSING SQUARE and
+// U+20E3 COMBINING ENCLOSING KEYCAP
+fn is_enclosing(ch: char) -> bool {
+    matches!(ch, '\u{20DD}' | '\u{20DE}' | KEYCAP)
+}
+
+// Write digits, # and * as keycaps: the character, the selector, U+20E3.
+pub fn keycaps(text: &str, presentation: &Presentation) -> String {
+    let mut res = String::with_capacity(text.len() * 4);
+    for ch in text.chars() {
+        res.push(ch);
+        if ch.is_ascii_digit() || ch == '#' || ch == '*' {
+            res.push(presentation.selector());
+            res.push(KEYCAP);
+        }
+    }
+    res
+}
+
+// Give every character followed by an enclosing mark the selector of
+// `presentation`, replacing any selector it has.
+pub fn set_presentation(text: &str, presentation: &Presentation) -> String {
+    let mut res = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if is_enclosing(ch) {
+            while res.ends_with(is_variation_selector) {
+                res.pop();
+            }
+            if res
+                .chars()
+                .next_back()
+                .is_some_and(|base| !is_enclosing(base))
+            {
+                res.push(presentation.selector());
+            }
+        }
+        res.push(ch);
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_presentation {
+    use super::*;
+
+    #[test]
+    pub fn test_keycaps() {
+        assert_eq!(
+            "1\u{FE0F}\u{20E3} a#\u{FE0F}\u{20E3}",
+            keycaps("1 a#", &Presentation::Emoji)
+        );
+        assert_eq!("1\u{FE0E}\u{20E3}", keycaps("1", &Presentation::Text));
+    }
+
+    #[test]
+    pub fn test_set_presentation() {
+        assert_eq!(
+            "1\u{FE0E}\u{20E3} A\u{FE0E}\u{20DD} b",
+            set_presentation("1\u{FE0F}\u{20E3} A\u{20DD} b", &Presentation::Text)
+        );
+        assert_eq!(
+            "1\u{FE0F}\u{20E3}",
+            set_presentation("1\u{FE0F}\u{20E3}", &Presentation::Emoji)
+        );
+    }
+}
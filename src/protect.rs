@@ -0,0 +1,86 @@
+use regex::Regex;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtectedSegment<'a> {
+    // a match of one of the patterns, to be kept byte for byte
+    Protected(&'a str),
+    Text(&'a str),
+}
+
+// Split text at the matches of any of `patterns`, e.g. ticket IDs, order
+// numbers or email addresses that must come out exactly as they went in.
+// Overlapping matches of different patterns are merged, empty ones ignored.
+pub fn split_protected<'a>(text: &'a str, patterns: &[Regex]) -> Vec<ProtectedSegment<'a>> {
+    let mut ranges: Vec<(usize, usize)> = patterns
+        .iter()
+        .flat_map(|pattern| pattern.find_iter(text))
+        .filter(|found| !found.is_empty())
+        .map(|found| (found.start(), found.end()))
+        .collect();
+    ranges.sort_unstable();
+    let mut segments = Vec::new();
+    let mut last = 0;
+    let mut protected: Option<(usize, usize)> = None;
+    for (start, end) in ranges {
+        match protected {
+            Some((_, ref mut protected_end)) if start <= *protected_end => {
+                *protected_end = (*protected_end).max(end);
+                continue;
+            }
+            Some((protected_start, protected_end)) => {
+                segments.push(ProtectedSegment::Protected(
+                    &text[protected_start..protected_end],
+                ));
+                last = protected_end;
+            }
+            None => {}
+        }
+        if last < start {
+            segments.push(ProtectedSegment::Text(&text[last..start]));
+        }
+        protected = Some((start, end));
+    }
+    if let Some((start, end)) = protected {
+        segments.push(ProtectedSegment::Protected(&text[start..end]));
+        last = end;
+    }
+    if last < text.len() {
+        segments.push(ProtectedSegment::Text(&text[last..]));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod test_protect {
+    use super::*;
+
+    #[test]
+    pub fn test_split_protected() {
+        let patterns = [
+            Regex::new(r"[A-Z]+-\d+").unwrap(),
+            Regex::new(r"\S+@\S+").unwrap(),
+            Regex::new(r"\d+").unwrap(),
+        ];
+        assert_eq!(
+            vec![
+                ProtectedSegment::Text("see "),
+                ProtectedSegment::Protected("ABC-12"),
+                ProtectedSegment::Text(" or mail "),
+                ProtectedSegment::Protected("me@x.io"),
+            ],
+            split_protected("see ABC-12 or mail me@x.io", &patterns)
+        );
+        assert_eq!(
+            vec![
+                ProtectedSegment::Protected("42"),
+                ProtectedSegment::Text(" items"),
+            ],
+            split_protected("42 items", &patterns)
+        );
+        assert_eq!(
+            vec![ProtectedSegment::Text("plain")],
+            split_protected("plain", &[Regex::new("x*").unwrap()])
+        );
+        assert!(split_protected("", &patterns).is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+use rand_core::RngCore;
+
+use crate::{
+    converter::walk_chars, export::combinations, Converter, LetterStyle, StyleSpec, StyledLetter,
+};
+
+// a uniform index below `len`; the bias of multiply-shift is negligible for
+// the few choices there are
+fn pick<R: RngCore + ?Sized>(rng: &mut R, len: usize) -> usize {
+    ((rng.next_u32() as u64 * len as u64) >> 32) as usize
+}
+
+// Every type and style combination except the excluded types and styles,
+// to pass to `convert_random`.
+pub fn random_choices(
+    exclude_types: &[StyledLetter],
+    exclude_styles: &[LetterStyle],
+) -> Vec<StyleSpec> {
+    combinations()
+        .filter(|(letter_type, letter_style)| {
+            !exclude_types.contains(letter_type) && !exclude_styles.contains(letter_style)
+        })
+        .map(|(letter_type, letter_style)| StyleSpec::new(*letter_type, *letter_style))
+        .collect()
+}
+
+// Convert every character with one of `choices` picked at random among
+// those that have it, e.g. converters for the specs of `random_choices`;
+// characters none of them has are kept. The randomness comes from `rng`
+// only, so a seeded RNG always gives the same output.
+pub fn convert_random<R: RngCore + ?Sized>(
+    text: &str,
+    choices: &[Converter],
+    rng: &mut R,
+) -> String {
+    let mut res = String::with_capacity(text.len() * 4);
+    let mut candidates = Vec::with_capacity(choices.len());
+    for (_, ch, emoji) in walk_chars(text) {
+        if emoji {
+            res.push(ch);
+            continue;
+        }
+        candidates.clear();
+        candidates.extend(
+            choices
+                .iter()
+                .filter_map(|converter| converter.convert_char(ch).ok()),
+        );
+        match candidates.len() {
+            0 => res.push(ch),
+            len => res.push(candidates[pick(rng, len)]),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_random {
+    use super::*;
+    use crate::destyle;
+
+    // a xorshift generator, enough to check determinism
+    struct TestRng(u64);
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst)
+        }
+    }
+
+    #[test]
+    pub fn test_convert_random() {
+        let choices = random_choices(&[StyledLetter::Serif], &[LetterStyle::Italic]);
+        assert!(!choices.contains(&StyleSpec::new(StyledLetter::Script, LetterStyle::Italic)));
+        assert!(choices.contains(&StyleSpec::new(StyledLetter::Script, LetterStyle::Bold)));
+        let choices: Vec<Converter> = choices
+            .iter()
+            .map(|spec| Converter::new(spec.letter_type, spec.letter_style))
+            .collect();
+        let text = "Hello, world 42 👍🏽";
+        let res = convert_random(text, &choices, &mut TestRng(42));
+        assert_eq!(res, convert_random(text, &choices, &mut TestRng(42)));
+        assert_ne!(text, res);
+        assert!(res.ends_with(" 👍🏽"));
+        assert_eq!(text, res.chars().map(destyle).collect::<String>());
+        // a single choice is a plain conversion
+        let rng: &mut dyn RngCore = &mut TestRng(7);
+        assert_eq!(
+            "𝔥𝔦!",
+            convert_random(
+                "hi!",
+                &[Converter::new(StyledLetter::Fraktur, LetterStyle::Normal)],
+                rng
+            )
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{document::render_text, Decoration, LetterStyle, Run, StyledDocument, StyledLetter};
+
+// Turns a document into text for some output. Backends express what their
+// format supports natively and fall back to styled Unicode for the rest.
+pub trait Render {
+    fn render_run(&self, run: &Run) -> String;
+
+    fn render(&self, document: &StyledDocument) -> String {
+        document
+            .runs
+            .iter()
+            .map(|run| self.render_run(run))
+            .collect()
+    }
+}
+
+// Mathematical alphanumerics and combining marks, as the converter outputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnicodeRenderer;
+
+impl Render for UnicodeRenderer {
+    fn render_run(&self, run: &Run) -> String {
+        render_text(
+            &run.text,
+            &run.letter_type,
+            &run.letter_style,
+            &run.decorations,
+        )
+    }
+}
+
+// Serif bold and italic as the emphasis of the format, other fonts keep
+// their style in Unicode.
+fn emphasis(run: &Run) -> (bool, bool, LetterStyle) {
+    match (&run.letter_type, &run.letter_style) {
+        (StyledLetter::Serif, LetterStyle::Bold) => (true, false, LetterStyle::Normal),
+        (StyledLetter::Serif, LetterStyle::Italic) => (false, true, LetterStyle::Normal),
+        (StyledLetter::Serif, LetterStyle::BoldItalic) => (true, true, LetterStyle::Normal),
+        (_, letter_style) => (false, false, *letter_style),
+    }
+}
+
+// Terminal text with SGR escape sequences.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnsiRenderer;
+
+impl Render for AnsiRenderer {
+    fn render_run(&self, run: &Run) -> String {
+        let (bold, italic, letter_style) = emphasis(run);
+        let mut codes = Vec::new();
+        if bold {
+            codes.push("1");
+        }
+        if italic {
+            codes.push("3");
+        }
+        let mut marks = Vec::new();
+        for decoration in run.decorations.iter() {
+            match decoration {
+                Decoration::Underline => codes.push("4"),
+                Decoration::DoubleUnderline => codes.push("21"),
+                Decoration::Strikethrough => codes.push("9"),
+                Decoration::Overline => codes.push("53"),
+                decoration => marks.push(*decoration),
+            }
+        }
+        let text = render_text(&run.text, &run.letter_type, &letter_style, &marks);
+        if codes.is_empty() {
+            text
+        } else {
+            format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+        }
+    }
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            ch => res.push(ch),
+        }
+    }
+    res
+}
+
+// HTML with inline elements, ready to be put inside a block element.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlRenderer;
+
+impl Render for HtmlRenderer {
+    fn render_run(&self, run: &Run) -> String {
+        let (bold, italic, letter_style) = emphasis(run);
+        // (open, close) from the outermost element in
+        let mut tags = Vec::new();
+        if bold {
+            tags.push(("<b>", "</b>"));
+        }
+        if italic {
+            tags.push(("<i>", "</i>"));
+        }
+        let mut marks = Vec::new();
+        for decoration in run.decorations.iter() {
+            match decoration {
+                Decoration::Underline => tags.push(("<u>", "</u>")),
+                Decoration::Strikethrough => tags.push(("<s>", "</s>")),
+                Decoration::Overline => {
+                    tags.push(("<span style=\"text-decoration: overline\">", "</span>"))
+                }
+                decoration => marks.push(*decoration),
+            }
+        }
+        let mut letter_type = run.letter_type;
+        if letter_type == StyledLetter::MonoSpace && letter_style == LetterStyle::Normal {
+            tags.push(("<code>", "</code>"));
+            letter_type = StyledLetter::Serif;
+        }
+        let mut res = String::new();
+        tags.iter().for_each(|(open, _)| res.push_str(open));
+        res.push_str(&escape_html(&render_text(
+            &run.text,
+            &letter_type,
+            &letter_style,
+            &marks,
+        )));
+        tags.iter().rev().for_each(|(_, close)| res.push_str(close));
+        res
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if "\\`*_~[]<>#".contains(ch) {
+            res.push('\\');
+        }
+        res.push(ch);
+    }
+    res
+}
+
+// Markdown with GitHub's strikethrough. Emphasis can't start or end with
+// whitespace, so surrounding whitespace stays outside the markers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownRenderer;
+
+impl Render for MarkdownRenderer {
+    fn render_run(&self, run: &Run) -> String {
+        let (bold, italic, letter_style) = emphasis(run);
+        let mut marker = String::new();
+        if bold {
+            marker.push_str("**");
+        }
+        if italic {
+            marker.push('*');
+        }
+        let mut marks = Vec::new();
+        for decoration in run.decorations.iter() {
+            match decoration {
+                Decoration::Strikethrough => marker.insert_str(0, "~~"),
+                decoration => marks.push(*decoration),
+            }
+        }
+        let inner = run.text.trim_start();
+        let leading = &run.text[..run.text.len() - inner.len()];
+        let inner = inner.trim_end();
+        let trailing = &run.text[leading.len() + inner.len()..];
+        let text = if run.letter_type == StyledLetter::MonoSpace
+            && letter_style == LetterStyle::Normal
+            && !inner.contains('`')
+            && !inner.is_empty()
+        {
+            format!(
+                "`{}`",
+                render_text(inner, &StyledLetter::Serif, &letter_style, &marks)
+            )
+        } else {
+            escape_markdown(&render_text(inner, &run.letter_type, &letter_style, &marks))
+        };
+        if marker.is_empty() || inner.is_empty() {
+            return format!("{}{}{}", leading, text, trailing);
+        }
+        let closing: String = marker.chars().rev().collect();
+        format!("{}{}{}{}{}", leading, marker, text, closing, trailing)
+    }
+}
+
+#[cfg(test)]
+mod test_render {
+    use super::*;
+
+    #[test]
+    pub fn test_render() {
+        let document = StyledDocument::new(vec![
+            Run::new("a <b> ", StyledLetter::Serif, LetterStyle::Bold),
+            Run::new("cd", StyledLetter::Fraktur, LetterStyle::Normal)
+                .with_decorations(vec![Decoration::Strikethrough]),
+            Run::new(" ls", StyledLetter::MonoSpace, LetterStyle::Normal),
+        ]);
+        assert_eq!(
+            "𝐚 <𝐛> 𝔠\u{336}𝔡\u{336} 𝚕𝚜",
+            UnicodeRenderer.render(&document)
+        );
+        assert_eq!(
+            "\x1b[1ma <b> \x1b[0m\x1b[9m𝔠𝔡\x1b[0m 𝚕𝚜",
+            AnsiRenderer.render(&document)
+        );
+        assert_eq!(
+            "<b>a &lt;b&gt; </b><s>𝔠𝔡</s><code> ls</code>",
+            HtmlRenderer.render(&document)
+        );
+        assert_eq!(
+            "**a \\<b\\>** ~~𝔠𝔡~~ `ls`",
+            MarkdownRenderer.render(&document)
+        );
+    }
+}
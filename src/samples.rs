@@ -0,0 +1,138 @@
+use clap::ValueEnum;
+
+use crate::{
+    convert_pair, export::combinations, format_table, letter::value_name, render::escape_html,
+    LetterStyle, StyledLetter,
+};
+
+pub const PANGRAM: &str = "The quick brown fox jumps over the lazy dog 0123456789";
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum SampleFormat {
+    Text,
+    Markdown,
+    Html,
+}
+
+impl StyledLetter {
+    // "Aa1" in this font and `letter_style`, pre-rendered for pickers to
+    // preview a style without converting anything. The digit comes from the
+    // default fallback chain where the style has none. Empty for styles
+    // without letters, e.g. fraktur italic.
+    pub fn sample(&self, letter_style: &LetterStyle) -> &'static str {
+        match (self, letter_style) {
+            (StyledLetter::Serif, LetterStyle::Normal) => "Aa1",
+            (StyledLetter::Serif, LetterStyle::Bold) => "𝐀𝐚𝟏",
+            (StyledLetter::Serif, LetterStyle::Italic) => "𝐴𝑎1",
+            (StyledLetter::Serif, LetterStyle::BoldItalic) => "𝑨𝒂𝟏",
+            (StyledLetter::SansSerif, LetterStyle::Normal) => "𝖠𝖺𝟣",
+            (StyledLetter::SansSerif, LetterStyle::Bold) => "𝗔𝗮𝟭",
+            (StyledLetter::SansSerif, LetterStyle::Italic) => "𝘈𝘢𝟣",
+            (StyledLetter::SansSerif, LetterStyle::BoldItalic) => "𝘼𝙖𝟭",
+            (StyledLetter::Script, LetterStyle::Normal) => "𝒜𝒶1",
+            (StyledLetter::Script, LetterStyle::Bold) => "𝓐𝓪𝟏",
+            (StyledLetter::Fraktur, LetterStyle::Normal) => "𝔄𝔞1",
+            (StyledLetter::Fraktur, LetterStyle::Bold) => "𝕬𝖆𝟏",
+            (StyledLetter::MonoSpace, LetterStyle::Normal) => "𝙰𝚊𝟷",
+            (StyledLetter::DoubleStruck, LetterStyle::Bold) => "𝔸𝕒𝟙",
+            _ => "",
+        }
+    }
+}
+
+// `text` in every font and style that has letters, as (font, style, sample)
+pub fn sample_rows(text: &str) -> Vec<[String; 3]> {
+    combinations()
+        .filter(|(letter_type, letter_style)| convert_pair('A', letter_type, letter_style).is_ok())
+        .map(|(letter_type, letter_style)| {
+            let sample = text
+                .chars()
+                .map(|ch| convert_pair(ch, letter_type, letter_style).unwrap_or(ch))
+                .collect();
+            [value_name(letter_type), value_name(letter_style), sample]
+        })
+        .collect()
+}
+
+// A sheet of `text` in every font and style, e.g. to check which of them a
+// font renders or to put on a documentation site.
+pub fn samples(text: &str, format: SampleFormat) -> String {
+    let rows = sample_rows(text);
+    match format {
+        SampleFormat::Text => {
+            let rows: Vec<Vec<String>> = rows.into_iter().map(Vec::from).collect();
+            format_table(&["FONT", "STYLE", "SAMPLE"], &rows)
+        }
+        SampleFormat::Markdown => {
+            let mut res = "| font | style | sample |\n|---|---|---|".to_string();
+            for [font, style, sample] in rows {
+                res.push_str(&format!(
+                    "\n| {} | {} | {} |",
+                    font,
+                    style,
+                    sample.replace('|', "\\|")
+                ));
+            }
+            res
+        }
+        SampleFormat::Html => {
+            let mut res =
+                "<table>\n<tr><th>font</th><th>style</th><th>sample</th></tr>".to_string();
+            for [font, style, sample] in rows {
+                res.push_str(&format!(
+                    "\n<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    font,
+                    style,
+                    escape_html(&sample)
+                ));
+            }
+            res.push_str("\n</table>");
+            res
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_samples {
+    use super::*;
+    use crate::Converter;
+
+    #[test]
+    pub fn test_sample() {
+        assert_eq!("𝓐𝓪𝟏", StyledLetter::Script.sample(&LetterStyle::Bold));
+        assert_eq!("", StyledLetter::Fraktur.sample(&LetterStyle::Italic));
+        // the table matches what the converter makes of "Aa1"
+        for (letter_type, letter_style) in combinations() {
+            let expected = if convert_pair('A', letter_type, letter_style).is_ok() {
+                let converter =
+                    Converter::new(*letter_type, *letter_style).with_default_fallback_chain();
+                "Aa1"
+                    .chars()
+                    .map(|ch| converter.convert_char(ch).unwrap())
+                    .collect()
+            } else {
+                String::new()
+            };
+            assert_eq!(expected, letter_type.sample(letter_style));
+        }
+    }
+
+    #[test]
+    pub fn test_samples() {
+        let rows = sample_rows("Ab|1");
+        assert_eq!(
+            ["serif".to_string(), "bold".to_string(), "𝐀𝐛|𝟏".to_string()],
+            rows[1]
+        );
+        assert!(!rows
+            .iter()
+            .any(|row| row[0] == "fraktur" && row[1] == "italic"));
+        let markdown = samples("Ab|1", SampleFormat::Markdown);
+        assert!(markdown
+            .starts_with("| font | style | sample |\n|---|---|---|\n| serif | normal | Ab\\|1 |"));
+        let html = samples("a<b", SampleFormat::Html);
+        assert!(html.contains("<tr><td>serif</td><td>bold</td><td>𝐚&lt;𝐛</td></tr>"));
+        assert!(samples("Ab", SampleFormat::Text).starts_with("FONT"));
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+use crate::{destyle, Decoration};
+
+// More marks than any real orthography stacks on one letter (polytonic Greek
+// goes up to three, e.g. ᾄ) is "Zalgo" spam.
+const MAX_MARKS_PER_LETTER: usize = 3;
+
+// Combining Diacritical Marks and their supplements and extensions
+pub(crate) fn is_combining(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF | 0xFE20..=0xFE2F
+    )
+}
+
+// Tags block, invisible characters once meant for language tags
+pub(crate) fn is_tag(ch: char) -> bool {
+    matches!(ch as u32, 0xE0000..=0xE007F)
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SanitizeReport {
+    // math alphanumerics folded to plain letters and digits
+    pub folded: usize,
+    // decoration marks (underline, strikethrough, ...)
+    pub decorations: usize,
+    // combining marks beyond `MAX_MARKS_PER_LETTER` or without a base letter
+    pub excess_marks: usize,
+    // invisible tag characters
+    pub tags: usize,
+}
+
+impl SanitizeReport {
+    pub fn is_clean(&self) -> bool {
+        *self == SanitizeReport::default()
+    }
+}
+
+impl Display for SanitizeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "folded {} styled characters, removed {} decoration marks, {} excess combining marks and {} tag characters",
+            self.folded, self.decorations, self.excess_marks, self.tags
+        )
+    }
+}
+
+// Clean untrusted text: fold styled letters back to plain text, drop
+// decoration marks, combining mark spam and tag characters. Ordinary
+// accents are kept.
+pub fn sanitize(text: &str) -> (String, SanitizeReport) {
+    let mut report = SanitizeReport::default();
+    let mut res = String::with_capacity(text.len());
+    // None before the first base character
+    let mut marks: Option<usize> = None;
+    for ch in text.chars() {
+        if is_tag(ch) {
+            report.tags += 1;
+        } else if Decoration::from_mark(ch).is_some() {
+            report.decorations += 1;
+        } else if is_combining(ch) {
+            match marks {
+                Some(count) if count < MAX_MARKS_PER_LETTER => {
+                    res.push(ch);
+                    marks = Some(count + 1);
+                }
+                _ => report.excess_marks += 1,
+            }
+        } else {
+            let plain = destyle(ch);
+            if plain != ch {
+                report.folded += 1;
+            }
+            res.push(plain);
+            marks = Some(0);
+        }
+    }
+    (res, report)
+}
+
+#[cfg(test)]
+mod test_sanitize {
+    use super::*;
+
+    #[test]
+    pub fn test_sanitize() {
+        let (text, report) = sanitize("𝐅𝐫𝐞𝐞 g\u{336}i\u{336}f\u{336}t\u{336} c\u{301}afe\u{301}");
+        assert_eq!("Free gift c\u{301}afe\u{301}", text);
+        assert_eq!(
+            SanitizeReport {
+                folded: 4,
+                decorations: 4,
+                excess_marks: 0,
+                tags: 0,
+            },
+            report
+        );
+        let zalgo = "z\u{30D}\u{30E}\u{304}\u{305}\u{33F}\u{311}a";
+        let (text, report) = sanitize(zalgo);
+        assert_eq!("z\u{30D}\u{30E}\u{304}a", text);
+        assert_eq!(2, report.decorations);
+        assert_eq!(1, report.excess_marks);
+        let (text, report) = sanitize("hi\u{E0068}\u{E0069}\u{E007F}");
+        assert_eq!("hi", text);
+        assert_eq!(3, report.tags);
+        assert!(sanitize("plain").1.is_clean());
+    }
+}
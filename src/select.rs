@@ -0,0 +1,222 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    converter::walk_chars, destyle, CharacterInfo, CharacterType, Converter, LetterStyle,
+    LetterTypeError, StyledLetter,
+};
+
+// Convert only the words accepted by `predicate`. Whitespace and punctuation
+// between words are never passed to the predicate and are kept as is, so the
+// output lines up with the input word by word.
+pub fn convert_words_if<F>(
+    text: &str,
+    mut predicate: F,
+    converter: &Converter,
+) -> Result<String, LetterTypeError>
+where
+    F: FnMut(&str) -> bool,
+{
+    let mut res = String::with_capacity(text.len());
+    for segment in text.split_word_bounds() {
+        let is_word = segment.chars().any(char::is_alphanumeric);
+        if is_word && predicate(segment) {
+            res.push_str(&converter.convert_str(segment)?);
+        } else {
+            res.push_str(segment);
+        }
+    }
+    Ok(res)
+}
+
+// Per-character variant of `convert_words_if`.
+pub fn convert_chars_if<F>(
+    text: &str,
+    mut predicate: F,
+    converter: &Converter,
+) -> Result<String, LetterTypeError>
+where
+    F: FnMut(char) -> bool,
+{
+    let mut res = String::with_capacity(text.len());
+    for (_, ch, emoji) in walk_chars(text) {
+        if !emoji && predicate(ch) {
+            res.push(converter.convert_char(ch)?);
+        } else {
+            res.push(ch);
+        }
+    }
+    Ok(res)
+}
+
+// What `convert_with` knows about a character when asking how to style it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Classification {
+    pub class: CharacterType,
+    // the font and style the character is already in; None for characters
+    // that have no styled forms
+    pub style: Option<(StyledLetter, LetterStyle)>,
+    pub uppercase: bool,
+}
+
+impl Classification {
+    pub fn of(ch: char) -> Self {
+        match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => Self {
+                class: *info.character_type(),
+                style: Some((*info.letter_type(), *info.letter_style())),
+                uppercase: info.uppercase(),
+            },
+            CharacterInfo::Other(_) => Self {
+                class: CharacterType::Other,
+                style: None,
+                uppercase: false,
+            },
+        }
+    }
+
+    // in a font and style other than plain serif normal
+    pub fn is_styled(&self) -> bool {
+        !matches!(
+            self.style,
+            None | Some((StyledLetter::Serif, LetterStyle::Normal))
+        )
+    }
+}
+
+// Whether the text has a styled character in one of `fonts`, or in any font
+// when `fonts` is empty.
+pub fn contains_styled(text: &str, fonts: &[StyledLetter]) -> bool {
+    text.chars().map(Classification::of).any(|classification| {
+        classification.is_styled()
+            && classification
+                .style
+                .is_some_and(|(font, _)| fonts.is_empty() || fonts.contains(&font))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StyleDecision {
+    // leave the character as it is
+    Keep,
+    // de-style it
+    Plain,
+    Style(StyledLetter, LetterStyle),
+}
+
+// Style each character as `decide` says, with the Greek variant policy and
+// fallbacks of `converter` whatever font and style it picks. Like the other
+// conversions, emoji sequences are kept whole and never passed to `decide`.
+pub fn convert_with<F>(
+    text: &str,
+    converter: &Converter,
+    mut decide: F,
+) -> Result<String, LetterTypeError>
+where
+    F: FnMut(char, &Classification) -> StyleDecision,
+{
+    let mut res = String::with_capacity(text.len());
+    // the converters for the fonts and styles picked so far
+    let mut converters: Vec<Converter> = Vec::new();
+    for (_, ch, emoji) in walk_chars(text) {
+        if emoji {
+            res.push(ch);
+            continue;
+        }
+        match decide(ch, &Classification::of(ch)) {
+            StyleDecision::Keep => res.push(ch),
+            StyleDecision::Plain => res.push(destyle(ch)),
+            StyleDecision::Style(letter_type, letter_style) => {
+                let idx = match converters.iter().position(|picked| {
+                    *picked.letter_type() == letter_type && *picked.letter_style() == letter_style
+                }) {
+                    Some(idx) => idx,
+                    None => {
+                        converters.push(converter.restyled(letter_type, letter_style));
+                        converters.len() - 1
+                    }
+                };
+                res.push(converters[idx].convert_char(ch)?);
+            }
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test_select {
+    use super::*;
+    use crate::{GreekVariantChoice, GreekVariantPolicy};
+
+    #[test]
+    pub fn test_convert_words_if() {
+        let glossary = ["rust", "cargo"];
+        let res = convert_words_if(
+            "Build rust code with cargo, not make.",
+            |word| glossary.contains(&word),
+            &Converter::new(StyledLetter::Serif, LetterStyle::Bold),
+        );
+        assert_eq!("Build 𝐫𝐮𝐬𝐭 code with 𝐜𝐚𝐫𝐠𝐨, not make.", res.unwrap());
+    }
+
+    #[test]
+    pub fn test_convert_chars_if() {
+        let res = convert_chars_if(
+            "hello",
+            |ch| "aeiou".contains(ch),
+            &Converter::new(StyledLetter::Serif, LetterStyle::Bold),
+        );
+        assert_eq!("h𝐞ll𝐨", res.unwrap());
+        // the converter's Greek variant policy applies
+        let symbol = Converter::new(StyledLetter::Serif, LetterStyle::Bold)
+            .with_greek_variants(GreekVariantPolicy::new(GreekVariantChoice::Symbol));
+        let res = convert_chars_if("θ1️⃣", |_| true, &symbol);
+        assert_eq!("𝛝1️⃣", res.unwrap());
+    }
+
+    #[test]
+    pub fn test_convert_with() {
+        let converter = Converter::new(StyledLetter::Serif, LetterStyle::Normal);
+        let res = convert_with(
+            "𝐀bc, 12!",
+            &converter,
+            |ch, classification| match classification.class {
+                CharacterType::Digit => {
+                    StyleDecision::Style(StyledLetter::DoubleStruck, LetterStyle::Normal)
+                }
+                CharacterType::Letter if classification.uppercase => StyleDecision::Plain,
+                CharacterType::Letter if ch == 'b' => StyleDecision::Keep,
+                CharacterType::Letter => {
+                    StyleDecision::Style(StyledLetter::Script, LetterStyle::Bold)
+                }
+                _ => StyleDecision::Keep,
+            },
+        );
+        assert_eq!("Ab𝓬, 𝟙𝟚!", res.unwrap());
+        assert!(convert_with("a", &converter, |_, _| StyleDecision::Style(
+            StyledLetter::Fraktur,
+            LetterStyle::Italic
+        ))
+        .is_err());
+        // fallbacks apply to the picked font and style
+        let fallback = converter.with_fallback(CharacterType::Letter, vec![LetterStyle::Bold]);
+        let res = convert_with("a", &fallback, |_, _| {
+            StyleDecision::Style(StyledLetter::Fraktur, LetterStyle::Italic)
+        });
+        assert_eq!("𝖆", res.unwrap());
+        assert_eq!(
+            Some((StyledLetter::Serif, LetterStyle::Bold)),
+            Classification::of('𝐀').style
+        );
+    }
+
+    #[test]
+    pub fn test_contains_styled() {
+        assert!(Classification::of('𝐀').is_styled());
+        assert!(!Classification::of('A').is_styled());
+        assert!(!Classification::of('!').is_styled());
+        assert!(contains_styled("plain 𝔣", &[]));
+        assert!(!contains_styled("plain α 1", &[]));
+        assert!(contains_styled("plain 𝔣", &[StyledLetter::Fraktur]));
+        assert!(!contains_styled("plain 𝔣", &[StyledLetter::Script]));
+    }
+}
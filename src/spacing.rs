@@ -0,0 +1,132 @@
+use std::{fmt::Display, str::FromStr};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+// U+2009 THIN SPACE
+const THIN_SPACE: char = '\u{2009}';
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Spacing {
+    None,
+    Thin,
+    Char(char),
+}
+
+impl Spacing {
+    pub fn separator(&self) -> Option<char> {
+        match *self {
+            Spacing::None => None,
+            Spacing::Thin => Some(THIN_SPACE),
+            Spacing::Char(ch) => Some(ch),
+        }
+    }
+}
+
+impl FromStr for Spacing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Spacing::None),
+            "thin" => Ok(Spacing::Thin),
+            "space" => Ok(Spacing::Char(' ')),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => Ok(Spacing::Char(ch)),
+                    _ => Err(format!(
+                        "invalid spacing '{}', expected none, thin, space or a single character",
+                        s
+                    )),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Spacing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Spacing::None => f.write_str("none"),
+            Spacing::Thin => f.write_str("thin"),
+            Spacing::Char(' ') => f.write_str("space"),
+            Spacing::Char(ch) => write!(f, "{}", ch),
+        }
+    }
+}
+
+// "aesthetic" -> "a e s t h e t i c". Separators go between grapheme
+// clusters, so accents, decorations and emoji sequences stay in one piece.
+pub fn add_spacing(text: &str, spacing: &Spacing) -> String {
+    let sep = match spacing.separator() {
+        Some(sep) => sep,
+        None => return text.to_string(),
+    };
+    let mut res = String::with_capacity(text.len() * 2);
+    for (idx, grapheme) in text.graphemes(true).enumerate() {
+        if idx > 0 {
+            res.push(sep);
+        }
+        res.push_str(grapheme);
+    }
+    res
+}
+
+// Inverse of `add_spacing`: every character is followed by at most one separator,
+// so an original separator character (e.g. a space between words) survives.
+pub fn remove_spacing(text: &str, spacing: &Spacing) -> String {
+    let sep = match spacing.separator() {
+        Some(sep) => sep,
+        None => return text.to_string(),
+    };
+    let mut res = String::with_capacity(text.len());
+    let sep = sep.to_string();
+    let mut graphemes = text.graphemes(true).peekable();
+    while let Some(grapheme) = graphemes.next() {
+        res.push_str(grapheme);
+        if graphemes.peek() == Some(&sep.as_str()) {
+            graphemes.next();
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_spacing {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_spacing() {
+        assert_eq!(Spacing::None, "none".parse().unwrap());
+        assert_eq!(Spacing::Thin, "thin".parse().unwrap());
+        assert_eq!(Spacing::Char(' '), "space".parse().unwrap());
+        assert_eq!(Spacing::Char('·'), "·".parse().unwrap());
+        assert!("ab".parse::<Spacing>().is_err());
+    }
+
+    #[test]
+    pub fn test_spacing_round_trip() {
+        for spacing in [Spacing::Thin, Spacing::Char(' '), Spacing::Char('.')] {
+            for text in [
+                "aesthetic",
+                "hello world",
+                "a  b",
+                "𝐚𝐛𝐜",
+                "",
+                "a\u{332}👩‍💻1️⃣",
+            ] {
+                let spaced = add_spacing(text, &spacing);
+                assert_eq!(text, remove_spacing(&spaced, &spacing));
+            }
+        }
+        assert_eq!(
+            "a e s t h e t i c",
+            add_spacing("aesthetic", &Spacing::Char(' '))
+        );
+        assert_eq!("abc", add_spacing("abc", &Spacing::None));
+        assert_eq!(
+            "a\u{332} 👩‍💻 🇯🇵",
+            add_spacing("a\u{332}👩‍💻🇯🇵", &Spacing::Char(' '))
+        );
+    }
+}
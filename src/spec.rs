@@ -0,0 +1,99 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{letter::value_name, LetterStyle, StyledLetter};
+
+// A letter type and style written as one value: "serif-bold-italic",
+// "serif.bold", "script" (normal style). Either part may use aliases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleSpec {
+    pub letter_type: StyledLetter,
+    pub letter_style: LetterStyle,
+}
+
+impl StyleSpec {
+    pub fn new(letter_type: StyledLetter, letter_style: LetterStyle) -> Self {
+        Self {
+            letter_type,
+            letter_style,
+        }
+    }
+}
+
+fn is_separator(ch: char) -> bool {
+    matches!(ch, '-' | '.' | ':' | '_')
+}
+
+impl FromStr for StyleSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(letter_type) = s.parse() {
+            return Ok(StyleSpec::new(letter_type, LetterStyle::Normal));
+        }
+        // names contain separators themselves ("sans-serif", "bold-italic"),
+        // so try every split point
+        for (idx, ch) in s.char_indices().filter(|(_, ch)| is_separator(*ch)) {
+            let (letter_type, letter_style) = (&s[..idx], &s[idx + ch.len_utf8()..]);
+            if let (Ok(letter_type), Ok(letter_style)) = (letter_type.parse(), letter_style.parse())
+            {
+                return Ok(StyleSpec::new(letter_type, letter_style));
+            }
+        }
+        // report the part that doesn't parse, with suggestions
+        let (letter_type, letter_style) = match s.find(is_separator) {
+            Some(idx) => (&s[..idx], &s[idx + 1..]),
+            None => (s, ""),
+        };
+        letter_type.parse::<StyledLetter>()?;
+        letter_style.parse::<LetterStyle>()?;
+        Err(format!(
+            "invalid style spec '{}', expected <type>[-<style>], e.g. serif-bold-italic",
+            s
+        ))
+    }
+}
+
+impl Display for StyleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}",
+            value_name(&self.letter_type),
+            value_name(&self.letter_style)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_spec {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_style_spec() {
+        let spec = |letter_type, letter_style| Ok(StyleSpec::new(letter_type, letter_style));
+        assert_eq!(
+            spec(StyledLetter::Serif, LetterStyle::BoldItalic),
+            "serif-bold-italic".parse()
+        );
+        assert_eq!(
+            spec(StyledLetter::Serif, LetterStyle::Bold),
+            "serif.bold".parse()
+        );
+        assert_eq!(
+            spec(StyledLetter::SansSerif, LetterStyle::Italic),
+            "sans-serif-italic".parse()
+        );
+        assert_eq!(
+            spec(StyledLetter::Script, LetterStyle::Normal),
+            "cal".parse()
+        );
+        assert_eq!(
+            Err("unknown letter style 'bld', did you mean 'bold'?".to_string()),
+            "fraktur-bld".parse::<StyleSpec>()
+        );
+        assert!("comic-bold".parse::<StyleSpec>().is_err());
+        let spec = StyleSpec::new(StyledLetter::DoubleStruck, LetterStyle::BoldItalic);
+        assert_eq!("doublestruck-bolditalic", spec.to_string());
+        assert_eq!(Ok(spec.clone()), spec.to_string().parse());
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::Display;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{destyle, CharacterInfo, LetterStyle, StyledLetter};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpoofKind {
+    // math alphanumeric letters or digits
+    Styled,
+    // Latin mixed with Cyrillic or Greek letters that look like Latin ones
+    MixedScript,
+}
+
+impl Display for SpoofKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SpoofKind::Styled => f.write_str("styled"),
+            SpoofKind::MixedScript => f.write_str("mixed-script"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpoofFinding {
+    // 1-based line and column (in chars) of the word
+    pub line: usize,
+    pub column: usize,
+    pub kind: SpoofKind,
+    pub text: String,
+    // the word with styled letters and lookalikes replaced by plain Latin
+    pub suggestion: String,
+}
+
+// Cyrillic and Greek letters that are rendered like a Latin letter in most
+// fonts.
+pub(crate) fn latin_lookalike(ch: char) -> Option<char> {
+    let latin = match ch {
+        // Cyrillic
+        'а' => 'a',
+        'е' => 'e',
+        'і' => 'i',
+        'ј' => 'j',
+        'о' => 'o',
+        'р' => 'p',
+        'с' => 'c',
+        'ѕ' => 's',
+        'у' => 'y',
+        'х' => 'x',
+        'һ' => 'h',
+        'ԁ' => 'd',
+        'А' => 'A',
+        'В' => 'B',
+        'Е' => 'E',
+        'І' => 'I',
+        'Ј' => 'J',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Ѕ' => 'S',
+        'Т' => 'T',
+        'Х' => 'X',
+        // Greek
+        'ι' => 'i',
+        'ο' => 'o',
+        'ν' => 'v',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Υ' => 'Y',
+        'Χ' => 'X',
+        _ => return None,
+    };
+    Some(latin)
+}
+
+fn is_styled(ch: char) -> bool {
+    match CharacterInfo::get_letter_info(ch) {
+        CharacterInfo::Letter(info) => !matches!(
+            (&info.letter_type, &info.letter_style),
+            (StyledLetter::Serif, LetterStyle::Normal)
+        ),
+        CharacterInfo::Other(_) => false,
+    }
+}
+
+fn check_word(word: &str) -> Option<(SpoofKind, String)> {
+    let plain: Vec<char> = word.chars().map(destyle).collect();
+    let has_latin = plain.iter().any(|ch| ch.is_ascii_alphabetic());
+    let has_lookalike = plain.iter().any(|ch| latin_lookalike(*ch).is_some());
+    let kind = if word.chars().any(is_styled) {
+        SpoofKind::Styled
+    } else if has_latin && has_lookalike {
+        SpoofKind::MixedScript
+    } else {
+        return None;
+    };
+    // a word of only Greek or Cyrillic letters is just Greek or Cyrillic
+    let suggestion = if has_latin {
+        plain
+            .iter()
+            .map(|ch| latin_lookalike(*ch).unwrap_or(*ch))
+            .collect()
+    } else {
+        plain.iter().collect()
+    };
+    Some((kind, suggestion))
+}
+
+// Find the words that use styled letters or mix Latin with lookalike
+// letters of other scripts, as used to dodge filters and impersonate names.
+pub fn detect_spoof(text: &str) -> Vec<SpoofFinding> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("detect_spoof", bytes = text.len()).entered();
+    let mut findings = Vec::new();
+    for (line_idx, line) in text.lines().enumerate() {
+        let mut column = 1;
+        for word in line.split_word_bounds() {
+            if let Some((kind, suggestion)) = check_word(word) {
+                findings.push(SpoofFinding {
+                    line: line_idx + 1,
+                    column,
+                    kind,
+                    text: word.to_string(),
+                    suggestion,
+                });
+            }
+            column += word.chars().count();
+        }
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(findings = findings.len(), "detected spoofing");
+    findings
+}
+
+#[cfg(test)]
+mod test_spoof {
+    use super::*;
+
+    #[test]
+    pub fn test_detect_spoof() {
+        let findings = detect_spoof("log in to pаypal\nfree 𝐆𝐈𝐅𝐓 card\nплохо και ο");
+        assert_eq!(
+            vec![
+                SpoofFinding {
+                    line: 1,
+                    column: 11,
+                    kind: SpoofKind::MixedScript,
+                    text: "pаypal".to_string(),
+                    suggestion: "paypal".to_string(),
+                },
+                SpoofFinding {
+                    line: 2,
+                    column: 6,
+                    kind: SpoofKind::Styled,
+                    text: "𝐆𝐈𝐅𝐓".to_string(),
+                    suggestion: "GIFT".to_string(),
+                },
+            ],
+            findings
+        );
+        assert!(detect_spoof("plain text only").is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use crate::{destyle, CharacterInfo, CharacterType, LetterStyle, LetterTypeError, StyledLetter};
+
+// A letter, digit or Greek letter in a font and style. Characters that have
+// no styled forms at all (punctuation, symbols, other scripts) are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyledChar(char);
+
+impl StyledChar {
+    pub fn get(&self) -> char {
+        self.0
+    }
+}
+
+impl TryFrom<(StyledLetter, LetterStyle, char)> for StyledChar {
+    type Error = LetterTypeError;
+
+    fn try_from(
+        (letter_type, letter_style, ch): (StyledLetter, LetterStyle, char),
+    ) -> Result<Self, Self::Error> {
+        match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => {
+                info.convert(&letter_type, &letter_style).map(StyledChar)
+            }
+            CharacterInfo::Other(ch) => Err(LetterTypeError::InvalidCodeError(ch as u32)),
+        }
+    }
+}
+
+impl From<StyledChar> for char {
+    fn from(ch: StyledChar) -> char {
+        ch.0
+    }
+}
+
+impl Display for StyledChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// A (possibly styled) character split into its plain form, font, style and
+// class: '𝐴' is 'A' in serif italic, a letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decomposed {
+    pub base: char,
+    pub font: StyledLetter,
+    pub style: LetterStyle,
+    pub class: CharacterType,
+}
+
+impl TryFrom<char> for Decomposed {
+    type Error = LetterTypeError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match CharacterInfo::get_letter_info(ch) {
+            CharacterInfo::Letter(info) => Ok(Decomposed {
+                base: destyle(ch),
+                font: info.letter_type,
+                style: info.letter_style,
+                class: info.character_type,
+            }),
+            CharacterInfo::Other(ch) => Err(LetterTypeError::InvalidCodeError(ch as u32)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_styled_char {
+    use super::*;
+
+    #[test]
+    pub fn test_styled_char() {
+        let ch = StyledChar::try_from((StyledLetter::Fraktur, LetterStyle::Bold, 'a')).unwrap();
+        assert_eq!('𝖆', ch.get());
+        assert_eq!("𝖆", ch.to_string());
+        assert!(StyledChar::try_from((StyledLetter::Fraktur, LetterStyle::Italic, 'a')).is_err());
+        assert!(StyledChar::try_from((StyledLetter::Serif, LetterStyle::Bold, '!')).is_err());
+    }
+
+    #[test]
+    pub fn test_decomposed() {
+        assert_eq!(
+            Decomposed {
+                base: 'A',
+                font: StyledLetter::Serif,
+                style: LetterStyle::Italic,
+                class: CharacterType::Letter,
+            },
+            Decomposed::try_from('𝐴').unwrap()
+        );
+        let digit = Decomposed::try_from('𝟘').unwrap();
+        assert_eq!(('0', StyledLetter::DoubleStruck), (digit.base, digit.font));
+        assert_eq!(CharacterType::Digit, digit.class);
+        assert!(Decomposed::try_from('!').is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Debug;
+
+// A style that isn't one of the built-in fonts, e.g. from a third-party
+// style pack. It maps single characters both ways.
+pub trait Styler: Debug {
+    // unique name to select the style by
+    fn name(&self) -> &str;
+
+    // styled form of `ch`, None when the style has none
+    fn style_char(&self, ch: char) -> Option<char>;
+
+    // plain form of a character this style outputs, None for anything else
+    fn unstyle_char(&self, ch: char) -> Option<char>;
+
+    // letters the style knowingly has no form for
+    fn gaps(&self) -> Vec<char> {
+        Vec::new()
+    }
+
+    // Whether the style borrows letters of other scripts that merely look
+    // like Latin ones. Screen readers and search read those as the other
+    // script, so such styles are left out of `strict_stylers`.
+    fn lookalike(&self) -> bool {
+        false
+    }
+
+    fn style(&self, text: &str) -> String {
+        text.chars()
+            .map(|ch| self.style_char(ch).unwrap_or(ch))
+            .collect()
+    }
+
+    fn unstyle(&self, text: &str) -> String {
+        text.chars()
+            .map(|ch| self.unstyle_char(ch).unwrap_or(ch))
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StyleRegistry {
+    stylers: Vec<Box<dyn Styler>>,
+}
+
+impl StyleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, styler: Box<dyn Styler>) -> Result<(), String> {
+        if self.get(styler.name()).is_some() {
+            return Err(format!(
+                "a style named '{}' is already registered",
+                styler.name()
+            ));
+        }
+        self.stylers.push(styler);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Styler> {
+        self.stylers
+            .iter()
+            .find(|styler| styler.name() == name)
+            .map(|styler| styler.as_ref())
+    }
+
+    pub fn stylers(&self) -> impl Iterator<Item = &dyn Styler> {
+        self.stylers.iter().map(|styler| styler.as_ref())
+    }
+
+    // the registered styles that aren't lookalike-based
+    pub fn strict_stylers(&self) -> impl Iterator<Item = &dyn Styler> {
+        self.stylers().filter(|styler| !styler.lookalike())
+    }
+}
+
+#[cfg(test)]
+mod test_styler {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Upper;
+
+    impl Styler for Upper {
+        fn name(&self) -> &str {
+            "upper"
+        }
+
+        fn style_char(&self, ch: char) -> Option<char> {
+            ch.is_ascii_lowercase().then(|| ch.to_ascii_uppercase())
+        }
+
+        fn unstyle_char(&self, ch: char) -> Option<char> {
+            ch.is_ascii_uppercase().then(|| ch.to_ascii_lowercase())
+        }
+    }
+
+    #[test]
+    pub fn test_registry() {
+        let mut registry = StyleRegistry::new();
+        assert_eq!(Ok(()), registry.register(Box::new(Upper)));
+        assert_eq!(
+            Err("a style named 'upper' is already registered".to_string()),
+            registry.register(Box::new(Upper))
+        );
+        let upper = registry.get("upper").unwrap();
+        assert_eq!("ABC 1", upper.style("abc 1"));
+        assert_eq!("abc 1", upper.unstyle("ABC 1"));
+        assert!(registry.get("lower").is_none());
+        assert_eq!(1, registry.stylers().count());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{destyle, Classification, Converter, LetterStyle, StyledLetter};
+
+// the font and style of the first styled-able character of a span; plain
+// spans are serif normal
+fn span_style(span: &[char]) -> (StyledLetter, LetterStyle) {
+    span.iter()
+        .find_map(|ch| Classification::of(*ch).style)
+        .unwrap_or((StyledLetter::Serif, LetterStyle::Normal))
+}
+
+// Replace every occurrence of `pattern` in the text, matched against the
+// de-styled text so that "bold" finds "𝐛𝐨𝐥𝐝". The replacement takes the font
+// and style of the span it replaces; its characters that have no glyph in it
+// are left plain.
+pub fn substitute(text: &str, pattern: &str, replacement: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let plain: Vec<char> = chars.iter().map(|ch| destyle(*ch)).collect();
+    let pattern: Vec<char> = pattern.chars().map(destyle).collect();
+    if pattern.is_empty() {
+        return text.to_string();
+    }
+    let plain_replacement: String = replacement.chars().map(destyle).collect();
+    let mut res = String::with_capacity(text.len());
+    let mut idx = 0;
+    while idx < chars.len() {
+        if !plain[idx..].starts_with(&pattern) {
+            res.push(chars[idx]);
+            idx += 1;
+            continue;
+        }
+        let (letter_type, letter_style) = span_style(&chars[idx..idx + pattern.len()]);
+        res.push_str(
+            &Converter::new(letter_type, letter_style)
+                .convert_str_lossy(&plain_replacement)
+                .output,
+        );
+        idx += pattern.len();
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_substitute {
+    use super::*;
+
+    #[test]
+    pub fn test_substitute() {
+        assert_eq!(
+            "say 𝐛𝐲𝐞 to 𝔟𝔶𝔢",
+            substitute("say 𝐡𝐞𝐥𝐥𝐨 to 𝔥𝔢𝔩𝔩𝔬", "hello", "bye")
+        );
+        assert_eq!("Bye 𝓑𝔂𝓮", substitute("hi 𝓱𝓲", "hi", "Bye"));
+        // digits have no script bold glyphs
+        assert_eq!("𝔀2", substitute("𝓿1", "v1", "w2"));
+        assert_eq!("abc", substitute("abc", "", "x"));
+        // emoji sequences in the replacement stay whole
+        assert_eq!("𝐤𝐞𝐲 1️⃣", substitute("𝐤𝐞𝐲 𝐨𝐧𝐞", "one", "1️⃣"));
+    }
+}
@@ -0,0 +1,61 @@
+// Optimal string alignment distance: the Levenshtein distance where swapping
+// two adjacent characters ("itlaic") also counts as one edit.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dist = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dist[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            dist[i][j] = (dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1)
+                .min(dist[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dist[i][j] = dist[i][j].min(dist[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    dist[a.len()][b.len()]
+}
+
+// The candidates close enough to `input` to be what was meant, closest
+// first. Comparison ignores case.
+pub fn suggest<'a, I>(input: &str, candidates: I) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let input = input.to_lowercase();
+    // one typo per three characters, at least one
+    let max_distance = (input.chars().count() / 3).max(1);
+    let mut close: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&input, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    close.sort_by_key(|(distance, _)| *distance);
+    close.dedup_by_key(|(_, candidate)| *candidate);
+    close.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+#[cfg(test)]
+mod test_suggest {
+    use super::*;
+
+    #[test]
+    pub fn test_suggest() {
+        assert_eq!(1, edit_distance("itlaic", "italic"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+        assert_eq!(0, edit_distance("", ""));
+        let styles = ["normal", "bold", "italic", "bolditalic"];
+        assert_eq!(vec!["italic"], suggest("itlaic", styles));
+        assert_eq!(vec!["bold"], suggest("BOLF", styles));
+        assert_eq!(vec!["bolditalic"], suggest("bolditalc", styles));
+        assert!(suggest("comic", styles).is_empty());
+    }
+}
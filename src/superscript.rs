@@ -0,0 +1,281 @@
+use clap::ValueEnum;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+#[value(rename_all = "lower")]
+pub enum DigitStyle {
+    Superscript,
+    Subscript,
+    // seven-segment display digits, U+1FBF0..U+1FBF9; there are no segmented
+    // operators
+    #[value(alias = "lcd")]
+    Segmented,
+}
+
+const SEGMENTED_ZERO: u32 = 0x1FBF0;
+
+// 0-9 + - = ( ) n
+const SUPERSCRIPTS: [char; 16] = [
+    '\u{2070}', '\u{B9}', '\u{B2}', '\u{B3}', '\u{2074}', '\u{2075}', '\u{2076}', '\u{2077}',
+    '\u{2078}', '\u{2079}', '\u{207A}', '\u{207B}', '\u{207C}', '\u{207D}', '\u{207E}', '\u{207F}',
+];
+const SUBSCRIPTS: [char; 16] = [
+    '\u{2080}', '\u{2081}', '\u{2082}', '\u{2083}', '\u{2084}', '\u{2085}', '\u{2086}', '\u{2087}',
+    '\u{2088}', '\u{2089}', '\u{208A}', '\u{208B}', '\u{208C}', '\u{208D}', '\u{208E}', '\u{2099}',
+];
+
+fn table_index(ch: char) -> Option<usize> {
+    match ch {
+        '0'..='9' => Some(ch as usize - '0' as usize),
+        '+' => Some(10),
+        // hyphen-minus and U+2212 MINUS SIGN
+        '-' | '\u{2212}' => Some(11),
+        '=' => Some(12),
+        '(' => Some(13),
+        ')' => Some(14),
+        'n' => Some(15),
+        _ => None,
+    }
+}
+
+pub fn convert_digit_style(ch: char, digit_style: &DigitStyle) -> Option<char> {
+    let table = match *digit_style {
+        DigitStyle::Superscript => &SUPERSCRIPTS,
+        DigitStyle::Subscript => &SUBSCRIPTS,
+        DigitStyle::Segmented => {
+            return ch
+                .to_digit(10)
+                .and_then(|digit| char::from_u32(SEGMENTED_ZERO + digit))
+        }
+    };
+    table_index(ch).map(|index| table[index])
+}
+
+// The plain character and style of a superscript, subscript or segmented
+// character, the reverse of `convert_digit_style`.
+pub fn from_digit_style(ch: char) -> Option<(char, DigitStyle)> {
+    if (SEGMENTED_ZERO..SEGMENTED_ZERO + 10).contains(&(ch as u32)) {
+        let digit = char::from_digit(ch as u32 - SEGMENTED_ZERO, 10)?;
+        return Some((digit, DigitStyle::Segmented));
+    }
+    let plain = "0123456789+-=()n";
+    [
+        (&SUPERSCRIPTS, DigitStyle::Superscript),
+        (&SUBSCRIPTS, DigitStyle::Subscript),
+    ]
+    .into_iter()
+    .find_map(|(table, digit_style)| {
+        let index = table.iter().position(|styled| *styled == ch)?;
+        plain.chars().nth(index).map(|plain| (plain, digit_style))
+    })
+}
+
+// What to do with a letter that has no superscript form.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lower")]
+pub enum SuperscriptGap {
+    // write the closest other modifier letter, see SUPERSCRIPT_SUBSTITUTES
+    Substitute,
+    // leave the letter as it is
+    Skip,
+    // fail on the first such letter
+    Error,
+}
+
+// Modifier letters standing in for superscript a-z and A-Z, with the plain
+// letter where there is none. Uppercase C, F and Q and lowercase q were only
+// added in Unicode 14 (U+A7F2..U+A7F4, U+107A5) and fonts rarely have them,
+// so they are treated as missing too. There are no superscript S, X, Y or Z.
+const SUPERSCRIPT_LOWERCASE: [char; 26] = [
+    'ᵃ', 'ᵇ', 'ᶜ', 'ᵈ', 'ᵉ', 'ᶠ', 'ᵍ', 'ʰ', 'ⁱ', 'ʲ', 'ᵏ', 'ˡ', 'ᵐ', 'ⁿ', 'ᵒ', 'ᵖ', 'q', 'ʳ', 'ˢ',
+    'ᵗ', 'ᵘ', 'ᵛ', 'ʷ', 'ˣ', 'ʸ', 'ᶻ',
+];
+const SUPERSCRIPT_UPPERCASE: [char; 26] = [
+    'ᴬ', 'ᴮ', 'C', 'ᴰ', 'ᴱ', 'F', 'ᴳ', 'ᴴ', 'ᴵ', 'ᴶ', 'ᴷ', 'ᴸ', 'ᴹ', 'ᴺ', 'ᴼ', 'ᴾ', 'Q', 'ᴿ', 'S',
+    'ᵀ', 'ᵁ', 'ⱽ', 'ᵂ', 'X', 'Y', 'Z',
+];
+
+// The letters without a superscript form and what `Substitute` writes for
+// them: the lowercase form for capitals, and the superscript phi that is
+// commonly used for q.
+pub const SUPERSCRIPT_SUBSTITUTES: &[(char, char)] = &[
+    ('q', 'ᵠ'),
+    ('C', 'ᶜ'),
+    ('F', 'ᶠ'),
+    ('Q', 'ᵠ'),
+    ('S', 'ˢ'),
+    ('X', 'ˣ'),
+    ('Y', 'ʸ'),
+    ('Z', 'ᶻ'),
+];
+
+pub fn superscript_letter(ch: char) -> Option<char> {
+    let superscript = match ch {
+        'a'..='z' => SUPERSCRIPT_LOWERCASE[ch as usize - 'a' as usize],
+        'A'..='Z' => SUPERSCRIPT_UPPERCASE[ch as usize - 'A' as usize],
+        _ => return None,
+    };
+    Some(superscript).filter(|superscript| *superscript != ch)
+}
+
+// Write ASCII letters as superscript modifier letters, and digits and + - = ( )
+// as superscripts. Letters without a superscript form are handled as `gap`
+// says; other characters are kept.
+pub fn to_superscript_letters(text: &str, gap: &SuperscriptGap) -> Result<String, String> {
+    let mut res = String::with_capacity(text.len() * 2);
+    for ch in text.chars() {
+        if let Some(superscript) = superscript_letter(ch) {
+            res.push(superscript);
+            continue;
+        }
+        if !ch.is_ascii_alphabetic() {
+            res.push(convert_digit_style(ch, &DigitStyle::Superscript).unwrap_or(ch));
+            continue;
+        }
+        match *gap {
+            SuperscriptGap::Substitute => {
+                let (_, substitute) = SUPERSCRIPT_SUBSTITUTES
+                    .iter()
+                    .find(|(letter, _)| *letter == ch)
+                    .unwrap();
+                res.push(*substitute);
+            }
+            SuperscriptGap::Skip => res.push(ch),
+            SuperscriptGap::Error => return Err(format!("'{}' has no superscript form", ch)),
+        }
+    }
+    Ok(res)
+}
+
+// Parentheses of a run that have no partner inside the same run.
+fn unmatched_parens(run: &[char]) -> Vec<bool> {
+    let mut unmatched = vec![false; run.len()];
+    let mut open = Vec::new();
+    for (idx, ch) in run.iter().enumerate() {
+        match *ch {
+            '(' => open.push(idx),
+            ')' => match open.pop() {
+                Some(_) => {}
+                None => unmatched[idx] = true,
+            },
+            _ => {}
+        }
+    }
+    for idx in open {
+        unmatched[idx] = true;
+    }
+    unmatched
+}
+
+// Convert numeric expressions: runs of digits, + - = ( ) and n that contain a
+// digit, or an n next to an operator ("2n+1", "(n)"). Operators outside such
+// runs, unmatched parentheses and an n that is part of a word or stands
+// alone are kept as is.
+pub fn to_digit_style(text: &str, digit_style: &DigitStyle) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let is_alphabetic = |idx: Option<usize>| {
+        idx.and_then(|idx| chars.get(idx))
+            .is_some_and(|ch| ch.is_alphabetic())
+    };
+    let in_expression: Vec<bool> = chars
+        .iter()
+        .enumerate()
+        .map(|(idx, ch)| match *ch {
+            'n' => !is_alphabetic(idx.checked_sub(1)) && !is_alphabetic(Some(idx + 1)),
+            _ => table_index(*ch).is_some(),
+        })
+        .collect();
+    let mut res = String::with_capacity(text.len() * 2);
+    let mut idx = 0;
+    while idx < chars.len() {
+        if !in_expression[idx] {
+            res.push(chars[idx]);
+            idx += 1;
+            continue;
+        }
+        let end = (idx..chars.len())
+            .find(|end| !in_expression[*end])
+            .unwrap_or(chars.len());
+        let run = &chars[idx..end];
+        let has_digit = run.iter().any(char::is_ascii_digit);
+        let has_n = run.len() > 1 && run.contains(&'n');
+        if has_digit || has_n {
+            for (ch, unmatched) in run.iter().zip(unmatched_parens(run)) {
+                match convert_digit_style(*ch, digit_style) {
+                    Some(converted) if !unmatched => res.push(converted),
+                    _ => res.push(*ch),
+                }
+            }
+        } else {
+            res.extend(run);
+        }
+        idx = end;
+    }
+    res
+}
+
+#[cfg(test)]
+mod test_superscript {
+    use super::*;
+
+    #[test]
+    pub fn test_to_digit_style() {
+        assert_eq!(
+            "x² + y²",
+            to_digit_style("x2 + y2", &DigitStyle::Superscript)
+        );
+        assert_eq!("CO₂", to_digit_style("CO2", &DigitStyle::Subscript));
+        assert_eq!("x²ⁿ⁺¹", to_digit_style("x2n+1", &DigitStyle::Superscript));
+        assert_eq!("a⁽ⁿ⁻¹⁾", to_digit_style("a(n-1)", &DigitStyle::Superscript));
+        assert_eq!("ⁿ⁽ⁿ⁾", to_digit_style("n(n)", &DigitStyle::Superscript));
+        assert_eq!(
+            "well-known (and) n",
+            to_digit_style("well-known (and) n", &DigitStyle::Subscript)
+        );
+        assert_eq!("(a+b)²", to_digit_style("(a+b)2", &DigitStyle::Superscript));
+        assert_eq!("f(x₁)", to_digit_style("f(x1)", &DigitStyle::Subscript));
+    }
+
+    #[test]
+    pub fn test_segmented() {
+        assert_eq!(
+            "🯱🯲:🯴🯵 (n)",
+            to_digit_style("12:45 (n)", &DigitStyle::Segmented)
+        );
+        assert_eq!(Ok(DigitStyle::Segmented), DigitStyle::from_str("lcd", true));
+        for ch in "0123456789+-=()n".chars() {
+            for digit_style in DigitStyle::value_variants() {
+                if let Some(styled) = convert_digit_style(ch, digit_style) {
+                    assert_eq!(Some((ch, *digit_style)), from_digit_style(styled));
+                }
+            }
+        }
+        assert_eq!(None, from_digit_style('7'));
+    }
+
+    #[test]
+    pub fn test_superscript_letters() {
+        assert_eq!(
+            Ok("ᵉ⁼ᵐᶜ²".to_string()),
+            to_superscript_letters("e=mc2", &SuperscriptGap::Error)
+        );
+        assert_eq!(
+            Ok("ᵠᵘᴵᶻ".to_string()),
+            to_superscript_letters("quIZ", &SuperscriptGap::Substitute)
+        );
+        assert_eq!(
+            Ok("qᵘᴵZ".to_string()),
+            to_superscript_letters("quIZ", &SuperscriptGap::Skip)
+        );
+        assert_eq!(
+            Err("'q' has no superscript form".to_string()),
+            to_superscript_letters("quiz", &SuperscriptGap::Error)
+        );
+        // every letter is either mapped or listed as a gap
+        for ch in ('a'..='z').chain('A'..='Z') {
+            let is_gap = SUPERSCRIPT_SUBSTITUTES
+                .iter()
+                .any(|(letter, _)| *letter == ch);
+            assert_ne!(is_gap, superscript_letter(ch).is_some(), "{}", ch);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{tables::*, CharacterType, LetterInfo, LetterStyle, LetterTypeError, StyledLetter};
+
+// Every styled character, laid out at compile time so that a conversion is
+// one index instead of walking the runs and corner case tables. Slots are
+// uppercase letters, lowercase letters, digits, uppercase Greek and
+// lowercase Greek, each indexed by offset.
+const CLASSES: usize = 5;
+const COUNTS: [usize; CLASSES] = [
+    LETTER_COUNT,
+    LETTER_COUNT,
+    DIGIT_COUNT,
+    GREEK_UPPER_COUNT,
+    GREEK_LOWER_COUNT,
+];
+const SLOTS: usize = LETTER_COUNT * 2 + DIGIT_COUNT + GREEK_UPPER_COUNT + GREEK_LOWER_COUNT;
+
+// the start of a run and the characters in place of its reserved code points
+#[derive(Clone, Copy)]
+struct Run {
+    start: u32,
+    corner_cases: &'static [(usize, char)],
+}
+
+const fn run(start: u32) -> Option<Run> {
+    Some(Run {
+        start,
+        corner_cases: &[],
+    })
+}
+
+const fn run_with(start: u32, corner_cases: &'static [(usize, char)]) -> Option<Run> {
+    Some(Run {
+        start,
+        corner_cases,
+    })
+}
+
+const NONE: [Option<Run>; CLASSES] = [None; CLASSES];
+
+// the runs of every font and style, in the order of `StyledLetter` and
+// `LetterStyle` and by class
+const RUNS: [[[Option<Run>; CLASSES]; 4]; 6] = [
+    // serif
+    [
+        [
+            run(SERIF_NORMAL_UPPER_START),
+            run(SERIF_NORMAL_LOWER_START),
+            run(SERIF_NORMAL_DIGIT_START),
+            run_with(
+                SERIF_NORMAL_GREEK_UPPER_START,
+                SERIF_NORMAL_GREEK_UPPER_CORNER_CASES,
+            ),
+            run_with(
+                SERIF_NORMAL_GREEK_LOWER_START,
+                SERIF_NORMAL_GREEK_LOWER_CORNER_CASES,
+            ),
+        ],
+        [
+            run(SERIF_BOLD_UPPER_START),
+            run(SERIF_BOLD_LOWER_START),
+            run(SERIF_BOLD_DIGIT_START),
+            run(SERIF_BOLD_GREEK_UPPER_START),
+            run(SERIF_BOLD_GREEK_LOWER_START),
+        ],
+        [
+            run(SERIF_ITALIC_UPPER_START),
+            run_with(SERIF_ITALIC_LOWER_START, SERIF_ITALIC_LOWER_CORNER_CASES),
+            None,
+            run(SERIF_ITALIC_GREEK_UPPER_START),
+            run(SERIF_ITALIC_GREEK_LOWER_START),
+        ],
+        [
+            run(SERIF_BOLD_ITALIC_UPPER_START),
+            run(SERIF_BOLD_ITALIC_LOWER_START),
+            None,
+            run(SERIF_BOLD_ITALIC_GREEK_UPPER_START),
+            run(SERIF_BOLD_ITALIC_GREEK_LOWER_START),
+        ],
+    ],
+    // sans-serif
+    [
+        [
+            run(SANS_SERIF_NORMAL_UPPER_START),
+            run(SANS_SERIF_NORMAL_LOWER_START),
+            run(SANS_SERIF_NORMAL_DIGIT_START),
+            None,
+            None,
+        ],
+        [
+            run(SANS_SERIF_BOLD_UPPER_START),
+            run(SANS_SERIF_BOLD_LOWER_START),
+            run(SANS_SERIF_BOLD_DIGIT_START),
+            run(SANS_SERIF_BOLD_GREEK_UPPER_START),
+            run(SANS_SERIF_BOLD_GREEK_LOWER_START),
+        ],
+        [
+            run(SANS_SERIF_ITALIC_UPPER_START),
+            run(SANS_SERIF_ITALIC_LOWER_START),
+            None,
+            None,
+            None,
+        ],
+        [
+            run(SANS_SERIF_BOLD_ITALIC_UPPER_START),
+            run(SANS_SERIF_BOLD_ITALIC_LOWER_START),
+            None,
+            run(SANS_SERIF_BOLD_ITALIC_GREEK_UPPER_START),
+            run(SANS_SERIF_BOLD_ITALIC_GREEK_LOWER_START),
+        ],
+    ],
+    // script
+    [
+        [
+            run_with(SCRIPT_NORMAL_UPPER_START, SCRIPT_NORMAL_UPPER_CORNER_CASES),
+            run_with(SCRIPT_NORMAL_LOWER_START, SCRIPT_NORMAL_LOWER_CORNER_CASES),
+            None,
+            None,
+            None,
+        ],
+        [
+            run(SCRIPT_BOLD_UPPER_START),
+            run(SCRIPT_BOLD_LOWER_START),
+            None,
+            None,
+            None,
+        ],
+        NONE,
+        NONE,
+    ],
+    // fraktur
+    [
+        [
+            run_with(
+                FRAKTUR_NORMAL_UPPER_START,
+                FRAKTUR_NORMAL_UPPER_CORNER_CASES,
+            ),
+            run(FRAKTUR_NORMAL_LOWER_START),
+            None,
+            None,
+            None,
+        ],
+        [
+            run(FRAKTUR_BOLD_UPPER_START),
+            run(FRAKTUR_BOLD_LOWER_START),
+            None,
+            None,
+            None,
+        ],
+        NONE,
+        NONE,
+    ],
+    // monospace
+    [
+        [
+            run(MONOSPACE_NORMAL_UPPER_START),
+            run(MONOSPACE_NORMAL_LOWER_START),
+            run(MONOSPACE_NORMAL_DIGIT_START),
+            None,
+            None,
+        ],
+        NONE,
+        NONE,
+        NONE,
+    ],
+    // double-struck: bold letters but normal digits
+    [
+        [
+            None,
+            None,
+            run(DOUBLE_STRUCK_NORMAL_DIGIT_START),
+            None,
+            None,
+        ],
+        [
+            run_with(
+                DOUBLE_STRUCK_BOLD_UPPER_START,
+                DOUBLE_STRUCK_BOLD_UPPER_CORNER_CASES,
+            ),
+            run(DOUBLE_STRUCK_BOLD_LOWER_START),
+            None,
+            None,
+            None,
+        ],
+        NONE,
+        NONE,
+    ],
+];
+
+const fn styled(run: &Run, offset: usize) -> Option<char> {
+    let mut idx = 0;
+    while idx < run.corner_cases.len() {
+        if run.corner_cases[idx].0 == offset {
+            return Some(run.corner_cases[idx].1);
+        }
+        idx += 1;
+    }
+    char::from_u32(run.start + offset as u32)
+}
+
+const fn build() -> [[[Option<char>; SLOTS]; 4]; 6] {
+    let mut table = [[[None; SLOTS]; 4]; 6];
+    let mut letter_type = 0;
+    while letter_type < RUNS.len() {
+        let mut letter_style = 0;
+        while letter_style < 4 {
+            let (mut class, mut slot) = (0, 0);
+            while class < CLASSES {
+                let mut offset = 0;
+                while offset < COUNTS[class] {
+                    if let Some(run) = &RUNS[letter_type][letter_style][class] {
+                        table[letter_type][letter_style][slot] = styled(run, offset);
+                    }
+                    offset += 1;
+                    slot += 1;
+                }
+                class += 1;
+            }
+            letter_style += 1;
+        }
+        letter_type += 1;
+    }
+    table
+}
+
+// fonts without a single run for a class have no characters of it in any
+// style
+const fn supported() -> [[bool; CLASSES]; 6] {
+    let mut supported = [[false; CLASSES]; 6];
+    let mut letter_type = 0;
+    while letter_type < RUNS.len() {
+        let mut letter_style = 0;
+        while letter_style < 4 {
+            let mut class = 0;
+            while class < CLASSES {
+                if RUNS[letter_type][letter_style][class].is_some() {
+                    supported[letter_type][class] = true;
+                }
+                class += 1;
+            }
+            letter_style += 1;
+        }
+        letter_type += 1;
+    }
+    supported
+}
+
+static TABLE: [[[Option<char>; SLOTS]; 4]; 6] = build();
+static SUPPORTED: [[bool; CLASSES]; 6] = supported();
+
+fn class(character_type: &CharacterType, uppercase: bool) -> Option<usize> {
+    match (character_type, uppercase) {
+        (CharacterType::Letter, true) => Some(0),
+        (CharacterType::Letter, false) => Some(1),
+        (CharacterType::Digit, _) => Some(2),
+        (CharacterType::Greek, true) => Some(3),
+        (CharacterType::Greek, false) => Some(4),
+        (CharacterType::Other, _) => None,
+    }
+}
+
+// The conversion of builds with the static-tables feature, with the errors
+// of the computed conversion.
+pub(crate) fn lookup(
+    info: &LetterInfo,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> Result<char, LetterTypeError> {
+    let class =
+        class(&info.character_type, info.uppercase).ok_or(LetterTypeError::InvalidTypeError)?;
+    if !SUPPORTED[*letter_type as usize][class] {
+        return Err(LetterTypeError::InvalidTypeError);
+    }
+    if info.offset >= COUNTS[class] {
+        return Err(LetterTypeError::ExceedLengthError(info.offset));
+    }
+    let slot = COUNTS[..class].iter().sum::<usize>() + info.offset;
+    TABLE[*letter_type as usize][*letter_style as usize][slot]
+        .ok_or(LetterTypeError::InvalidStyleError)
+}
+
+#[cfg(test)]
+mod test_table {
+    use super::*;
+    use crate::computed::compute;
+
+    #[test]
+    pub fn test_table_matches_computed() {
+        let classes = [
+            (CharacterType::Letter, true),
+            (CharacterType::Letter, false),
+            (CharacterType::Digit, false),
+            (CharacterType::Greek, true),
+            (CharacterType::Greek, false),
+            (CharacterType::Other, false),
+        ];
+        for (character_type, uppercase) in classes {
+            // past the end of every class, for the errors
+            for offset in 0..GREEK_LOWER_COUNT + 2 {
+                let info = LetterInfo::new(
+                    offset,
+                    uppercase,
+                    StyledLetter::Serif,
+                    LetterStyle::Normal,
+                    character_type,
+                );
+                for letter_type in StyledLetter::ALL {
+                    for letter_style in LetterStyle::ALL {
+                        assert_eq!(
+                            format!("{:?}", compute(&info, letter_type, letter_style)),
+                            format!("{:?}", lookup(&info, letter_type, letter_style)),
+                            "{:?} {} {:?} {:?}",
+                            character_type,
+                            offset,
+                            letter_type,
+                            letter_style
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
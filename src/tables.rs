@@ -0,0 +1,171 @@
+//! The code point tables conversion is built on, for generating lookup code
+//! elsewhere. A run starts at `*_START` and is indexed by the offset of the
+//! plain character: 'A'..'Z' and 'a'..'z' are 0..26, '0'..'9' are 0..10.
+//! Greek runs are 25 capitals (Α..Ω with ϴ in the gap of final sigma) then ∇,
+//! and 25 small letters (α..ω) then ∂ ϵ ϑ ϰ ϕ ϱ ϖ.
+//!
+//! Some letters were encoded before the Mathematical Alphanumeric Symbols
+//! block and are missing from their run; the code point at their offset is
+//! reserved. The `*_CORNER_CASES` tables map those offsets to the character
+//! that takes their place, as (offset, character) pairs ordered by offset.
+
+pub const LETTER_COUNT: usize = 26;
+pub const DIGIT_COUNT: usize = 10;
+pub const GREEK_UPPER_COUNT: usize = 26;
+pub const GREEK_LOWER_COUNT: usize = 32;
+
+// letters
+pub const SERIF_NORMAL_UPPER_START: u32 = 0x41;
+pub const SERIF_NORMAL_LOWER_START: u32 = 0x61;
+pub const SERIF_BOLD_UPPER_START: u32 = 0x1D400;
+pub const SERIF_BOLD_LOWER_START: u32 = 0x1D41A;
+pub const SERIF_ITALIC_UPPER_START: u32 = 0x1D434;
+pub const SERIF_ITALIC_LOWER_START: u32 = 0x1D44E;
+pub const SERIF_BOLD_ITALIC_UPPER_START: u32 = 0x1D468;
+pub const SERIF_BOLD_ITALIC_LOWER_START: u32 = 0x1D482;
+pub const SANS_SERIF_NORMAL_UPPER_START: u32 = 0x1D5A0;
+pub const SANS_SERIF_NORMAL_LOWER_START: u32 = 0x1D5BA;
+pub const SANS_SERIF_BOLD_UPPER_START: u32 = 0x1D5D4;
+pub const SANS_SERIF_BOLD_LOWER_START: u32 = 0x1D5EE;
+pub const SANS_SERIF_ITALIC_UPPER_START: u32 = 0x1D608;
+pub const SANS_SERIF_ITALIC_LOWER_START: u32 = 0x1D622;
+pub const SANS_SERIF_BOLD_ITALIC_UPPER_START: u32 = 0x1D63C;
+pub const SANS_SERIF_BOLD_ITALIC_LOWER_START: u32 = 0x1D656;
+pub const SCRIPT_NORMAL_UPPER_START: u32 = 0x1D49C;
+pub const SCRIPT_NORMAL_LOWER_START: u32 = 0x1D4B6;
+pub const SCRIPT_BOLD_UPPER_START: u32 = 0x1D4D0;
+pub const SCRIPT_BOLD_LOWER_START: u32 = 0x1D4EA;
+pub const FRAKTUR_NORMAL_UPPER_START: u32 = 0x1D504;
+pub const FRAKTUR_NORMAL_LOWER_START: u32 = 0x1D51E;
+pub const FRAKTUR_BOLD_UPPER_START: u32 = 0x1D56C;
+pub const FRAKTUR_BOLD_LOWER_START: u32 = 0x1D586;
+pub const MONOSPACE_NORMAL_UPPER_START: u32 = 0x1D670;
+pub const MONOSPACE_NORMAL_LOWER_START: u32 = 0x1D68A;
+pub const DOUBLE_STRUCK_BOLD_UPPER_START: u32 = 0x1D538;
+pub const DOUBLE_STRUCK_BOLD_LOWER_START: u32 = 0x1D552;
+
+// digits
+pub const SERIF_NORMAL_DIGIT_START: u32 = 0x30;
+pub const SERIF_BOLD_DIGIT_START: u32 = 0x1D7CE;
+pub const SANS_SERIF_NORMAL_DIGIT_START: u32 = 0x1D7E2;
+pub const SANS_SERIF_BOLD_DIGIT_START: u32 = 0x1D7EC;
+pub const MONOSPACE_NORMAL_DIGIT_START: u32 = 0x1D7F6;
+pub const DOUBLE_STRUCK_NORMAL_DIGIT_START: u32 = 0x1D7D8;
+
+// Greek
+pub const SERIF_NORMAL_GREEK_UPPER_START: u32 = 0x391;
+pub const SERIF_NORMAL_GREEK_LOWER_START: u32 = 0x3B1;
+pub const SERIF_BOLD_GREEK_UPPER_START: u32 = 0x1D6A8;
+pub const SERIF_BOLD_GREEK_LOWER_START: u32 = 0x1D6C2;
+pub const SERIF_ITALIC_GREEK_UPPER_START: u32 = 0x1D6E2;
+pub const SERIF_ITALIC_GREEK_LOWER_START: u32 = 0x1D6FC;
+pub const SERIF_BOLD_ITALIC_GREEK_UPPER_START: u32 = 0x1D71C;
+pub const SERIF_BOLD_ITALIC_GREEK_LOWER_START: u32 = 0x1D736;
+pub const SANS_SERIF_BOLD_GREEK_UPPER_START: u32 = 0x1D756;
+pub const SANS_SERIF_BOLD_GREEK_LOWER_START: u32 = 0x1D770;
+pub const SANS_SERIF_BOLD_ITALIC_GREEK_UPPER_START: u32 = 0x1D790;
+pub const SANS_SERIF_BOLD_ITALIC_GREEK_LOWER_START: u32 = 0x1D7AA;
+
+// corner cases
+pub const SERIF_ITALIC_LOWER_CORNER_CASES: &[(usize, char)] = &[
+    (7, '\u{210E}'), // ℎ h
+];
+pub const SCRIPT_NORMAL_UPPER_CORNER_CASES: &[(usize, char)] = &[
+    (1, '\u{212C}'),  // ℬ B
+    (4, '\u{2130}'),  // ℰ E
+    (5, '\u{2131}'),  // ℱ F
+    (7, '\u{210B}'),  // ℋ H
+    (8, '\u{2110}'),  // ℐ I
+    (11, '\u{2112}'), // ℒ L
+    (12, '\u{2133}'), // ℳ M
+    (17, '\u{211B}'), // ℛ R
+];
+pub const SCRIPT_NORMAL_LOWER_CORNER_CASES: &[(usize, char)] = &[
+    (4, '\u{212F}'),  // ℯ e
+    (6, '\u{210A}'),  // ℊ g
+    (14, '\u{2134}'), // ℴ o
+];
+pub const FRAKTUR_NORMAL_UPPER_CORNER_CASES: &[(usize, char)] = &[
+    (2, '\u{212D}'),  // ℭ C
+    (7, '\u{210C}'),  // ℌ H
+    (8, '\u{2111}'),  // ℑ I
+    (17, '\u{211C}'), // ℜ R
+    (25, '\u{2128}'), // ℨ Z
+];
+pub const DOUBLE_STRUCK_BOLD_UPPER_CORNER_CASES: &[(usize, char)] = &[
+    (2, '\u{2102}'),  // ℂ C
+    (7, '\u{210D}'),  // ℍ H
+    (13, '\u{2115}'), // ℕ N
+    (15, '\u{2119}'), // ℙ P
+    (16, '\u{211A}'), // ℚ Q
+    (17, '\u{211D}'), // ℝ R
+    (25, '\u{2124}'), // ℤ Z
+];
+/// plain Greek keeps these outside the Greek and Coptic run
+pub const SERIF_NORMAL_GREEK_UPPER_CORNER_CASES: &[(usize, char)] = &[
+    (17, '\u{3F4}'),  // ϴ
+    (25, '\u{2207}'), // ∇
+];
+/// plain Greek keeps these outside the Greek and Coptic run
+pub const SERIF_NORMAL_GREEK_LOWER_CORNER_CASES: &[(usize, char)] = &[
+    (25, '\u{2202}'), // ∂
+    (26, '\u{3F5}'),  // ϵ
+    (27, '\u{3D1}'),  // ϑ
+    (28, '\u{3F0}'),  // ϰ
+    (29, '\u{3D5}'),  // ϕ
+    (30, '\u{3F1}'),  // ϱ
+    (31, '\u{3D6}'),  // ϖ
+];
+
+#[cfg(test)]
+mod test_tables {
+    use super::*;
+    use crate::{convert_pair, LetterStyle, StyledLetter};
+
+    fn styled(ch: char, letter_type: StyledLetter, letter_style: LetterStyle) -> char {
+        convert_pair(ch, &letter_type, &letter_style).unwrap()
+    }
+
+    #[test]
+    pub fn test_tables_match_conversion() {
+        // offsets not covered by a corner case land in the run
+        assert_eq!(
+            char::from_u32(SCRIPT_NORMAL_UPPER_START).unwrap(),
+            styled('A', StyledLetter::Script, LetterStyle::Normal)
+        );
+        assert_eq!(
+            char::from_u32(SANS_SERIF_BOLD_DIGIT_START + 7).unwrap(),
+            styled('7', StyledLetter::SansSerif, LetterStyle::Bold)
+        );
+        assert_eq!(
+            char::from_u32(SERIF_ITALIC_GREEK_LOWER_START + 1).unwrap(),
+            styled('β', StyledLetter::Serif, LetterStyle::Italic)
+        );
+        let tables = [
+            (SCRIPT_NORMAL_UPPER_CORNER_CASES, 'A', StyledLetter::Script),
+            (SCRIPT_NORMAL_LOWER_CORNER_CASES, 'a', StyledLetter::Script),
+            (
+                FRAKTUR_NORMAL_UPPER_CORNER_CASES,
+                'A',
+                StyledLetter::Fraktur,
+            ),
+        ];
+        for (table, first, letter_type) in tables {
+            for (offset, ch) in table {
+                let plain = char::from_u32(first as u32 + *offset as u32).unwrap();
+                assert_eq!(*ch, styled(plain, letter_type, LetterStyle::Normal));
+            }
+        }
+        for (offset, ch) in DOUBLE_STRUCK_BOLD_UPPER_CORNER_CASES {
+            let plain = char::from_u32('A' as u32 + *offset as u32).unwrap();
+            assert_eq!(
+                *ch,
+                styled(plain, StyledLetter::DoubleStruck, LetterStyle::Bold)
+            );
+        }
+        assert_eq!(
+            '\u{210E}',
+            styled('h', StyledLetter::Serif, LetterStyle::Italic)
+        );
+    }
+}
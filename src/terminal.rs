@@ -0,0 +1,134 @@
+use std::io::{self, Write};
+
+use anstream::{AutoStream, RawStream};
+use anstyle::Style;
+
+use crate::{display_width, pad_right, StyledDocument};
+
+// Styled documents after columns of labels, e.g. a font and style, aligned
+// like `format_table` for pickers and previews in a terminal. Headers and
+// labels are styled with SGR codes, which `write_to` drops for streams that
+// don't take them.
+#[derive(Debug, Clone)]
+pub struct TerminalPreview {
+    headers: Vec<String>,
+    rows: Vec<(Vec<String>, StyledDocument)>,
+    header_style: Style,
+    label_style: Style,
+}
+
+impl TerminalPreview {
+    // the last header is the one of the documents; no headers for no header
+    // line
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: Vec::new(),
+            header_style: Style::new().bold(),
+            label_style: Style::new().dimmed(),
+        }
+    }
+
+    pub fn with_header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    pub fn with_label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    pub fn push(&mut self, labels: &[&str], document: StyledDocument) {
+        let labels = labels.iter().map(|label| label.to_string()).collect();
+        self.rows.push((labels, document));
+    }
+
+    // the preview with SGR codes, one line per row
+    pub fn render(&self) -> String {
+        let label_columns = self
+            .rows
+            .iter()
+            .map(|(labels, _)| labels.len())
+            .chain([self.headers.len().saturating_sub(1)])
+            .max()
+            .unwrap_or_default();
+        let mut widths = vec![0; label_columns];
+        let label_rows = self.rows.iter().map(|(labels, _)| labels);
+        for labels in label_rows.chain([&self.headers]) {
+            for (width, label) in widths.iter_mut().zip(labels) {
+                *width = (*width).max(display_width(label));
+            }
+        }
+        let line = |labels: &[String], style: &Style, text: &str| {
+            let mut res = String::new();
+            for (idx, width) in widths.iter().enumerate() {
+                let label = labels.get(idx).map(String::as_str).unwrap_or_default();
+                res.push_str(&format!(
+                    "{}{}{}  ",
+                    style.render(),
+                    pad_right(label, *width),
+                    style.render_reset()
+                ));
+            }
+            res + text
+        };
+        let mut lines = Vec::with_capacity(self.rows.len() + 1);
+        if let Some((last, labels)) = self.headers.split_last() {
+            let header = format!(
+                "{}{}{}",
+                self.header_style.render(),
+                last,
+                self.header_style.render_reset()
+            );
+            lines.push(line(labels, &self.header_style, &header));
+        }
+        for (labels, document) in self.rows.iter() {
+            lines.push(line(labels, &self.label_style, &document.render()));
+        }
+        lines.join("\n")
+    }
+
+    // Write the preview and a newline, e.g. to `anstream::stdout()`, which
+    // keeps the colors only when writing to a terminal.
+    pub fn write_to<S: RawStream>(&self, stream: &mut AutoStream<S>) -> io::Result<()> {
+        writeln!(stream, "{}", self.render())
+    }
+}
+
+#[cfg(test)]
+mod test_terminal {
+    use super::*;
+    use crate::{format_table, LetterStyle, Run, StyledLetter};
+
+    #[test]
+    pub fn test_terminal_preview() {
+        let mut preview = TerminalPreview::new(&["FONT", "STYLE", "SAMPLE"]);
+        preview.push(
+            &["fraktur", "bold"],
+            StyledDocument::new(vec![Run::new(
+                "Hi",
+                StyledLetter::Fraktur,
+                LetterStyle::Bold,
+            )]),
+        );
+        preview.push(&["serif", "bold"], StyledDocument::parse("𝐱"));
+        let rendered = preview.render();
+        assert!(rendered.starts_with("\x1b[1mFONT"));
+        assert!(rendered.contains("\x1b[2mfraktur\x1b[0m  "));
+        // without colors, the preview is a plain table
+        let mut stream = AutoStream::never(anstream::Buffer::new());
+        preview.write_to(&mut stream).unwrap();
+        let plain = format_table(
+            &["FONT", "STYLE", "SAMPLE"],
+            &[
+                vec!["fraktur".to_string(), "bold".to_string(), "𝕳𝖎".to_string()],
+                vec!["serif".to_string(), "bold".to_string(), "𝐱".to_string()],
+            ],
+        );
+        assert_eq!(
+            format!("{}\n", plain),
+            String::from_utf8(stream.into_inner().as_bytes().to_vec()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "normalization")]
+use clap::ValueEnum;
+#[cfg(feature = "normalization")]
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Reverse by extended grapheme clusters so combining marks, emoji ZWJ
+// sequences and flags stay attached to their base character.
+pub fn reverse(text: &str) -> String {
+    text.graphemes(true).rev().collect()
+}
+
+// Lowercase everything, then uppercase the first letter of the text and the
+// first letter after each sentence terminator (`.`, `!`, `?`) that is
+// followed by whitespace. Unicode sentence boundaries are not used here
+// because they depend on the original casing ("end. next" is one sentence).
+pub fn sentence_case(text: &str) -> String {
+    sentence_case_pieces([(text, true)])
+}
+
+// `sentence_case` over text in pieces of which only those paired with true
+// change; the others, e.g. matches of --protect, are kept as they are but
+// still start, continue and end sentences like the rest of the text.
+pub fn sentence_case_pieces<'a, I>(pieces: I) -> String
+where
+    I: IntoIterator<Item = (&'a str, bool)>,
+{
+    let mut res = String::new();
+    let mut sentence_start = true;
+    let mut after_terminator = false;
+    for (piece, change) in pieces {
+        res.reserve(piece.len());
+        for ch in piece.chars() {
+            if ch.is_alphabetic() {
+                if !change {
+                    res.push(ch);
+                } else if sentence_start {
+                    res.extend(ch.to_uppercase());
+                } else {
+                    res.extend(ch.to_lowercase());
+                }
+                sentence_start = false;
+                after_terminator = false;
+                continue;
+            }
+            if matches!(ch, '.' | '!' | '?') {
+                after_terminator = true;
+            } else if ch.is_whitespace() && after_terminator {
+                sentence_start = true;
+            } else if !ch.is_whitespace() && !sentence_start {
+                after_terminator = false;
+            }
+            res.push(ch);
+        }
+    }
+    res
+}
+
+// Letters with a stroke or similar have no canonical decomposition, so they
+// are folded by hand.
+#[cfg(feature = "normalization")]
+fn fold_undecomposable(ch: char) -> char {
+    match ch {
+        'ø' => 'o',
+        'Ø' => 'O',
+        'ł' => 'l',
+        'Ł' => 'L',
+        'đ' => 'd',
+        'Đ' => 'D',
+        'ħ' => 'h',
+        'Ħ' => 'H',
+        'ı' => 'i',
+        _ => ch,
+    }
+}
+
+// Decompose (NFD) and drop all combining marks, e.g. "é" -> "e", so accented
+// Latin input can be styled. This is lossy and therefore never applied
+// implicitly.
+#[cfg(feature = "normalization")]
+pub fn strip_diacritics(text: &str) -> String {
+    text.nfd()
+        .filter(|ch| !is_combining_mark(*ch))
+        .map(fold_undecomposable)
+        .collect()
+}
+
+#[cfg(feature = "normalization")]
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+#[value(rename_all = "lower")]
+pub enum Normalization {
+    // canonical composition, e.g. "e" + U+0301 -> "é"
+    Nfc,
+    // compatibility composition, e.g. fullwidth "Ａ" -> "A", "ﬁ" -> "fi"
+    Nfkc,
+}
+
+// Normalize before classification so decomposed input and compatibility
+// characters map onto the ranges `CharacterInfo` knows about instead of
+// falling into `Other`.
+#[cfg(feature = "normalization")]
+pub fn normalize(text: &str, normalization: &Normalization) -> String {
+    match *normalization {
+        Normalization::Nfc => text.nfc().collect(),
+        Normalization::Nfkc => text.nfkc().collect(),
+    }
+}
+
+#[cfg(test)]
+mod test_transform {
+    use super::*;
+
+    #[test]
+    pub fn test_reverse() {
+        assert_eq!("cba", reverse("abc"));
+        assert_eq!("𝐜𝐛𝐚", reverse("𝐚𝐛𝐜"));
+        // e + U+0301 COMBINING ACUTE ACCENT stays one unit
+        assert_eq!("bae\u{301}", reverse("e\u{301}ab"));
+        assert_eq!("🇯🇵a", reverse("a🇯🇵"));
+        assert_eq!("", reverse(""));
+    }
+
+    #[test]
+    pub fn test_sentence_case() {
+        assert_eq!(
+            "Hello world. This is fine! Is it? \"Yes,\" she said.",
+            sentence_case("HELLO WORLD. this IS fine! is it? \"yes,\" SHE said.")
+        );
+        assert_eq!("  Leading spaces", sentence_case("  leading SPACES"));
+        assert_eq!("", sentence_case(""));
+        // kept pieces are left alone but still count as words
+        assert_eq!(
+            "Ask ABC-12. Then ship it",
+            sentence_case_pieces([("ASK ", true), ("ABC-12", false), (". then SHIP it", true)])
+        );
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    pub fn test_strip_diacritics() {
+        assert_eq!("Creme brulee", strip_diacritics("Crème brûlée"));
+        assert_eq!("Lodz", strip_diacritics("Łódź"));
+        assert_eq!("e", strip_diacritics("e\u{301}"));
+        assert_eq!("𝐚", strip_diacritics("𝐚"));
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    pub fn test_normalize() {
+        assert_eq!("é", normalize("e\u{301}", &Normalization::Nfc));
+        assert_eq!("Ａ", normalize("Ａ", &Normalization::Nfc));
+        assert_eq!("ABC fi", normalize("ＡＢＣ ﬁ", &Normalization::Nfkc));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Converter, LetterStyle, LetterTypeError, StyledLetter};
+
+// Calls `push` with every char of the converted text, keeping emoji
+// sequences whole like the other conversions.
+fn for_each_converted<F>(
+    text: &str,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+    mut push: F,
+) -> Result<(), LetterTypeError>
+where
+    F: FnMut(char),
+{
+    Converter::new(*letter_type, *letter_style).convert_each(text, |_, _, converted| {
+        push(converted?);
+        Ok(())
+    })
+}
+
+// Convert text straight to UTF-16 code units, e.g. for a JavaScript string or
+// a Windows API, without going through a UTF-8 String. Styled letters outside
+// the BMP are written as surrogate pairs.
+pub fn convert_to_utf16(
+    text: &str,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> Result<Vec<u16>, LetterTypeError> {
+    // most styled letters take a surrogate pair
+    let mut res = Vec::with_capacity(text.len() * 2);
+    let mut buf = [0; 2];
+    for_each_converted(text, letter_type, letter_style, |ch| {
+        res.extend_from_slice(ch.encode_utf16(&mut buf))
+    })?;
+    Ok(res)
+}
+
+// Number of UTF-16 code units the converted text takes, i.e. the length of
+// `convert_to_utf16`, without allocating it.
+pub fn converted_utf16_len(
+    text: &str,
+    letter_type: &StyledLetter,
+    letter_style: &LetterStyle,
+) -> Result<usize, LetterTypeError> {
+    let mut len = 0;
+    for_each_converted(text, letter_type, letter_style, |ch| len += ch.len_utf16())?;
+    Ok(len)
+}
+
+#[cfg(test)]
+mod test_utf16 {
+    use super::*;
+
+    #[test]
+    pub fn test_convert_to_utf16() {
+        let units = convert_to_utf16("Ah !", &StyledLetter::Serif, &LetterStyle::Italic).unwrap();
+        // 𝐴 is a surrogate pair, ℎ is in the BMP
+        assert_eq!(vec![0xD835, 0xDC34, 0x210E, 0x20, 0x21], units);
+        assert_eq!("𝐴ℎ !", String::from_utf16(&units).unwrap());
+        assert_eq!(
+            units.len(),
+            converted_utf16_len("Ah !", &StyledLetter::Serif, &LetterStyle::Italic).unwrap()
+        );
+        assert!(convert_to_utf16("a", &StyledLetter::Fraktur, &LetterStyle::Italic).is_err());
+    }
+}
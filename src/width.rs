@@ -0,0 +1,185 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+// Number of terminal columns the text occupies. Styled letters are 4 bytes
+// and 2 UTF-16 units but still a single column, which is why `len()` can't be
+// used for alignment.
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+fn padding(width: usize) -> String {
+    " ".repeat(width)
+}
+
+// left-aligned, padded with spaces on the right up to `width` columns
+pub fn pad_right(text: &str, width: usize) -> String {
+    let fill = width.saturating_sub(display_width(text));
+    format!("{}{}", text, padding(fill))
+}
+
+// right-aligned, padded with spaces on the left up to `width` columns
+pub fn pad_left(text: &str, width: usize) -> String {
+    let fill = width.saturating_sub(display_width(text));
+    format!("{}{}", padding(fill), text)
+}
+
+// centered in `width` columns, the extra column goes to the right
+pub fn center(text: &str, width: usize) -> String {
+    let fill = width.saturating_sub(display_width(text));
+    let left = fill / 2;
+    format!("{}{}{}", padding(left), text, padding(fill - left))
+}
+
+// At most `width` columns of the text, cut between grapheme clusters and
+// ending with `ellipsis` if it doesn't fit. Empty if not even the ellipsis
+// fits.
+pub fn truncate_width(text: &str, width: usize, ellipsis: &str) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    let Some(budget) = width.checked_sub(display_width(ellipsis)) else {
+        return String::new();
+    };
+    let mut res = String::with_capacity(text.len());
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = display_width(grapheme);
+        if used + grapheme_width > budget {
+            break;
+        }
+        used += grapheme_width;
+        res.push_str(grapheme);
+    }
+    res.push_str(ellipsis);
+    res
+}
+
+// The text in lines of at most `width` columns, broken at spaces. Runs of
+// spaces collapse at the breaks, newlines are kept, and words wider than a
+// line are split between grapheme clusters.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in paragraph.split(' ').filter(|word| !word.is_empty()) {
+            let word_width = display_width(word);
+            if line_width > 0 {
+                if line_width + 1 + word_width <= width {
+                    line.push(' ');
+                    line.push_str(word);
+                    line_width += 1 + word_width;
+                    continue;
+                }
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            if word_width <= width {
+                line.push_str(word);
+                line_width = word_width;
+                continue;
+            }
+            for grapheme in word.graphemes(true) {
+                let grapheme_width = display_width(grapheme);
+                if line_width > 0 && line_width + grapheme_width > width {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+                line.push_str(grapheme);
+                line_width += grapheme_width;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+// A header row and rows in columns aligned by display width, separated by
+// two spaces. The last column is not padded.
+pub fn format_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|header| display_width(header)).collect();
+    for row in rows {
+        for (idx, cell) in row.iter().enumerate() {
+            match widths.get_mut(idx) {
+                Some(width) => *width = (*width).max(display_width(cell)),
+                None => widths.push(display_width(cell)),
+            }
+        }
+    }
+    let format_row = |cells: Vec<&str>| {
+        let last = cells.len().saturating_sub(1);
+        let cells: Vec<String> = cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| {
+                if idx == last {
+                    cell.to_string()
+                } else {
+                    pad_right(cell, widths[idx])
+                }
+            })
+            .collect();
+        cells.join("  ")
+    };
+    let mut lines = vec![format_row(headers.to_vec())];
+    for row in rows {
+        lines.push(format_row(row.iter().map(String::as_str).collect()));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test_width {
+    use super::*;
+
+    #[test]
+    pub fn test_display_width() {
+        assert_eq!(5, display_width("hello"));
+        assert_eq!(5, display_width("𝐡𝐞𝐥𝐥𝐨"));
+        assert_eq!(1, display_width("e\u{301}"));
+        assert_eq!(4, display_width("中文"));
+    }
+
+    #[test]
+    pub fn test_padding() {
+        assert_eq!("𝐚𝐛  ", pad_right("𝐚𝐛", 4));
+        assert_eq!("  𝐚𝐛", pad_left("𝐚𝐛", 4));
+        assert_eq!(" 𝐚𝐛  ", center("𝐚𝐛", 5));
+        assert_eq!("𝐚𝐛𝐜", center("𝐚𝐛𝐜", 2));
+    }
+
+    #[test]
+    pub fn test_truncate_width() {
+        assert_eq!("𝐡𝐞𝐥𝐥𝐨", truncate_width("𝐡𝐞𝐥𝐥𝐨", 5, "…"));
+        assert_eq!("𝐡𝐞𝐥…", truncate_width("𝐡𝐞𝐥𝐥𝐨", 4, "…"));
+        // a wide character that doesn't fit is left out whole
+        assert_eq!("...", truncate_width("中文字", 4, "..."));
+        assert_eq!("中...", truncate_width("中文字", 5, "..."));
+        assert_eq!("", truncate_width("𝐡𝐞𝐥𝐥𝐨", 2, "..."));
+    }
+
+    #[test]
+    pub fn test_wrap() {
+        assert_eq!(
+            vec!["𝐭𝐡𝐞 𝐪𝐮𝐢𝐜𝐤", "𝐛𝐫𝐨𝐰𝐧 𝐟𝐨𝐱"],
+            wrap("𝐭𝐡𝐞 𝐪𝐮𝐢𝐜𝐤 𝐛𝐫𝐨𝐰𝐧  𝐟𝐨𝐱", 9)
+        );
+        assert_eq!(vec!["𝐚", "𝐛𝐜𝐝𝐞", "𝐟𝐠𝐡", "", "𝐢"], wrap("𝐚 𝐛𝐜𝐝𝐞𝐟𝐠𝐡\n\n𝐢", 4));
+        assert_eq!(vec!["中", "文"], wrap("中文", 3));
+    }
+
+    #[test]
+    pub fn test_format_table() {
+        let rows = vec![
+            vec!["serif-bold".to_string(), "𝐡𝐢".to_string(), "2".to_string()],
+            vec!["mono".to_string(), "中文".to_string(), "4".to_string()],
+        ];
+        assert_eq!(
+            "style       text  width\n\
+             serif-bold  𝐡𝐢    2\n\
+             mono        中文  4",
+            format_table(&["style", "text", "width"], &rows)
+        );
+    }
+}
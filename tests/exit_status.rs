@@ -0,0 +1,207 @@
+use std::{
+    io::Read,
+    process::{Command, Output, Stdio},
+};
+
+fn styledtext(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_styledtext"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+pub fn test_exit_status() {
+    let output = styledtext(&["--as", "script.bold", "ab"]);
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!("𝓪𝓫\n", String::from_utf8_lossy(&output.stdout));
+
+    // script has no digits: they are dropped
+    let output = styledtext(&["--as", "script.bold", "a1"]);
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!("𝓪\n", String::from_utf8_lossy(&output.stdout));
+
+    let output = styledtext(&["--input", "/nonexistent/styledtext-input"]);
+    assert_eq!(Some(2), output.status.code());
+
+    assert_eq!(Some(3), styledtext(&["--check", "abc"]).status.code());
+    assert_eq!(Some(0), styledtext(&["--check", "!?"]).status.code());
+    // changes found by --check say more than dropped characters
+    let output = styledtext(&["--check", "--as", "script.bold", "a1"]);
+    assert_eq!(Some(3), output.status.code());
+}
+
+#[test]
+pub fn test_convert_exit_status() {
+    let dir = std::env::temp_dir().join(format!("styledtext-convert-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("letters.txt"), "ab").unwrap();
+    let glob = format!("{}/*.txt", dir.display());
+    let out_dir = dir.join("out");
+    let convert = |spec| {
+        let out_dir = out_dir.to_str().unwrap();
+        styledtext(&[
+            "convert",
+            "--glob",
+            &glob,
+            "--out-dir",
+            out_dir,
+            "--as",
+            spec,
+        ])
+    };
+    assert_eq!(Some(0), convert("script").status.code());
+
+    // script has no digits: the file is written with them unconverted
+    std::fs::write(dir.join("digits.txt"), "a1").unwrap();
+    assert_eq!(Some(1), convert("script").status.code());
+    let converted = std::fs::read_to_string(out_dir.join("digits.txt")).unwrap();
+    assert_eq!("𝒶1", converted);
+
+    let output = styledtext(&["convert", "--glob", &glob, "--out-dir", "/dev/null/out"]);
+    assert_eq!(Some(2), output.status.code());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn test_convert_in_place_encoding() {
+    let dir = std::env::temp_dir().join(format!("styledtext-encoding-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    // one file to convert and one with nothing to convert
+    std::fs::write(dir.join("letters.txt"), "ab").unwrap();
+    std::fs::write(dir.join("plain.txt"), "!?").unwrap();
+    let glob = format!("{}/*.txt", dir.display());
+    let args = [
+        "convert",
+        "--glob",
+        &glob,
+        "-i",
+        "--output-encoding",
+        "utf16le",
+    ];
+    for _ in 0..2 {
+        assert_eq!(Some(0), styledtext(&args).status.code());
+        for (name, text) in [("letters.txt", "𝚊𝚋"), ("plain.txt", "!?")] {
+            let bytes = std::fs::read(dir.join(name)).unwrap();
+            let expected: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+            assert_eq!(
+                [b"\xFF\xFE".as_slice(), &expected].concat(),
+                bytes,
+                "{}",
+                name
+            );
+        }
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn test_convert_in_place_backup() {
+    let dir = std::env::temp_dir().join(format!("styledtext-backup-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("letters.txt"), "ab\n").unwrap();
+    std::fs::write(dir.join("plain.txt"), "!?\n").unwrap();
+    let glob = format!("{}/*.txt", dir.display());
+    let modified = |name: &str| dir.join(name).metadata().unwrap().modified().unwrap();
+    let plain_modified = modified("plain.txt");
+    // the second run finds nothing left to convert and keeps the first backup
+    for _ in 0..2 {
+        let output = styledtext(&["convert", "--glob", &glob, "-i.bak"]);
+        assert_eq!(Some(0), output.status.code());
+        assert_eq!(
+            "𝚊𝚋\n",
+            std::fs::read_to_string(dir.join("letters.txt")).unwrap()
+        );
+        assert_eq!(
+            b"ab\n".as_slice(),
+            std::fs::read(dir.join("letters.txt.bak")).unwrap()
+        );
+        assert_eq!(
+            b"!?\n".as_slice(),
+            std::fs::read(dir.join("plain.txt")).unwrap()
+        );
+        assert_eq!(plain_modified, modified("plain.txt"));
+        assert!(!dir.join("plain.txt.bak").exists());
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn test_output_error() {
+    let full = std::fs::OpenOptions::new()
+        .write(true)
+        .open("/dev/full")
+        .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_styledtext"))
+        .args(["--errors", "json", "abc"])
+        .stdout(full)
+        .output()
+        .unwrap();
+    assert_eq!(Some(2), output.status.code());
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!("fatal", error["level"]);
+    assert_eq!("output", error["code"]);
+}
+
+#[cfg(feature = "random")]
+#[test]
+pub fn test_random() {
+    let random = |args: &[&str]| {
+        let output = styledtext(args);
+        assert_eq!(Some(0), output.status.code());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let text = "the quick brown fox";
+    let seeded = random(&["--random", "--seed", "7", text]);
+    assert_eq!(seeded, random(&["--random", "--seed", "7", text]));
+    assert_ne!(random(&["--as", "monospace", text]), seeded);
+
+    // with a single choice left, --random is that style
+    let output = random(&[
+        "--random",
+        "--exclude-types",
+        "serif,sans-serif,script,fraktur,double-struck",
+        "--exclude-styles",
+        "bold,italic,bolditalic",
+        text,
+    ]);
+    assert_eq!(random(&["--as", "monospace", text]), output);
+
+    let output = styledtext(&[
+        "--random",
+        "--exclude-styles",
+        "normal,bold,italic,bolditalic",
+        text,
+    ]);
+    assert_eq!(Some(2), output.status.code());
+}
+
+#[test]
+pub fn test_protect() {
+    let output = styledtext(&[
+        "--protect",
+        "ABC-[0-9]+",
+        "--sentence-case",
+        "--reverse",
+        "ASK ABC-12 NOW",
+    ]);
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!("𝚠𝚘𝚗 ABC-12 𝚔𝚜𝙰\n", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+pub fn test_closed_pipe() {
+    // the export is larger than a pipe buffer, so it runs into the closed pipe
+    let mut child = Command::new(env!("CARGO_BIN_EXE_styledtext"))
+        .arg("export")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut head = [0; 16];
+    child.stdout.take().unwrap().read_exact(&mut head).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(Some(0), output.status.code());
+    assert!(output.stderr.is_empty());
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "i18n")]
+
+use std::process::Command;
+
+fn help(args: &[&str], lang: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_styledtext"))
+        .args(args)
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env("LANG", lang)
+        .output()
+        .unwrap();
+    assert_eq!(Some(0), output.status.code());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+pub fn test_localized_help() {
+    let english = help(&["--help"], "C");
+    assert!(english.contains("read the text to convert from a file"));
+    assert!(english.contains("Exit status: 0"));
+
+    let spanish = help(&["--lang", "es", "--help"], "C");
+    assert!(spanish.contains("leer el texto a convertir de un archivo"));
+    assert!(spanish.contains("Estado de salida: 0"));
+    assert!(spanish.contains("-h, --help"));
+    // every option of the long help is translated
+    for line in english
+        .lines()
+        .filter(|line| line.starts_with("          "))
+    {
+        let text = line.trim();
+        if !text.starts_with('[') {
+            assert!(!spanish.contains(text), "{}", text);
+        }
+    }
+
+    // the locale, unless --lang says otherwise
+    let french = help(&["--help"], "fr_FR.UTF-8");
+    assert!(french.contains("lire le texte à convertir depuis un fichier"));
+    let portuguese = help(&["--lang=pt", "--help"], "fr_FR.UTF-8");
+    assert!(portuguese.contains("ler o texto a converter de um arquivo"));
+
+    let subcommand = help(&["convert", "--help"], "pt_BR.UTF-8");
+    assert!(subcommand.contains("número de arquivos a converter em paralelo"));
+    assert!(subcommand.contains("Mostrar a ajuda"));
+    assert_eq!(
+        format!("styledtext {}\n", env!("CARGO_PKG_VERSION")),
+        help(&["--lang", "fr", "-V"], "C")
+    );
+}
+
+#[test]
+pub fn test_localized_errors() {
+    let output = Command::new(env!("CARGO_BIN_EXE_styledtext"))
+        .args(["--input", "/nonexistent/styledtext-input"])
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env("LANG", "fr_FR.UTF-8")
+        .output()
+        .unwrap();
+    assert_eq!(Some(2), output.status.code());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.starts_with("Erreur : /nonexistent/styledtext-input: "),
+        "{}",
+        stderr
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_styledtext"))
+        .args(["convert", "--glob", "[", "--out-dir", "out"])
+        .env("LC_ALL", "es_ES.UTF-8")
+        .output()
+        .unwrap();
+    assert_eq!(Some(2), output.status.code());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.starts_with("Error: glob no válido '['"),
+        "{}",
+        stderr
+    );
+}